@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use sysinfo::{System, Pid};
 use std::sync::Mutex;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tauri::{
     State, Manager, Emitter,
     tray::{TrayIconBuilder, MouseButton, MouseButtonState, TrayIconEvent},
@@ -12,7 +12,7 @@ use tauri::{
 };
 
 #[cfg(windows)]
-use windows::Win32::System::ProcessStatus::{GetProcessMemoryInfo, PROCESS_MEMORY_COUNTERS_EX};
+use windows::Win32::System::ProcessStatus::{GetProcessMemoryInfo, PROCESS_MEMORY_COUNTERS, PROCESS_MEMORY_COUNTERS_EX, PROCESS_MEMORY_COUNTERS_EX2};
 #[cfg(windows)]
 use windows::Win32::System::Threading::{OpenProcess, PROCESS_QUERY_INFORMATION, PROCESS_VM_READ};
 #[cfg(windows)]
@@ -35,26 +35,359 @@ use std::ffi::OsStr;
 #[cfg(windows)]
 use std::os::windows::ffi::OsStrExt;
 use std::collections::HashMap;
+use std::time::Instant;
+
+// File logger - release builds have no console, so errors go to
+// <app_data_dir>/logs/performance-guard.log (rotated at a size cap)
+mod logger {
+    use std::collections::HashMap;
+    use std::fs::{self, OpenOptions};
+    use std::io::Write;
+    use std::path::{Path, PathBuf};
+    use std::sync::atomic::{AtomicU8, Ordering};
+    use std::sync::Mutex;
+    use std::time::{Duration, Instant};
+
+    pub const LOG_FILE_NAME: &str = "performance-guard.log";
+    const MAX_LOG_BYTES: u64 = 1024 * 1024;
+    // The same message is written at most once per window (e.g. a failing poll every second)
+    const THROTTLE_WINDOW: Duration = Duration::from_secs(60);
+    const MAX_THROTTLE_ENTRIES: usize = 256;
+
+    #[derive(Clone, Copy, PartialEq, PartialOrd)]
+    pub enum Level {
+        Error = 0,
+        Warn = 1,
+        Info = 2,
+        Debug = 3,
+    }
+
+    impl Level {
+        pub fn parse(level: &str) -> Option<Level> {
+            match level.to_ascii_lowercase().as_str() {
+                "error" => Some(Level::Error),
+                "warn" => Some(Level::Warn),
+                "info" => Some(Level::Info),
+                "debug" => Some(Level::Debug),
+                _ => None,
+            }
+        }
+
+        fn label(self) -> &'static str {
+            match self {
+                Level::Error => "ERROR",
+                Level::Warn => "WARN",
+                Level::Info => "INFO",
+                Level::Debug => "DEBUG",
+            }
+        }
+    }
+
+    static LEVEL: AtomicU8 = AtomicU8::new(Level::Info as u8);
+    static LOG_PATH: Mutex<Option<PathBuf>> = Mutex::new(None);
+    static LAST_WRITTEN: Mutex<Option<HashMap<String, Instant>>> = Mutex::new(None);
+
+    pub fn init(dir: &Path) {
+        let _ = fs::create_dir_all(dir);
+        *LOG_PATH.lock().unwrap() = Some(dir.join(LOG_FILE_NAME));
+    }
+
+    pub fn set_level(level: Level) {
+        LEVEL.store(level as u8, Ordering::SeqCst);
+    }
+
+    pub fn path() -> Option<PathBuf> {
+        LOG_PATH.lock().unwrap().clone()
+    }
+
+    pub fn write(level: Level, message: String) {
+        if level as u8 > LEVEL.load(Ordering::SeqCst) {
+            return;
+        }
+
+        {
+            let mut last_written = LAST_WRITTEN.lock().unwrap();
+            let last_written = last_written.get_or_insert_with(HashMap::new);
+            let now = Instant::now();
+            if let Some(last) = last_written.get(&message) {
+                if now.duration_since(*last) < THROTTLE_WINDOW {
+                    return;
+                }
+            }
+            if last_written.len() >= MAX_THROTTLE_ENTRIES {
+                last_written.retain(|_, last| now.duration_since(*last) < THROTTLE_WINDOW);
+            }
+            last_written.insert(message.clone(), now);
+        }
+
+        let line = format!("{} [{}] {}\n", chrono::Local::now().to_rfc3339(), level.label(), message);
+        #[cfg(debug_assertions)]
+        eprint!("{}", line);
+
+        let log_path = LOG_PATH.lock().unwrap();
+        let Some(path) = log_path.as_ref() else { return };
+
+        // Keep one previous file around, like most desktop apps' .log / .log.1
+        if fs::metadata(path).map(|m| m.len() >= MAX_LOG_BYTES).unwrap_or(false) {
+            let _ = fs::rename(path, path.with_extension("log.1"));
+        }
+        if let Ok(mut file) = OpenOptions::new().create(true).append(true).open(path) {
+            let _ = file.write_all(line.as_bytes());
+        }
+    }
+}
+
+// Crash reports - opt-in panic hook that writes logs/crash-<timestamp>.log with the
+// message, location and a backtrace (release builds abort right after the hook)
+const CRASH_FILE_PREFIX: &str = "crash-";
+const MAX_RECENT_CRASHES: usize = 10;
+// Older reports are deleted when a new one is written, so a crash loop can't fill the disk
+const MAX_CRASH_LOGS: usize = 20;
+static CRASH_REPORTS: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+
+fn crash_dir() -> Option<PathBuf> {
+    logger::path()?.parent().map(Path::to_path_buf)
+}
+
+/// Crash reports in `dir`, oldest first (timestamped names sort chronologically)
+fn crash_log_files(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else { return Vec::new() };
+    let mut files: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with(CRASH_FILE_PREFIX) && name.ends_with(".log"))
+        })
+        .collect();
+    files.sort();
+    files
+}
+
+/// Installed first thing in main so panics during startup are covered too. Reports
+/// are only written once setup has loaded the opt-in and initialized logging
+fn install_crash_reporter() {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        if CRASH_REPORTS.load(std::sync::atomic::Ordering::SeqCst) {
+            if let Some(dir) = crash_dir() {
+                let message = info
+                    .payload()
+                    .downcast_ref::<&str>()
+                    .map(|s| s.to_string())
+                    .or_else(|| info.payload().downcast_ref::<String>().cloned())
+                    .unwrap_or_else(|| "(non-string panic payload)".to_string());
+                let location = info
+                    .location()
+                    .map(|l| format!("{}:{}:{}", l.file(), l.line(), l.column()))
+                    .unwrap_or_else(|| "unknown".to_string());
+                let now = chrono::Local::now();
+                let report = format!(
+                    "Performance Guard {} crash at {}\nThread: {}\nPanic: {}\nLocation: {}\n\nBacktrace:\n{}\n",
+                    env!("CARGO_PKG_VERSION"),
+                    now.to_rfc3339(),
+                    std::thread::current().name().unwrap_or("unnamed"),
+                    message,
+                    location,
+                    std::backtrace::Backtrace::force_capture(),
+                );
+                let file = dir.join(format!("{}{}.log", CRASH_FILE_PREFIX, now.format("%Y%m%d-%H%M%S%.3f")));
+                let _ = fs::write(file, report);
+
+                let files = crash_log_files(&dir);
+                for old in files.iter().take(files.len().saturating_sub(MAX_CRASH_LOGS)) {
+                    let _ = fs::remove_file(old);
+                }
+            }
+        }
+        default_hook(info);
+    }));
+}
+
+macro_rules! log_error {
+    ($($arg:tt)*) => { $crate::logger::write($crate::logger::Level::Error, format!($($arg)*)) };
+}
+
+macro_rules! log_warn {
+    ($($arg:tt)*) => { $crate::logger::write($crate::logger::Level::Warn, format!($($arg)*)) };
+}
+
+macro_rules! log_info {
+    ($($arg:tt)*) => { $crate::logger::write($crate::logger::Level::Info, format!($($arg)*)) };
+}
 
 struct AppState {
     system: Mutex<System>,
     data_path: PathBuf,
+    // User-defined (pattern, category) rules, checked before the built-in ones
+    category_rules: Mutex<Vec<(String, String)>>,
+    // PID -> soft CPU cap enforced by a per-process throttle thread
+    cpu_caps: Mutex<HashMap<u32, CpuCap>>,
+    // Duration of the last full process collection pass
+    last_cycle_ms: Mutex<Option<f64>>,
+    // PIDs suspended by game mode, None while game mode is off
+    game_mode_suspended: Mutex<Option<Vec<u32>>>,
+    // Exe path -> version resource strings (file IO, so read once per path)
+    version_cache: Mutex<HashMap<String, VersionStrings>>,
+    // Exe path -> (file mtime, signature check result)
+    signature_cache: Mutex<HashMap<String, (std::time::SystemTime, SignatureInfo)>>,
+    // Per-PID CPU mean/stddev for spike detection
+    cpu_anomaly: Mutex<CpuAnomalyDetector>,
+    // PID -> recent (time, GPU memory MB) samples for leak detection
+    gpu_memory_history: Mutex<HashMap<u32, std::collections::VecDeque<(Instant, f64)>>>,
+    // Throttle and already-reported PIDs for detect_gui_resource_leaks
+    gui_leak_check: Mutex<GuiLeakCheck>,
+    // Exe path -> RFC 3339 time it was first seen running (persisted)
+    first_seen: Mutex<FirstSeenTracker>,
+    // User additions to PROTECTED_PROCESSES (exe names)
+    protected_processes: Mutex<Vec<String>>,
+    // When setup took the throwaway CPU sample, and how many process/system stats polls ran
+    // since (counted apart, a consumer may only ever poll one of them)
+    cpu_primed_at: Instant,
+    cpu_polls: AtomicU32,
+    stats_polls: AtomicU32,
+    memory_metric: Mutex<MemoryMetric>,
+    memory_units: Mutex<MemoryUnits>,
+    // Auto-track grace period and loop generation, grace None = auto-track off
+    auto_track: Mutex<AutoTrackLoop>,
+    // A profiling burst thread is running
+    profiling_active: AtomicBool,
+    // PDH counter for system page faults/sec, opened on first use
+    #[cfg(windows)]
+    page_fault_counter: Mutex<Option<pdh::Counter>>,
+    // Downsampled memory history of every process, for the growth leaderboard
+    memory_history: Mutex<HashMap<u32, MemoryHistory>>,
+    // Labeled process lists for diff_named_snapshots, oldest first
+    named_snapshots: Mutex<Vec<NamedSnapshot>>,
+    // PDH wildcard counter over all GPU engines, opened on first use
+    #[cfg(windows)]
+    gpu_engine_counter: Mutex<Option<pdh::Counter>>,
+    // PhysicalDisk PDH counters, opened on first get_disk_activity
+    #[cfg(windows)]
+    disk_counters: Mutex<Option<DiskCounters>>,
+    // Trailing CPU/GPU samples per PID for the *_avg fields
+    display_average: Mutex<DisplayAverage>,
+    // PID -> temporary boost and the settings to put back when it expires
+    boosts: Mutex<HashMap<u32, ProcessBoost>>,
+    // PDH Energy Meter counter (RAPL on supported CPUs), opened on first use
+    #[cfg(windows)]
+    energy_meter_counter: Mutex<Option<pdh::Counter>>,
+    // Idle threshold for automatic memory trimming and loop generation, threshold None = off
+    auto_optimize: Mutex<AutoOptimizeLoop>,
+    // Per local day active/idle seconds and per-app active time (persisted)
+    daily_activity: Mutex<DailyActivityTracker>,
+    // Automation rules evaluated every poll
+    process_rules: Mutex<Vec<ProcessRule>>,
+    // PID -> create_time of processes suspended by rules, only these get resumed
+    rule_suspended: Mutex<HashMap<u32, u64>>,
+    // Exe path -> base64 PNG icon, filled on demand
+    icon_cache: Mutex<IconCache>,
+    // get_processes returns at most this many rows, None = all
+    max_list_length: Mutex<Option<usize>>,
+    // PID -> last I/O operation counts, for per-poll IOPS deltas
+    io_ops: Mutex<HashMap<u32, IoOpsSample>>,
+    // Stats server settings and its accept loop, config None while it is off
+    stats_server: Mutex<StatsServer>,
+    // Latest local results as JSON, served by the stats server
+    stats_cache: Mutex<StatsCache>,
+    // Source id -> remote Performance Guard instance being polled
+    remotes: Mutex<HashMap<String, RemoteSource>>,
+    // Adapter LUID -> description for gpu_adapter (None = DXGI doesn't list it), refreshed when an unknown LUID shows up
+    #[cfg(windows)]
+    gpu_adapter_names: Mutex<HashMap<String, Option<String>>>,
+    // PID -> last thread context switch total, for detail lookup rates
+    context_switches: Mutex<HashMap<u32, ContextSwitchSample>>,
+    // Exe paths waiting for a prefetch worker, see prefetch_icons
+    icon_prefetch: Mutex<IconPrefetchQueue>,
+    // Folders whose new executables get whitelisted, see watch_whitelist_directory
+    watched_directories: Mutex<Vec<WatchedDirectory>>,
+    network_sampler: Mutex<NetworkSampler>,
+    self_cpu_budget: Mutex<SelfCpuBudget>,
+    // PID -> create_time of processes suspended through suspend_process
+    manually_suspended: Mutex<HashMap<u32, u64>>,
+    // NVML adapter UUID -> newest process utilization sample timestamp already read,
+    // advanced only by the main poll (get_gpu_usage_per_process)
+    gpu_sample_timestamps: Mutex<HashMap<String, u64>>,
+    // GPU usage from the last main poll, for readers that must not advance the cursor
+    gpu_usage: Mutex<GpuProcessUsage>,
+    // NVML handle, initialized once in setup; None without an NVIDIA driver (never retried)
+    #[cfg(windows)]
+    nvml: Option<Nvml>,
+    // Temperature sensors, refreshed on every system stats poll
+    components: Mutex<sysinfo::Components>,
+    // Backend polling loop started by start_monitoring
+    monitoring: Mutex<MonitoringLoop>,
+    // Set when the data file was unreadable this launch and had to be recovered
+    data_recovery: Mutex<Option<DataRecovery>>,
+    // Held across every read-modify-write of the data file (commands and background writers)
+    data_file_lock: Mutex<()>,
+    // PID -> display names of the services it hosts, re-read every SERVICE_NAMES_TTL_SECS
+    service_names: Mutex<ServiceNameCache>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
 struct ProcessInfo {
     pid: u32,
     name: String,
     cpu_percent: f32,
     memory_mb: f64,
+    // Private commit charge, regardless of the selected memory metric
+    commit_mb: f64,
+    // OS-kept highs since the process started (PeakWorkingSetSize / PeakPagefileUsage)
+    peak_working_set_mb: f64,
+    peak_commit_mb: f64,
     memory_percent: f32,
     gpu_percent: f32,
+    // Dedicated VRAM from NVML, 0 for processes not on an NVIDIA GPU
+    gpu_memory_mb: f64,
+    // Trailing averages over the display window, equal to the raw values when it is 1
+    cpu_percent_avg: f32,
+    gpu_percent_avg: f32,
+    // Listed by NVML or active on any GPU engine - reliable even when gpu_percent is 0
+    uses_gpu: bool,
+    // Adapter gpu_percent was measured on (iGPU or dGPU on hybrid laptops)
+    gpu_adapter: Option<String>,
     status: String,
     create_time: u64,
     exe_path: Option<String>,
+    category: String,
+    description: Option<String>,
+    company: Option<String>,
+    // Exe first observed within the last NEW_BINARY_DAYS
+    is_new_binary: bool,
+    // CPU readings are not reliable yet (sysinfo needs two samples)
+    is_warming_up: bool,
+    // Display names of the services hosted by this process (svchost etc.)
+    service_names: Vec<String>,
+    // Owns at least one visible top-level window
+    has_visible_window: bool,
+    // Share of thread samples blocked on I/O or paging, single-process lookups only
+    io_wait_percent: Option<f32>,
+    // Summed over all threads, single-process lookups only (None on the first)
+    context_switches_per_sec: Option<u64>,
+    // Names of the processes that started this one, root first; single-process lookups only
+    ancestor_chain: Vec<String>,
+    // Open TCP connections / UDP endpoints (IPv4 + IPv6), single-process lookups only
+    tcp_connections: u32,
+    udp_connections: u32,
+    // GDI / USER handles in use (quota 10,000 each), single-process lookups only
+    gdi_objects: u32,
+    user_objects: u32,
+    // Read/write I/O operations per second since the previous poll
+    disk_read_ops_per_sec: f32,
+    disk_write_ops_per_sec: f32,
+    // Suspended by the OS (UWP/Store app in the background), not actually running
+    is_frozen: bool,
+    // Deterministic chart color from the name, stable as the list reorders
+    color_hex: String,
+    // Base64 PNG, only filled by get_user_apps
+    #[serde(skip_serializing_if = "Option::is_none")]
+    icon: Option<String>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
 struct SystemStats {
     cpu_percent: f32,
     memory_percent: f32,
@@ -62,12 +395,50 @@ struct SystemStats {
     used_memory_gb: f64,
     available_memory_gb: f64,
     cpu_cores: usize,
+    // CPU readings are not reliable yet (sysinfo needs two samples)
+    is_warming_up: bool,
+    // Memory pressure: high fault rates predict swapping before "available" drops
+    page_faults_per_sec: Option<u64>,
+    committed_percent: Option<f32>,
+    // Power draw in watts, None where the hardware doesn't report it
+    gpu_watts: Option<f32>,
+    cpu_watts: Option<f32>,
+    // Summed over the active non-loopback interfaces
+    network_received_bytes_per_sec: f64,
+    network_transmitted_bytes_per_sec: f64,
+    // Degrees Celsius, None where no sensor is exposed
+    cpu_temp_c: Option<f32>,
+    gpu_temp_c: Option<f32>,
+    // One entry per logical core, in sysinfo's order
+    per_core_usage: Vec<f32>,
+    cpu_frequency_mhz: Vec<u64>,
+}
+
+/// Which figure `memory_mb` reports
+#[derive(Serialize, Deserialize, Clone, Copy, Default, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum MemoryMetric {
+    // Task Manager's "Memory" column
+    PrivateWorkingSet,
+    // Private bytes committed, including memory that was never touched (PrivateUsage,
+    // what memory_mb has always reported)
+    #[default]
+    Commit,
 }
 
-/// Get Private Working Set memory for a process using Windows API
-/// This matches what Task Manager shows in the "Memory" column (Private Working Set)
+/// Memory figures for one process, in bytes
+struct ProcessMemory {
+    private_working_set: u64,
+    commit: u64,
+    // Highest values since the process started, kept by the OS
+    peak_working_set: u64,
+    peak_commit: u64,
+}
+
+/// Get Private Working Set and commit charge for a process using Windows API
+/// The private working set matches Task Manager's "Memory" column
 #[cfg(windows)]
-fn get_private_working_set(pid: u32) -> Option<u64> {
+fn get_process_memory(pid: u32) -> Option<ProcessMemory> {
     unsafe {
         let handle = OpenProcess(
             PROCESS_QUERY_INFORMATION | PROCESS_VM_READ,
@@ -75,20 +446,39 @@ fn get_private_working_set(pid: u32) -> Option<u64> {
             pid,
         ).ok()?;
 
-        let mut pmc = PROCESS_MEMORY_COUNTERS_EX::default();
-        pmc.cb = std::mem::size_of::<PROCESS_MEMORY_COUNTERS_EX>() as u32;
+        let mut pmc = PROCESS_MEMORY_COUNTERS_EX2::default();
+        let counters = &mut pmc as *mut PROCESS_MEMORY_COUNTERS_EX2 as *mut PROCESS_MEMORY_COUNTERS;
 
-        let result = GetProcessMemoryInfo(
+        let mut result = GetProcessMemoryInfo(
             handle,
-            std::mem::transmute(&mut pmc),
-            std::mem::size_of::<PROCESS_MEMORY_COUNTERS_EX>() as u32,
+            counters,
+            std::mem::size_of::<PROCESS_MEMORY_COUNTERS_EX2>() as u32,
         );
+        // EX2 needs Windows 10 1809+, older builds only fill the EX prefix
+        if result.is_err() {
+            result = GetProcessMemoryInfo(
+                handle,
+                counters,
+                std::mem::size_of::<PROCESS_MEMORY_COUNTERS_EX>() as u32,
+            );
+        }
 
         let _ = CloseHandle(handle);
 
         if result.is_ok() {
-            // PrivateUsage is the Private Working Set - exactly what Task Manager shows
-            Some(pmc.PrivateUsage as u64)
+            // PrivateUsage is the commit charge; fall back to it where the private
+            // working set isn't reported
+            let commit = pmc.PrivateUsage as u64;
+            let private_working_set = match pmc.PrivateWorkingSetSize as u64 {
+                0 => commit,
+                size => size,
+            };
+            Some(ProcessMemory {
+                private_working_set,
+                commit,
+                peak_working_set: pmc.PeakWorkingSetSize as u64,
+                peak_commit: pmc.PeakPagefileUsage as u64,
+            })
         } else {
             None
         }
@@ -96,56 +486,188 @@ fn get_private_working_set(pid: u32) -> Option<u64> {
 }
 
 #[cfg(not(windows))]
-fn get_private_working_set(_pid: u32) -> Option<u64> {
+fn get_process_memory(_pid: u32) -> Option<ProcessMemory> {
     None
 }
 
-/// Get GPU usage per process using NVML (NVIDIA only)
-/// Returns a HashMap of PID -> GPU utilization percentage
+/// Per-process GPU data from one NVML query
+#[derive(Default, Clone)]
+struct GpuProcessUsage {
+    // PID -> GPU utilization percentage
+    utilization: HashMap<u32, f32>,
+    // PID -> dedicated GPU memory in bytes
+    memory_bytes: HashMap<u32, u64>,
+    // PID -> name of the NVIDIA device its utilization comes from (the busiest one)
+    adapters: HashMap<u32, String>,
+}
+
+// GPU utilization smoothing - NVML's first utilization reading after init can be spurious and
+// the even split across processes amplifies it, so it is discarded and later readings go through an EMA
+const DEFAULT_GPU_SMOOTHING_ALPHA: f32 = 0.5;
+static GPU_SMOOTHING_ALPHA: AtomicU32 = AtomicU32::new(0x3F00_0000); // 0.5f32
+// Adapter UUID -> smoothed utilization, None while the first reading is discarded
 #[cfg(windows)]
-fn get_gpu_usage_per_process() -> HashMap<u32, f32> {
-    let mut gpu_usage: HashMap<u32, f32> = HashMap::new();
+static GPU_UTILIZATION_EMA: Mutex<std::collections::BTreeMap<String, Option<f32>>> =
+    Mutex::new(std::collections::BTreeMap::new());
 
-    // Try to initialize NVML
-    let nvml = match Nvml::init() {
-        Ok(nvml) => nvml,
-        Err(_) => return gpu_usage, // No NVIDIA GPU or driver not installed
-    };
+/// Feed one utilization reading for `adapter`, None for the discarded first reading
+#[cfg(windows)]
+fn smooth_gpu_utilization(adapter: &str, reading: f32) -> Option<f32> {
+    let alpha = f32::from_bits(GPU_SMOOTHING_ALPHA.load(Ordering::SeqCst));
+    let mut ema = GPU_UTILIZATION_EMA.lock().unwrap();
+    match ema.get_mut(adapter) {
+        None => {
+            ema.insert(adapter.to_string(), None);
+            None
+        }
+        Some(slot @ None) => {
+            *slot = Some(reading);
+            Some(reading)
+        }
+        Some(Some(value)) => {
+            *value = alpha * reading + (1.0 - alpha) * *value;
+            Some(*value)
+        }
+    }
+}
+
+/// EMA weight of the newest GPU utilization reading, 1 = no smoothing (persisted)
+#[tauri::command]
+fn set_gpu_smoothing_alpha(state: State<AppState>, alpha: f32) -> Result<(), String> {
+    if !(alpha > 0.0 && alpha <= 1.0) {
+        return Err("alpha must be greater than 0 and at most 1".to_string());
+    }
+    GPU_SMOOTHING_ALPHA.store(alpha.to_bits(), Ordering::SeqCst);
+    update_app_data(&state, |data| data.gpu_smoothing_alpha = Some(alpha))
+}
+
+#[tauri::command]
+fn get_gpu_smoothing_alpha() -> f32 {
+    f32::from_bits(GPU_SMOOTHING_ALPHA.load(Ordering::SeqCst))
+}
+
+/// Per-PID SM utilization since the last read through this cursor (adapter UUID -> newest
+/// sample timestamp seen), None when the driver doesn't support per-process accounting
+#[cfg(windows)]
+fn read_process_utilization(
+    device: &nvml_wrapper::Device,
+    adapter_key: &str,
+    timestamps: &mut HashMap<String, u64>,
+) -> Option<HashMap<u32, f32>> {
+    use nvml_wrapper::error::NvmlError;
 
-    // Get first GPU (device 0)
-    let device = match nvml.device_by_index(0) {
-        Ok(device) => device,
-        Err(_) => return gpu_usage,
+    let last_seen = timestamps.get(adapter_key).copied();
+    let samples = match device.process_utilization_stats(last_seen) {
+        Ok(samples) => samples,
+        // No process ran on the GPU since the last sample
+        Err(NvmlError::NotFound) => return Some(HashMap::new()),
+        Err(_) => return None,
     };
 
+    // A PID can have several samples in the window, keep the newest one
+    let mut newest: HashMap<u32, (u64, f32)> = HashMap::new();
+    for sample in &samples {
+        let entry = newest.entry(sample.pid).or_insert((0, 0.0));
+        if sample.timestamp >= entry.0 {
+            *entry = (sample.timestamp, sample.sm_util as f32);
+        }
+    }
+    if let Some(latest) = samples.iter().map(|s| s.timestamp).max() {
+        timestamps.insert(adapter_key.to_string(), latest);
+    }
+    Some(newest.into_iter().map(|(pid, (_, util))| (pid, util)).collect())
+}
+
+/// Load NVML once for the lifetime of the app, init is too slow to repeat every poll
+#[cfg(windows)]
+fn init_nvml() -> Option<Nvml> {
+    match Nvml::init() {
+        Ok(nvml) => Some(nvml),
+        Err(e) => {
+            log_info!("NVML unavailable, NVIDIA GPU metrics disabled: {}", e);
+            None
+        }
+    }
+}
+
+/// Per-PID utilization and memory on one NVIDIA device. `smooth` feeds the overall reading
+/// through the shared per-adapter EMA, which only the main poll may advance
+#[cfg(windows)]
+fn device_process_usage(
+    device: &nvml_wrapper::Device,
+    cursor: &mut HashMap<String, u64>,
+    smooth: bool,
+) -> (HashMap<u32, f32>, HashMap<u32, u64>) {
+    use nvml_wrapper::enums::device::UsedGpuMemory;
+
+    let mut utilization = HashMap::new();
+    let mut memory_bytes = HashMap::new();
+
+    let adapter_key = device.uuid().unwrap_or_else(|_| "0".to_string());
+    let sampled = read_process_utilization(device, &adapter_key, cursor);
+
     // Get running compute processes
     if let Ok(processes) = device.running_compute_processes() {
         for proc in processes {
-            // NVML doesn't give per-process GPU utilization directly
-            // We can only get memory usage per process
-            // For utilization, we'll use the overall GPU utilization divided by process count
-            gpu_usage.insert(proc.pid, 0.0);
+            let util = sampled.as_ref().and_then(|s| s.get(&proc.pid)).copied().unwrap_or(0.0);
+            utilization.insert(proc.pid, util);
+            if let UsedGpuMemory::Used(bytes) = proc.used_gpu_memory {
+                *memory_bytes.entry(proc.pid).or_insert(0) += bytes;
+            }
         }
     }
 
     // Get running graphics processes
     if let Ok(processes) = device.running_graphics_processes() {
-        let process_count = processes.len() as f32;
-
-        // Get overall GPU utilization
-        let overall_util = device.utilization_rates()
-            .map(|u| u.gpu as f32)
-            .unwrap_or(0.0);
-
-        // Distribute utilization among graphics processes (rough approximation)
-        let per_process_util = if process_count > 0.0 {
-            overall_util / process_count
+        // Without per-process accounting, fall back to splitting the overall utilization
+        // (warm-up discarded and smoothed per adapter) evenly across graphics processes
+        let per_process_util = if sampled.is_none() && !processes.is_empty() {
+            device.utilization_rates()
+                .ok()
+                .and_then(|u| if smooth { smooth_gpu_utilization(&adapter_key, u.gpu as f32) } else { Some(u.gpu as f32) })
+                .unwrap_or(0.0) / processes.len() as f32
         } else {
             0.0
         };
 
         for proc in processes {
-            gpu_usage.insert(proc.pid, per_process_util);
+            let util = match &sampled {
+                Some(sampled) => sampled.get(&proc.pid).copied().unwrap_or(0.0),
+                None => per_process_util,
+            };
+            utilization.insert(proc.pid, util);
+            if let UsedGpuMemory::Used(bytes) = proc.used_gpu_memory {
+                *memory_bytes.entry(proc.pid).or_insert(0) += bytes;
+            }
+        }
+    }
+
+    (utilization, memory_bytes)
+}
+
+/// Get GPU usage per process using NVML (NVIDIA only), merged over every device:
+/// memory is summed, utilization is the busiest device's. Samples are read since `cursor`
+#[cfg(windows)]
+fn query_gpu_usage(state: &AppState, cursor: &mut HashMap<String, u64>, smooth: bool) -> GpuProcessUsage {
+    let mut gpu_usage = GpuProcessUsage::default();
+
+    // No NVIDIA GPU or driver not installed
+    let Some(nvml) = state.nvml.as_ref() else { return gpu_usage };
+
+    for index in 0..nvml.device_count().unwrap_or(0) {
+        let Ok(device) = nvml.device_by_index(index) else { continue };
+        let name = device.name().unwrap_or_else(|_| format!("GPU {}", index));
+        let (utilization, memory_bytes) = device_process_usage(&device, cursor, smooth);
+
+        for (pid, util) in utilization {
+            let busier = gpu_usage.utilization.get(&pid).is_none_or(|&current| util > current);
+            if busier {
+                gpu_usage.utilization.insert(pid, util);
+                gpu_usage.adapters.insert(pid, name.clone());
+            }
+        }
+        for (pid, bytes) in memory_bytes {
+            *gpu_usage.memory_bytes.entry(pid).or_insert(0) += bytes;
         }
     }
 
@@ -153,10 +675,188 @@ fn get_gpu_usage_per_process() -> HashMap<u32, f32> {
 }
 
 #[cfg(not(windows))]
-fn get_gpu_usage_per_process() -> HashMap<u32, f32> {
+fn query_gpu_usage(_state: &AppState, _cursor: &mut HashMap<String, u64>, _smooth: bool) -> GpuProcessUsage {
+    GpuProcessUsage::default()
+}
+
+/// GPU usage for the main process poll - the only caller that advances the shared sample
+/// cursor and EMA. The result is cached for everything else, see cached_gpu_usage
+fn get_gpu_usage_per_process(state: &AppState) -> GpuProcessUsage {
+    let gpu = query_gpu_usage(state, &mut state.gpu_sample_timestamps.lock().unwrap(), true);
+    *state.gpu_usage.lock().unwrap() = gpu.clone();
+    gpu
+}
+
+/// GPU usage as of the last process poll, reading NVML here would take samples from it
+fn cached_gpu_usage(state: &AppState) -> GpuProcessUsage {
+    state.gpu_usage.lock().unwrap().clone()
+}
+
+/// PIDs owning at least one visible top-level window, one EnumWindows pass
+#[cfg(windows)]
+fn visible_window_pids() -> std::collections::HashSet<u32> {
+    use std::collections::HashSet;
+    use windows::Win32::Foundation::{BOOL, HWND, LPARAM};
+    use windows::Win32::UI::WindowsAndMessaging::{EnumWindows, GetWindowThreadProcessId, IsWindowVisible};
+
+    unsafe extern "system" fn collect(hwnd: HWND, lparam: LPARAM) -> BOOL {
+        let pids = &mut *(lparam.0 as *mut HashSet<u32>);
+        if IsWindowVisible(hwnd).as_bool() {
+            let mut pid = 0u32;
+            GetWindowThreadProcessId(hwnd, Some(&mut pid));
+            if pid != 0 {
+                pids.insert(pid);
+            }
+        }
+        BOOL(1)
+    }
+
+    let mut pids: HashSet<u32> = HashSet::new();
+    unsafe {
+        let _ = EnumWindows(Some(collect), LPARAM(&mut pids as *mut _ as isize));
+    }
+    pids
+}
+
+#[cfg(not(windows))]
+fn visible_window_pids() -> std::collections::HashSet<u32> {
+    std::collections::HashSet::new()
+}
+
+/// Set `has_visible_window` from a single window enumeration per cycle
+fn mark_visible_windows(processes: &mut [ProcessInfo]) {
+    let pids = visible_window_pids();
+    for process in processes.iter_mut() {
+        process.has_visible_window = pids.contains(&process.pid);
+    }
+}
+
+// IOPS - read/write operation counters diffed between polls
+struct IoOpsSample {
+    create_time: u64,
+    read_ops: u64,
+    write_ops: u64,
+    at: Instant,
+}
+
+/// The system process list for one poll (NtQuerySystemInformation), shared by every
+/// pass that needs it so each poll makes the call once. None when unavailable
+#[cfg(windows)]
+fn query_process_list() -> Option<Vec<u64>> {
+    ntdll::query_process_list()
+}
+
+#[cfg(not(windows))]
+fn query_process_list() -> Option<Vec<u64>> {
+    None
+}
+
+/// PID -> (read operations, write operations) since process start
+#[cfg(windows)]
+fn read_io_operation_counts(process_list: Option<&[u64]>) -> HashMap<u32, (u64, u64)> {
+    // One NtQuerySystemInformation call covers every process, no per-PID handles
+    let Some(buffer) = process_list else { return HashMap::new() };
+    ntdll::processes(buffer)
+        .into_iter()
+        .map(|entry| (
+            entry.unique_process_id as u32,
+            (entry.read_operation_count as u64, entry.write_operation_count as u64),
+        ))
+        .collect()
+}
+
+#[cfg(not(windows))]
+fn read_io_operation_counts(_process_list: Option<&[u64]>) -> HashMap<u32, (u64, u64)> {
     HashMap::new()
 }
 
+/// Set the per-second IOPS fields from the change since the previous poll
+fn attach_io_ops(state: &AppState, processes: &mut [ProcessInfo], process_list: Option<&[u64]>) {
+    let counts = read_io_operation_counts(process_list);
+    let now = Instant::now();
+    let mut samples = state.io_ops.lock().unwrap();
+    let alive: std::collections::HashSet<u32> = processes.iter().map(|p| p.pid).collect();
+    samples.retain(|pid, _| alive.contains(pid));
+
+    for process in processes.iter_mut() {
+        let Some(&(read_ops, write_ops)) = counts.get(&process.pid) else { continue };
+        let current = IoOpsSample { create_time: process.create_time, read_ops, write_ops, at: now };
+
+        if let Some(previous) = samples.insert(process.pid, current) {
+            let secs = (now - previous.at).as_secs_f64();
+            // Skip the first sample after a PID was reused
+            if previous.create_time == process.create_time && secs > 0.0 {
+                let rate = |now: u64, before: u64| (now.saturating_sub(before) as f64 / secs) as f32;
+                process.disk_read_ops_per_sec = round_metric_f32(rate(read_ops, previous.read_ops));
+                process.disk_write_ops_per_sec = round_metric_f32(rate(write_ops, previous.write_ops));
+            }
+        }
+    }
+}
+
+// UWP frozen state - the OS suspends Store apps in the background
+/// PIDs the OS has frozen (IsFrozen in the extended basic information)
+#[cfg(windows)]
+fn frozen_process_pids(process_list: Option<&[u64]>) -> std::collections::HashSet<u32> {
+    use windows::Win32::System::Threading::PROCESS_QUERY_LIMITED_INFORMATION;
+
+    let Some(buffer) = process_list else { return Default::default() };
+
+    // Only processes whose threads are all suspended can be frozen, which
+    // keeps the per-process handle opens down to a handful each poll
+    let candidates: Vec<u32> = ntdll::processes(buffer)
+        .into_iter()
+        .filter(|entry| entry.unique_process_id != 0 && entry.number_of_threads > 0)
+        .filter(|entry| {
+            ntdll::threads(entry).iter().all(|thread| {
+                thread.thread_state == ntdll::THREAD_STATE_WAITING && thread.wait_reason == ntdll::WAIT_REASON_SUSPENDED
+            })
+        })
+        .map(|entry| entry.unique_process_id as u32)
+        .collect();
+
+    candidates
+        .into_iter()
+        .filter(|&pid| unsafe {
+            let Ok(handle) = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid) else { return false };
+            let mut info = ntdll::ProcessExtendedBasicInformation {
+                size: std::mem::size_of::<ntdll::ProcessExtendedBasicInformation>(),
+                ..Default::default()
+            };
+            let status = ntdll::NtQueryInformationProcess(
+                handle,
+                ntdll::PROCESS_BASIC_INFORMATION,
+                &mut info as *mut _ as *mut _,
+                std::mem::size_of::<ntdll::ProcessExtendedBasicInformation>() as u32,
+                std::ptr::null_mut(),
+            );
+            let _ = CloseHandle(handle);
+            status >= 0 && info.flags & ntdll::PROCESS_FLAG_IS_FROZEN != 0
+        })
+        .collect()
+}
+
+#[cfg(not(windows))]
+fn frozen_process_pids(_process_list: Option<&[u64]>) -> std::collections::HashSet<u32> {
+    std::collections::HashSet::new()
+}
+
+/// Set `is_frozen` from the poll's process list
+fn mark_frozen_processes(processes: &mut [ProcessInfo], process_list: Option<&[u64]>) {
+    let frozen = frozen_process_pids(process_list);
+    for process in processes.iter_mut() {
+        process.is_frozen = frozen.contains(&process.pid);
+    }
+}
+
+/// PIDs of processes the OS currently holds suspended (background Store apps)
+#[tauri::command]
+fn get_frozen_processes() -> Vec<u32> {
+    let mut pids: Vec<u32> = frozen_process_pids(query_process_list().as_deref()).into_iter().collect();
+    pids.sort_unstable();
+    pids
+}
+
 /// Get the process ID of the foreground window
 #[cfg(windows)]
 fn get_foreground_process_id() -> Option<u32> {
@@ -179,8 +879,25 @@ fn get_foreground_process_id() -> Option<u32> {
     None
 }
 
+/// Full exe path of a process without going through sysinfo
+#[cfg(windows)]
+fn process_image_path(pid: u32) -> Option<String> {
+    use windows::core::PWSTR;
+    use windows::Win32::System::Threading::{QueryFullProcessImageNameW, PROCESS_NAME_WIN32, PROCESS_QUERY_LIMITED_INFORMATION};
+
+    unsafe {
+        let handle = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid).ok()?;
+        let mut buffer = [0u16; 1024];
+        let mut size = buffer.len() as u32;
+        let result = QueryFullProcessImageNameW(handle, PROCESS_NAME_WIN32, PWSTR(buffer.as_mut_ptr()), &mut size);
+        let _ = CloseHandle(handle);
+        result.ok()?;
+        Some(String::from_utf16_lossy(&buffer[..size as usize]))
+    }
+}
+
 // Static state for tracking activity between calls
-use std::sync::atomic::{AtomicU32, AtomicI32, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicI32, AtomicU64, Ordering};
 
 // Keyboard hook click counter - incremented by low-level keyboard hook
 static KEYBOARD_HOOK_CLICKS: AtomicU32 = AtomicU32::new(0);
@@ -189,6 +906,36 @@ static MOUSE_DISTANCE: AtomicU32 = AtomicU32::new(0);
 // Previous cursor position for movement calculation
 static PREV_CURSOR_X: AtomicI32 = AtomicI32::new(0);
 static PREV_CURSOR_Y: AtomicI32 = AtomicI32::new(0);
+// Display scale (f32 bits, 1.0 = 96 DPI) - mouse distance is divided by this
+// so the same hand movement scores the same on 1080p and 4K displays
+static MOUSE_DPI_SCALE: AtomicU32 = AtomicU32::new(0x3F80_0000); // 1.0f32
+// Exe names that count toward activity, empty = every app
+static ACTIVITY_APP_FILTER: Mutex<Vec<String>> = Mutex::new(Vec::new());
+// Our own PID, cached for the hook thread, and whether input to our own window is ignored
+// (otherwise using Performance Guard inflates activity of whatever was foreground before)
+static OWN_PID: AtomicU32 = AtomicU32::new(0);
+static EXCLUDE_OWN_INPUT: AtomicBool = AtomicBool::new(true);
+
+/// Is Performance Guard itself foreground while own input is excluded
+#[cfg(windows)]
+fn is_own_input() -> bool {
+    EXCLUDE_OWN_INPUT.load(Ordering::SeqCst)
+        && get_foreground_process_id() == Some(OWN_PID.load(Ordering::SeqCst))
+}
+
+/// System DPI scale relative to 96 DPI
+#[cfg(windows)]
+fn detect_screen_scale() -> f32 {
+    use windows::Win32::UI::HiDpi::GetDpiForSystem;
+
+    let dpi = unsafe { GetDpiForSystem() };
+    if dpi > 0 { dpi as f32 / 96.0 } else { 1.0 }
+}
+
+#[cfg(not(windows))]
+fn detect_screen_scale() -> f32 {
+    1.0
+}
 
 // Low-level input hooks for accurate activity tracking
 // Both keyboard and mouse hooks need a message loop to work properly
@@ -216,7 +963,7 @@ mod input_hooks {
     ) -> LRESULT {
         if code >= 0 {
             let msg = wparam.0 as u32;
-            if msg == WM_KEYDOWN || msg == WM_SYSKEYDOWN {
+            if (msg == WM_KEYDOWN || msg == WM_SYSKEYDOWN) && !is_own_input() {
                 KEYBOARD_HOOK_CLICKS.fetch_add(1, Ordering::SeqCst);
             }
         }
@@ -237,7 +984,8 @@ mod input_hooks {
             let prev_y = PREV_CURSOR_Y.swap(y, Ordering::SeqCst);
 
             // Calculate distance if we have previous position
-            if prev_x != 0 || prev_y != 0 {
+            // (position is still tracked while excluded so there's no jump afterwards)
+            if (prev_x != 0 || prev_y != 0) && !is_own_input() {
                 let dx = (x - prev_x) as f32;
                 let dy = (y - prev_y) as f32;
                 let dist = (dx * dx + dy * dy).sqrt() as u32;
@@ -273,11 +1021,11 @@ mod input_hooks {
                 );
 
                 // Log errors only
-                if kb_hook.is_err() {
-                    eprintln!("[ERROR] Failed to install keyboard hook");
+                if let Err(e) = kb_hook {
+                    log_error!("Failed to install keyboard hook: {}", e);
                 }
-                if mouse_hook.is_err() {
-                    eprintln!("[ERROR] Failed to install mouse hook");
+                if let Err(e) = mouse_hook {
+                    log_error!("Failed to install mouse hook: {}", e);
                 }
 
                 // Message loop - required for low-level hooks to work
@@ -297,6 +1045,18 @@ struct RawActivityData {
     mouse_pixels: u32,
 }
 
+/// True when the app filter is empty or the foreground process is on it
+#[cfg(windows)]
+fn foreground_passes_activity_filter() -> bool {
+    let filter = ACTIVITY_APP_FILTER.lock().unwrap();
+    if filter.is_empty() {
+        return true;
+    }
+    get_foreground_process_id()
+        .and_then(process_image_path)
+        .is_some_and(|path| filter.iter().any(|name| same_process_name(name, &path)))
+}
+
 /// Get global user activity (keyboard/mouse) - call ONCE per polling cycle
 /// Returns activity percentage (0-100) combining:
 /// - Keyboard presses: up to 100% (12 keystrokes per 2 seconds = 100%)
@@ -306,7 +1066,20 @@ struct RawActivityData {
 fn calculate_global_activity() -> RawActivityData {
     // Get and reset counters - both hooks capture input continuously
     let clicks = KEYBOARD_HOOK_CLICKS.swap(0, Ordering::SeqCst);
-    let total_mouse_dist = MOUSE_DISTANCE.swap(0, Ordering::SeqCst);
+    let raw_mouse_dist = MOUSE_DISTANCE.swap(0, Ordering::SeqCst);
+
+    // App filter: input only scores while a listed app is in the foreground
+    if !foreground_passes_activity_filter() {
+        return RawActivityData {
+            activity_percent: 0.0,
+            keyboard_clicks: 0,
+            mouse_pixels: 0,
+        };
+    }
+
+    // Normalize to 96-DPI pixels so scoring is resolution-independent
+    let dpi_scale = f32::from_bits(MOUSE_DPI_SCALE.load(Ordering::SeqCst));
+    let total_mouse_dist = (raw_mouse_dist as f32 / dpi_scale) as u32;
 
     // Calculate activity scores:
     // - Keyboard: 12 keystrokes in 2 seconds = 100% (can reach 100% alone)
@@ -343,6 +1116,8 @@ struct UserActivityResult {
 struct GlobalActivityResult {
     activity_percent: f32,
     foreground_pid: Option<u32>,
+    // Name of the foreground app, None when it's this app or unknown
+    foreground_app: Option<String>,
     keyboard_clicks: u32,
     mouse_pixels: u32,
 }
@@ -350,18 +1125,138 @@ struct GlobalActivityResult {
 /// Get global activity and foreground PID - call ONCE per polling cycle
 /// This resets the input counters, so it should only be called once
 #[tauri::command]
-fn get_global_activity() -> GlobalActivityResult {
+fn get_global_activity(state: State<AppState>) -> GlobalActivityResult {
     let raw = calculate_global_activity();
     let foreground_pid = get_foreground_process_id();
+    let foreground_app = foreground_app(&state).map(|(name, _)| name);
+    record_daily_activity(&state, raw.activity_percent, foreground_app.as_deref());
 
     GlobalActivityResult {
         activity_percent: raw.activity_percent,
         foreground_pid,
+        foreground_app,
         keyboard_clicks: raw.keyboard_clicks,
         mouse_pixels: raw.mouse_pixels,
     }
 }
 
+// Daily activity - each activity poll adds its elapsed time to today's active or idle
+// total; active time is also credited to the foreground app
+const DAILY_ACTIVE_THRESHOLD_PERCENT: f32 = 5.0;
+// Longer gaps (sleep, hidden window) are not counted as either
+const DAILY_MAX_SAMPLE_GAP_SECS: f64 = 30.0;
+const DAILY_ACTIVITY_PERSIST_SECS: u64 = 60;
+const DAILY_ACTIVITY_KEEP_DAYS: usize = 90;
+const DAILY_TOP_APPS: usize = 10;
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+struct DayActivity {
+    active_seconds: f64,
+    idle_seconds: f64,
+    // App name -> active seconds while it was in the foreground
+    apps: HashMap<String, f64>,
+}
+
+struct DailyActivityTracker {
+    days: std::collections::BTreeMap<String, DayActivity>,
+    last_sample: Option<Instant>,
+    last_persist: Instant,
+}
+
+#[derive(Serialize)]
+struct DailySummary {
+    date: String,
+    active_seconds: i64,
+    idle_seconds: i64,
+    // (app name, active seconds), most used first
+    top_apps: Vec<(String, i64)>,
+}
+
+fn record_daily_activity(state: &AppState, activity_percent: f32, foreground: Option<&str>) {
+    let now = Instant::now();
+    let active = activity_percent > DAILY_ACTIVE_THRESHOLD_PERCENT;
+
+    let mut tracker = state.daily_activity.lock().unwrap();
+    let elapsed = tracker.last_sample.replace(now).map(|last| (now - last).as_secs_f64());
+    let Some(elapsed) = elapsed.filter(|&secs| secs <= DAILY_MAX_SAMPLE_GAP_SECS) else { return };
+
+    // Keyed by local date, so a new day starts from zero at midnight
+    let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+    let day = tracker.days.entry(today).or_default();
+    if active {
+        day.active_seconds += elapsed;
+        if let Some(name) = foreground {
+            *day.apps.entry(name.to_string()).or_insert(0.0) += elapsed;
+        }
+    } else {
+        day.idle_seconds += elapsed;
+    }
+
+    while tracker.days.len() > DAILY_ACTIVITY_KEEP_DAYS {
+        tracker.days.pop_first();
+    }
+
+    if tracker.last_persist.elapsed().as_secs() >= DAILY_ACTIVITY_PERSIST_SECS {
+        tracker.last_persist = now;
+        drop(tracker);
+        if let Err(e) = persist_daily_activity(state) {
+            log_error!("Failed to save daily activity: {}", e);
+        }
+    }
+}
+
+/// Write the in-memory totals. They're read under the data file lock, after a recovery
+/// from backup has reloaded them, so the recovered totals aren't overwritten
+fn persist_daily_activity(state: &AppState) -> Result<(), String> {
+    update_app_data(state, |data| data.daily_activity = state.daily_activity.lock().unwrap().days.clone())
+}
+
+/// Start the accumulator over from `days` whenever the data file is replaced (import, reset,
+/// recovery from backup), so the next save doesn't write the old totals back over it
+fn reload_daily_activity(state: &AppState, days: &std::collections::BTreeMap<String, DayActivity>) {
+    let mut tracker = state.daily_activity.lock().unwrap();
+    tracker.days = days.clone();
+    tracker.last_sample = None;
+}
+
+/// Write the in-memory totals now instead of waiting for the next periodic save
+fn flush_daily_activity(state: &AppState) {
+    if let Err(e) = persist_daily_activity(state) {
+        log_error!("Failed to save daily activity: {}", e);
+    }
+}
+
+/// Active/idle time and top foreground apps for a local date (YYYY-MM-DD, default today)
+#[tauri::command]
+fn get_daily_activity_summary(state: State<AppState>, date: Option<String>) -> Result<DailySummary, String> {
+    let date = match date {
+        Some(date) => chrono::NaiveDate::parse_from_str(&date, "%Y-%m-%d")
+            .map_err(|_| format!("Invalid date (expected YYYY-MM-DD): {}", date))?
+            .format("%Y-%m-%d")
+            .to_string(),
+        None => chrono::Local::now().format("%Y-%m-%d").to_string(),
+    };
+
+    let tracker = state.daily_activity.lock().unwrap();
+    let day = tracker.days.get(&date).cloned().unwrap_or_default();
+    drop(tracker);
+
+    let mut top_apps: Vec<(String, i64)> = day
+        .apps
+        .into_iter()
+        .map(|(name, seconds)| (name, seconds.round() as i64))
+        .collect();
+    top_apps.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    top_apps.truncate(DAILY_TOP_APPS);
+
+    Ok(DailySummary {
+        date,
+        active_seconds: day.active_seconds.round() as i64,
+        idle_seconds: day.idle_seconds.round() as i64,
+        top_apps,
+    })
+}
+
 /// Check if any of the given PIDs is the foreground window
 /// Does NOT reset activity counters - safe to call multiple times
 #[tauri::command]
@@ -372,6 +1267,50 @@ fn check_foreground(pids: Vec<u32>) -> bool {
         .unwrap_or(false)
 }
 
+/// Override the display scale used to normalize mouse distance (1.0 = 96 DPI)
+/// Pass None to go back to auto-detection
+#[tauri::command]
+fn set_screen_scale(state: State<AppState>, dpi_scale: Option<f32>) -> Result<(), String> {
+    if let Some(scale) = dpi_scale {
+        if scale.is_nan() || scale <= 0.0 {
+            return Err("Scale must be greater than 0".to_string());
+        }
+    }
+
+    let scale = dpi_scale.unwrap_or_else(detect_screen_scale);
+    MOUSE_DPI_SCALE.store(scale.to_bits(), Ordering::SeqCst);
+    update_app_data(&state, |data| data.screen_scale = dpi_scale)
+}
+
+/// Ignore keyboard/mouse input while Performance Guard is the foreground app (persisted)
+#[tauri::command]
+fn set_exclude_own_input(state: State<AppState>, enabled: bool) -> Result<(), String> {
+    EXCLUDE_OWN_INPUT.store(enabled, Ordering::SeqCst);
+    update_app_data(&state, |data| data.exclude_own_input = Some(enabled))
+}
+
+#[tauri::command]
+fn get_exclude_own_input() -> bool {
+    EXCLUDE_OWN_INPUT.load(Ordering::SeqCst)
+}
+
+/// Only count activity while one of these apps is in the foreground, empty = all (persisted)
+#[tauri::command]
+fn set_activity_app_filter(state: State<AppState>, exe_names: Vec<String>) -> Result<(), String> {
+    let exe_names: Vec<String> = exe_names
+        .into_iter()
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .collect();
+    *ACTIVITY_APP_FILTER.lock().unwrap() = exe_names.clone();
+    update_app_data(&state, |data| data.activity_app_filter = exe_names)
+}
+
+#[tauri::command]
+fn get_activity_app_filter() -> Vec<String> {
+    ACTIVITY_APP_FILTER.lock().unwrap().clone()
+}
+
 /// Legacy function - now just checks foreground status
 /// Activity should be obtained via get_global_activity() once per cycle
 #[tauri::command]
@@ -386,154 +1325,2426 @@ fn get_user_activity(pids: Vec<u32>) -> UserActivityResult {
     UserActivityResult { activity_percent: 0.0, is_foreground }
 }
 
-#[tauri::command]
-fn get_processes(state: State<AppState>) -> Vec<ProcessInfo> {
-    let mut system = state.system.lock().unwrap();
-    // Clear and refresh processes to ensure dead processes are removed
-    // refresh_all() keeps dead processes in cache, so we need refresh_processes()
-    system.refresh_processes(sysinfo::ProcessesToUpdate::All, true);
+#[cfg(windows)]
+fn to_wide(s: &str) -> Vec<u16> {
+    OsStr::new(s).encode_wide().chain(std::iter::once(0)).collect()
+}
 
-    // Get CPU core count for normalization (sysinfo reports per-core CPU usage)
-    let cpu_cores = system.cpus().len() as f32;
-    let cpu_divisor = if cpu_cores > 0.0 { cpu_cores } else { 1.0 };
+// Minimal registry helpers (values are read/written as raw bytes)
+#[cfg(windows)]
+mod registry {
+    use super::to_wide;
+    use windows::core::{PCWSTR, PWSTR};
+    use windows::Win32::Foundation::{ERROR_ACCESS_DENIED, ERROR_NO_MORE_ITEMS, ERROR_SUCCESS, WIN32_ERROR};
+    use windows::Win32::System::Registry::{
+        RegCloseKey, RegCreateKeyExW, RegEnumKeyExW, RegEnumValueW, RegOpenKeyExW, RegSetValueExW, HKEY, KEY_READ,
+        KEY_SET_VALUE, REG_OPTION_NON_VOLATILE, REG_VALUE_TYPE,
+    };
 
-    let total_memory = system.total_memory();
+    pub struct RegValue {
+        pub name: String,
+        pub data: Vec<u8>,
+    }
 
-    // Get GPU usage per process
-    let gpu_usage = get_gpu_usage_per_process();
+    fn describe(status: WIN32_ERROR) -> String {
+        if status == ERROR_ACCESS_DENIED {
+            "Access denied (try running as administrator)".to_string()
+        } else {
+            windows::core::Error::from(status.to_hresult()).message().to_string()
+        }
+    }
 
-    let mut processes: Vec<ProcessInfo> = system
-        .processes()
-        .iter()
-        .map(|(pid, process)| {
-            let pid_u32 = pid.as_u32();
+    /// All values of a key, empty if the key doesn't exist
+    pub fn values(root: HKEY, path: &str) -> Vec<RegValue> {
+        let wide_path = to_wide(path);
+        let mut key = HKEY::default();
+        let status = unsafe { RegOpenKeyExW(root, PCWSTR::from_raw(wide_path.as_ptr()), 0, KEY_READ, &mut key) };
+        if status != ERROR_SUCCESS {
+            return Vec::new();
+        }
 
-            // Try to get accurate memory from Windows API, fallback to sysinfo
-            let memory_bytes = get_private_working_set(pid_u32)
-                .unwrap_or_else(|| process.memory());
+        let mut values = Vec::new();
+        let mut index = 0;
+        loop {
+            let mut name = vec![0u16; 1024];
+            let mut name_len = name.len() as u32;
+            let mut data = vec![0u8; 8192];
+            let mut data_len = data.len() as u32;
 
-            let memory_percent = if total_memory > 0 {
-                (memory_bytes as f64 / total_memory as f64 * 100.0) as f32
-            } else {
-                0.0
+            let status = unsafe {
+                RegEnumValueW(
+                    key,
+                    index,
+                    PWSTR(name.as_mut_ptr()),
+                    &mut name_len,
+                    None,
+                    None,
+                    Some(data.as_mut_ptr()),
+                    Some(&mut data_len),
+                )
             };
+            if status == ERROR_NO_MORE_ITEMS {
+                break;
+            }
+            index += 1;
+            if status != ERROR_SUCCESS {
+                // Oversized value, skip it
+                continue;
+            }
 
-            // Normalize CPU usage by dividing by core count
-            // sysinfo returns per-core percentage (can exceed 100% on multi-core)
-            // We want total system percentage (0-100%)
-            let normalized_cpu = process.cpu_usage() / cpu_divisor;
+            data.truncate(data_len as usize);
+            values.push(RegValue {
+                name: String::from_utf16_lossy(&name[..name_len as usize]),
+                data,
+            });
+        }
 
-            // Convert bytes to MB
-            let memory_mb = memory_bytes as f64 / (1024.0 * 1024.0);
+        unsafe {
+            let _ = RegCloseKey(key);
+        }
+        values
+    }
 
-            // Get GPU usage for this process (0 if not using GPU)
-            let gpu_percent = gpu_usage.get(&pid_u32).copied().unwrap_or(0.0);
+    /// Names of a key's direct subkeys, empty if the key doesn't exist
+    pub fn subkeys(root: HKEY, path: &str) -> Vec<String> {
+        let wide_path = to_wide(path);
+        let mut key = HKEY::default();
+        let status = unsafe { RegOpenKeyExW(root, PCWSTR::from_raw(wide_path.as_ptr()), 0, KEY_READ, &mut key) };
+        if status != ERROR_SUCCESS {
+            return Vec::new();
+        }
 
-            ProcessInfo {
-                pid: pid_u32,
-                name: process.name().to_string_lossy().to_string(),
-                cpu_percent: normalized_cpu,
-                memory_mb,
-                memory_percent,
-                gpu_percent,
-                status: format!("{:?}", process.status()),
-                create_time: process.start_time(),
-                exe_path: process.exe().map(|p| p.to_string_lossy().to_string()),
+        let mut names = Vec::new();
+        let mut index = 0;
+        loop {
+            let mut name = vec![0u16; 1024];
+            let mut name_len = name.len() as u32;
+            let status = unsafe {
+                RegEnumKeyExW(key, index, PWSTR(name.as_mut_ptr()), &mut name_len, None, PWSTR::null(), None, None)
+            };
+            if status == ERROR_NO_MORE_ITEMS {
+                break;
             }
-        })
-        .collect();
+            index += 1;
+            if status == ERROR_SUCCESS {
+                names.push(String::from_utf16_lossy(&name[..name_len as usize]));
+            }
+        }
+
+        unsafe {
+            let _ = RegCloseKey(key);
+        }
+        names
+    }
 
-    // Sort by CPU usage descending
-    processes.sort_by(|a, b| b.cpu_percent.partial_cmp(&a.cpu_percent).unwrap_or(std::cmp::Ordering::Equal));
+    pub fn value(root: HKEY, path: &str, name: &str) -> Option<Vec<u8>> {
+        values(root, path)
+            .into_iter()
+            .find(|v| v.name.eq_ignore_ascii_case(name))
+            .map(|v| v.data)
+    }
 
-    processes
+    /// Write a value, creating the key if needed
+    pub fn set_value(root: HKEY, path: &str, name: &str, kind: REG_VALUE_TYPE, data: &[u8]) -> Result<(), String> {
+        let wide_path = to_wide(path);
+        let wide_name = to_wide(name);
+        let mut key = HKEY::default();
+
+        unsafe {
+            let status = RegCreateKeyExW(
+                root,
+                PCWSTR::from_raw(wide_path.as_ptr()),
+                0,
+                PCWSTR::null(),
+                REG_OPTION_NON_VOLATILE,
+                KEY_SET_VALUE,
+                None,
+                &mut key,
+                None,
+            );
+            if status != ERROR_SUCCESS {
+                return Err(describe(status));
+            }
+
+            let status = RegSetValueExW(key, PCWSTR::from_raw(wide_name.as_ptr()), 0, kind, Some(data));
+            let _ = RegCloseKey(key);
+
+            if status == ERROR_SUCCESS { Ok(()) } else { Err(describe(status)) }
+        }
+    }
+
+    /// REG_SZ / REG_EXPAND_SZ data as a String
+    pub fn string(data: &[u8]) -> String {
+        let wide: Vec<u16> = data
+            .chunks_exact(2)
+            .map(|c| u16::from_le_bytes([c[0], c[1]]))
+            .collect();
+        String::from_utf16_lossy(&wide).trim_end_matches('\0').to_string()
+    }
+
+    /// REG_DWORD data as a u32
+    pub fn dword(data: &[u8]) -> Option<u32> {
+        Some(u32::from_le_bytes(data.get(..4)?.try_into().ok()?))
+    }
+
+    /// REG_QWORD data as a u64
+    pub fn qword(data: &[u8]) -> Option<u64> {
+        Some(u64::from_le_bytes(data.get(..8)?.try_into().ok()?))
+    }
 }
 
-#[tauri::command]
-fn get_system_stats(state: State<AppState>) -> SystemStats {
-    let mut system = state.system.lock().unwrap();
-    system.refresh_all();
+/// FileDescription / CompanyName from an exe's version resource
+#[derive(Clone, Default)]
+struct VersionStrings {
+    description: Option<String>,
+    company: Option<String>,
+}
 
-    let total_memory = system.total_memory();
-    let used_memory = system.used_memory();
-    let available_memory = system.available_memory();
+#[cfg(windows)]
+fn read_version_strings(exe_path: &str) -> VersionStrings {
+    use windows::Win32::Storage::FileSystem::{GetFileVersionInfoSizeW, GetFileVersionInfoW, VerQueryValueW};
 
-    // Calculate average CPU usage across all cores
-    let cpu_percent = system.global_cpu_usage();
+    let wide_path = to_wide(exe_path);
 
-    SystemStats {
-        cpu_percent,
-        memory_percent: if total_memory > 0 {
-            (used_memory as f64 / total_memory as f64 * 100.0) as f32
+    unsafe {
+        let size = GetFileVersionInfoSizeW(PCWSTR::from_raw(wide_path.as_ptr()), None);
+        if size == 0 {
+            // No version resource
+            return VersionStrings::default();
+        }
+
+        let mut block = vec![0u8; size as usize];
+        if GetFileVersionInfoW(PCWSTR::from_raw(wide_path.as_ptr()), 0, size, block.as_mut_ptr() as *mut _).is_err() {
+            return VersionStrings::default();
+        }
+
+        let query = |sub_block: &str| -> Option<(*const u16, u32)> {
+            let wide_sub_block = to_wide(sub_block);
+            let mut value: *mut std::ffi::c_void = std::ptr::null_mut();
+            let mut len: u32 = 0;
+            let found = VerQueryValueW(
+                block.as_ptr() as *const _,
+                PCWSTR::from_raw(wide_sub_block.as_ptr()),
+                &mut value,
+                &mut len,
+            ).as_bool();
+            if found && !value.is_null() && len > 0 {
+                Some((value as *const u16, len))
+            } else {
+                None
+            }
+        };
+
+        // First language/codepage pair, default to US English + Unicode
+        let (lang, codepage) = query("\\VarFileInfo\\Translation")
+            .map(|(pair, _)| (*pair, *pair.add(1)))
+            .unwrap_or((0x0409, 0x04B0));
+
+        let read_string = |name: &str| -> Option<String> {
+            let sub_block = format!("\\StringFileInfo\\{:04x}{:04x}\\{}", lang, codepage, name);
+            // String lengths are in characters, including the null terminator
+            let (text, len) = query(&sub_block)?;
+            let text = String::from_utf16_lossy(std::slice::from_raw_parts(text, len as usize));
+            let text = text.trim_end_matches('\0').trim();
+            if text.is_empty() { None } else { Some(text.to_string()) }
+        };
+
+        VersionStrings {
+            description: read_string("FileDescription"),
+            company: read_string("CompanyName"),
+        }
+    }
+}
+
+#[cfg(not(windows))]
+fn read_version_strings(_exe_path: &str) -> VersionStrings {
+    VersionStrings::default()
+}
+
+/// Cached version strings for an exe path
+fn get_version_strings(cache: &Mutex<HashMap<String, VersionStrings>>, exe_path: &str) -> VersionStrings {
+    if let Some(strings) = cache.lock().unwrap().get(exe_path) {
+        return strings.clone();
+    }
+    let strings = read_version_strings(exe_path);
+    cache.lock().unwrap().insert(exe_path.to_string(), strings.clone());
+    strings
+}
+
+/// Built-in process categories as (pattern, category)
+/// Patterns containing a path separator match a fragment of the exe path,
+/// anything else matches the exe name exactly (case-insensitive)
+const DEFAULT_CATEGORY_RULES: &[(&str, &str)] = &[
+    ("chrome.exe", "browser"),
+    ("msedge.exe", "browser"),
+    ("firefox.exe", "browser"),
+    ("opera.exe", "browser"),
+    ("brave.exe", "browser"),
+    ("vivaldi.exe", "browser"),
+    ("code.exe", "dev"),
+    ("devenv.exe", "dev"),
+    ("idea64.exe", "dev"),
+    ("pycharm64.exe", "dev"),
+    ("rustrover64.exe", "dev"),
+    ("windowsterminal.exe", "dev"),
+    ("node.exe", "dev"),
+    ("cargo.exe", "dev"),
+    ("git.exe", "dev"),
+    ("\\steamapps\\common\\", "game"),
+    ("\\epic games\\", "game"),
+    ("\\riot games\\", "game"),
+    ("\\gog galaxy\\games\\", "game"),
+    ("svchost.exe", "background"),
+    ("runtimebroker.exe", "background"),
+    ("searchindexer.exe", "background"),
+    ("dllhost.exe", "background"),
+    ("conhost.exe", "background"),
+    ("\\windows\\system32\\", "background"),
+];
+
+/// Stable display name - the exe file name when the path is known, since `name()`
+/// is truncated to 15 chars on older Windows
+fn canonical_name(process: &sysinfo::Process) -> String {
+    name_from_exe(process.exe(), process.name())
+}
+
+/// The file name of `exe`, or `fallback` when the path is unknown or has no file name
+fn name_from_exe(exe: Option<&Path>, fallback: &std::ffi::OsStr) -> String {
+    exe.and_then(|path| path.file_name())
+        .unwrap_or(fallback)
+        .to_string_lossy()
+        .to_string()
+}
+
+/// Lowercase base name without directory or `.exe`
+fn process_name_stem(name: &str) -> String {
+    let base = name.rsplit(['\\', '/']).next().unwrap_or(name).to_lowercase();
+    match base.strip_suffix(".exe") {
+        Some(stem) => stem.to_string(),
+        None => base,
+    }
+}
+
+/// Compare process names by base stem, ignoring directory, `.exe` and case
+fn same_process_name(a: &str, b: &str) -> bool {
+    process_name_stem(a) == process_name_stem(b)
+}
+
+/// Stable chart color for a process name, same on every machine and session
+/// FNV-1a rather than DefaultHasher, whose output isn't guaranteed across builds
+fn process_color(name: &str) -> String {
+    let hash = process_name_stem(name)
+        .bytes()
+        .fold(0xcbf29ce484222325u64, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x100000001b3));
+
+    // Golden-ratio hue spread keeps neighbouring hashes apart, low bits vary
+    // saturation/value a little so equal hues stay distinguishable. The fraction is taken
+    // in fixed point (2^64 / phi) since a 48-bit product in f64 keeps only a few bits of it
+    let spread = (hash >> 16).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+    let hue = (spread >> 40) as f64 / (1u64 << 24) as f64 * 360.0;
+    let saturation = 0.55 + (hash & 0xff) as f64 / 255.0 * 0.2;
+    let value = 0.75 + ((hash >> 8) & 0xff) as f64 / 255.0 * 0.15;
+
+    let chroma = value * saturation;
+    let x = chroma * (1.0 - ((hue / 60.0) % 2.0 - 1.0).abs());
+    let (r, g, b) = match hue as u32 / 60 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = value - chroma;
+    let channel = |c: f64| ((c + m) * 255.0).round() as u8;
+    format!("#{:02x}{:02x}{:02x}", channel(r), channel(g), channel(b))
+}
+
+/// Chart color for a name that may not be running (sessions, history)
+#[tauri::command]
+fn get_process_color(name: String) -> String {
+    process_color(&name)
+}
+
+/// Classify a process as game/browser/dev/background/other
+/// User rules take precedence over the built-in ones
+fn classify_process(name: &str, exe_path: Option<&str>, user_rules: &[(String, String)]) -> String {
+    let name = name.to_lowercase();
+    let path = exe_path.map(|p| p.to_lowercase().replace('/', "\\"));
+
+    let matches = |pattern: &str| {
+        let pattern = pattern.to_lowercase().replace('/', "\\");
+        if pattern.contains('\\') {
+            path.as_deref().map(|p| p.contains(&pattern)).unwrap_or(false)
         } else {
-            0.0
-        },
-        total_memory_gb: total_memory as f64 / 1024.0 / 1024.0 / 1024.0,
-        used_memory_gb: used_memory as f64 / 1024.0 / 1024.0 / 1024.0,
-        available_memory_gb: available_memory as f64 / 1024.0 / 1024.0 / 1024.0,
-        cpu_cores: system.cpus().len(),
+            name == pattern
+        }
+    };
+
+    user_rules
+        .iter()
+        .map(|(pattern, category)| (pattern.as_str(), category.as_str()))
+        .chain(DEFAULT_CATEGORY_RULES.iter().copied())
+        .find(|&(pattern, _)| matches(pattern))
+        .map(|(_, category)| category.to_string())
+        .unwrap_or_else(|| "other".to_string())
+}
+
+// Metric precision - values are rounded at the source to keep IPC payloads small
+const MAX_METRIC_PRECISION: u8 = 6;
+static METRIC_PRECISION: std::sync::atomic::AtomicU8 = std::sync::atomic::AtomicU8::new(1);
+
+/// Round to the configured number of decimals, NaN/infinite become 0
+fn round_metric(value: f64) -> f64 {
+    if !value.is_finite() {
+        return 0.0;
+    }
+    let factor = 10f64.powi(METRIC_PRECISION.load(Ordering::SeqCst) as i32);
+    (value * factor).round() / factor
+}
+
+fn round_metric_f32(value: f32) -> f32 {
+    round_metric(value as f64) as f32
+}
+
+/// Decimals reported for CPU/memory/GPU metrics, 0-6 (persisted)
+#[tauri::command]
+fn set_metric_precision(state: State<AppState>, decimals: u8) -> Result<(), String> {
+    if decimals > MAX_METRIC_PRECISION {
+        return Err(format!("decimals must be between 0 and {}", MAX_METRIC_PRECISION));
+    }
+    METRIC_PRECISION.store(decimals, Ordering::SeqCst);
+    update_app_data(&state, |data| data.metric_precision = Some(decimals))
+}
+
+/// A process's busiest GPU according to PDH
+struct GpuEngineUsage {
+    // Adapter description, None when DXGI doesn't know the LUID
+    adapter: Option<String>,
+    percent: f32,
+}
+
+/// Nonzero GPU engine utilization per PID from PDH - covers every adapter and vendor,
+/// so iGPU work on hybrid laptops shows up too. Instances are named like
+/// "pid_1234_luid_0x00000000_0x0000C6B2_phys_0_eng_3_engtype_3D"
+#[cfg(windows)]
+fn read_gpu_engines(state: &AppState) -> HashMap<u32, GpuEngineUsage> {
+    let readings = {
+        let mut counter = state.gpu_engine_counter.lock().unwrap();
+        if counter.is_none() {
+            *counter = pdh::Counter::open("\\GPU Engine(*)\\Utilization Percentage");
+        }
+        match counter.as_ref() {
+            Some(counter) => counter.read_array(),
+            None => return HashMap::new(),
+        }
+    };
+
+    // (PID, adapter LUID) -> engine type -> summed utilization
+    let mut engines: HashMap<(u32, String), HashMap<String, f64>> = HashMap::new();
+    for (instance, value) in readings {
+        if value <= 0.0 {
+            continue;
+        }
+        let Some((pid, rest)) = instance.strip_prefix("pid_").and_then(|rest| rest.split_once("_luid_")) else { continue };
+        let Ok(pid) = pid.parse::<u32>() else { continue };
+        let Some((luid, rest)) = rest.split_once("_phys_") else { continue };
+        let engine_type = rest.rsplit_once("_engtype_").map(|(_, t)| t).unwrap_or_default();
+        *engines
+            .entry((pid, luid.to_lowercase()))
+            .or_default()
+            .entry(engine_type.to_string())
+            .or_insert(0.0) += value;
+    }
+
+    // Per adapter, Task Manager shows the busiest engine type; per process, the busiest adapter
+    let mut busiest: HashMap<u32, (String, f32)> = HashMap::new();
+    for ((pid, luid), types) in engines {
+        let percent = types.values().copied().fold(0.0, f64::max).min(100.0) as f32;
+        if !busiest.get(&pid).is_some_and(|(_, current)| *current >= percent) {
+            busiest.insert(pid, (luid, percent));
+        }
+    }
+
+    let mut names = state.gpu_adapter_names.lock().unwrap();
+    // Adapters can appear later (eGPU, driver restart), re-enumerate on an unknown LUID.
+    // LUIDs DXGI doesn't list (software adapters) are cached as None so they don't re-enumerate every poll
+    if busiest.values().any(|(luid, _)| !names.contains_key(luid)) {
+        *names = gpu_adapter_names().into_iter().map(|(luid, name)| (luid, Some(name))).collect();
+        for (luid, _) in busiest.values() {
+            names.entry(luid.clone()).or_insert(None);
+        }
+    }
+    busiest
+        .into_iter()
+        .map(|(pid, (luid, percent))| (pid, GpuEngineUsage { adapter: names.get(&luid).cloned().flatten(), percent }))
+        .collect()
+}
+
+#[cfg(not(windows))]
+fn read_gpu_engines(_state: &AppState) -> HashMap<u32, GpuEngineUsage> {
+    HashMap::new()
+}
+
+/// Adapter LUID, formatted like the GPU Engine instance names (lowercase), -> description
+#[cfg(windows)]
+fn gpu_adapter_names() -> HashMap<String, String> {
+    use windows::Win32::Graphics::Dxgi::{CreateDXGIFactory1, IDXGIFactory1, DXGI_ADAPTER_FLAG_SOFTWARE};
+
+    let mut names = HashMap::new();
+    let Ok(factory) = (unsafe { CreateDXGIFactory1::<IDXGIFactory1>() }) else { return names };
+    let mut index = 0;
+    while let Ok(adapter) = unsafe { factory.EnumAdapters1(index) } {
+        index += 1;
+        let Ok(desc) = (unsafe { adapter.GetDesc1() }) else { continue };
+        // Microsoft Basic Render Driver
+        if desc.Flags & DXGI_ADAPTER_FLAG_SOFTWARE.0 as u32 != 0 {
+            continue;
+        }
+        let len = desc.Description.iter().position(|&c| c == 0).unwrap_or(desc.Description.len());
+        let luid = format!("0x{:08x}_0x{:08x}", desc.AdapterLuid.HighPart as u32, desc.AdapterLuid.LowPart);
+        names.insert(luid, String::from_utf16_lossy(&desc.Description[..len]));
+    }
+    names
+}
+
+/// GPU share and adapter for one process - NVML where it reports activity, PDH for
+/// everything else (the iGPU on Optimus/hybrid laptops has no NVML data)
+fn merged_gpu_usage(pid: u32, gpu: &GpuProcessUsage, engines: &HashMap<u32, GpuEngineUsage>) -> (f32, Option<String>) {
+    let engine = engines.get(&pid);
+    match (gpu.utilization.get(&pid).copied(), engine) {
+        (Some(percent), _) if percent > 0.0 || engine.is_none() => (percent, gpu.adapters.get(&pid).cloned()),
+        (_, Some(engine)) => (engine.percent, engine.adapter.clone()),
+        _ => (0.0, None),
+    }
+}
+
+/// How much of ProcessInfo a poll fills in
+#[derive(Deserialize, Clone, Copy, Default, PartialEq)]
+#[serde(rename_all = "lowercase")]
+enum DetailLevel {
+    // Name/CPU/memory/GPU only: no exe path, version strings or status
+    Basic,
+    #[default]
+    Full,
+}
+
+/// Per-poll data shared by every ProcessInfo built during one collection pass
+struct CollectionContext<'a> {
+    total_memory: u64,
+    // sysinfo reports per-core CPU usage, divide by this to get a 0-100% value
+    cpu_divisor: f32,
+    gpu: &'a GpuProcessUsage,
+    category_rules: &'a [(String, String)],
+    version_cache: &'a Mutex<HashMap<String, VersionStrings>>,
+    memory_metric: MemoryMetric,
+    // GPU engine activity according to PDH (any vendor, any adapter)
+    gpu_engines: &'a HashMap<u32, GpuEngineUsage>,
+    detail: DetailLevel,
+}
+
+fn build_process_info(pid: u32, process: &sysinfo::Process, ctx: &CollectionContext) -> ProcessInfo {
+    // Try to get accurate memory from Windows API, fallback to sysinfo
+    let memory = get_process_memory(pid).unwrap_or_else(|| ProcessMemory {
+        private_working_set: process.memory(),
+        commit: process.virtual_memory(),
+        peak_working_set: process.memory(),
+        peak_commit: process.virtual_memory(),
+    });
+    let memory_bytes = match ctx.memory_metric {
+        MemoryMetric::PrivateWorkingSet => memory.private_working_set,
+        MemoryMetric::Commit => memory.commit,
+    };
+
+    let memory_percent = if ctx.total_memory > 0 {
+        (memory_bytes as f64 / ctx.total_memory as f64 * 100.0) as f32
+    } else {
+        0.0
+    };
+
+    // Convert bytes to MB
+    let memory_mb = memory_bytes as f64 / (1024.0 * 1024.0);
+    let commit_mb = memory.commit as f64 / (1024.0 * 1024.0);
+    let peak_working_set_mb = memory.peak_working_set as f64 / (1024.0 * 1024.0);
+    let peak_commit_mb = memory.peak_commit as f64 / (1024.0 * 1024.0);
+    let gpu_memory_mb = ctx.gpu.memory_bytes.get(&pid).copied().unwrap_or(0) as f64 / (1024.0 * 1024.0);
+
+    // Get GPU usage for this process (0 if not using GPU)
+    let (gpu_percent, gpu_adapter) = merged_gpu_usage(pid, ctx.gpu, ctx.gpu_engines);
+
+    let full = ctx.detail == DetailLevel::Full;
+    let name = canonical_name(process);
+    // Path rules need the exe whenever sysinfo has it, so categories don't change between
+    // basic and full polls. Basic polls only leave the path (and version lookup) out of the output
+    let exe = process.exe().map(|p| p.to_string_lossy().to_string());
+    let category = classify_process(&name, exe.as_deref(), ctx.category_rules);
+    let exe_path = if full { exe } else { None };
+    let color_hex = process_color(&name);
+    let version = exe_path
+        .as_deref()
+        .map(|path| get_version_strings(ctx.version_cache, path))
+        .unwrap_or_default();
+
+    ProcessInfo {
+        pid,
+        name,
+        cpu_percent: round_metric_f32(process.cpu_usage() / ctx.cpu_divisor),
+        memory_mb: round_metric(memory_mb),
+        commit_mb: round_metric(commit_mb),
+        peak_working_set_mb: round_metric(peak_working_set_mb),
+        peak_commit_mb: round_metric(peak_commit_mb),
+        memory_percent: round_metric_f32(memory_percent),
+        gpu_percent: round_metric_f32(gpu_percent),
+        gpu_memory_mb: round_metric(gpu_memory_mb),
+        cpu_percent_avg: round_metric_f32(process.cpu_usage() / ctx.cpu_divisor),
+        gpu_percent_avg: round_metric_f32(gpu_percent),
+        uses_gpu: ctx.gpu.utilization.contains_key(&pid) || ctx.gpu_engines.contains_key(&pid),
+        gpu_adapter,
+        status: if full { format!("{:?}", process.status()) } else { String::new() },
+        create_time: process.start_time(),
+        exe_path,
+        category,
+        description: version.description,
+        company: version.company,
+        is_new_binary: false,
+        is_warming_up: false,
+        service_names: Vec::new(),
+        has_visible_window: false,
+        io_wait_percent: None,
+        context_switches_per_sec: None,
+        ancestor_chain: Vec::new(),
+        tcp_connections: 0,
+        udp_connections: 0,
+        gdi_objects: 0,
+        user_objects: 0,
+        disk_read_ops_per_sec: 0.0,
+        disk_write_ops_per_sec: 0.0,
+        is_frozen: false,
+        color_hex,
+        icon: None,
+    }
+}
+
+// First-seen tracking - flags executables that only recently started appearing
+const NEW_BINARY_DAYS: i64 = 7;
+// New paths are saved at most this often (and on shutdown), not on every poll that finds one
+const FIRST_SEEN_PERSIST_SECS: u64 = 60;
+
+struct FirstSeenTracker {
+    paths: HashMap<String, String>,
+    // When the first scan ran, everything it found counts as already installed
+    baseline: Option<String>,
+    // Paths were added since the last save
+    dirty: bool,
+    last_persist: Instant,
+}
+
+/// Record first-seen times for unseen exe paths and set `is_new_binary`.
+/// The very first scan only records a silent baseline, nothing is flagged
+fn mark_new_binaries(state: &AppState, processes: &mut [ProcessInfo]) {
+    let now = chrono::Local::now();
+    let now_text = now.to_rfc3339();
+    let mut guard = state.first_seen.lock().unwrap();
+    let tracker = &mut *guard;
+    // Only a full scan can be the baseline, not a single-process lookup
+    if tracker.paths.is_empty() && tracker.baseline.is_none() && processes.len() > 1 {
+        tracker.baseline = Some(now_text.clone());
+    }
+
+    for process in processes.iter_mut() {
+        let Some(exe_path) = process.exe_path.as_ref() else { continue };
+
+        let seen = tracker.paths.entry(exe_path.clone()).or_insert_with(|| {
+            tracker.dirty = true;
+            now_text.clone()
+        });
+
+        process.is_new_binary = tracker.baseline.as_deref() != Some(seen.as_str())
+            && chrono::DateTime::parse_from_rfc3339(seen)
+                .map(|seen| now.signed_duration_since(seen) < chrono::Duration::days(NEW_BINARY_DAYS))
+                .unwrap_or(false);
+    }
+
+    if tracker.dirty && tracker.last_persist.elapsed().as_secs() >= FIRST_SEEN_PERSIST_SECS {
+        tracker.last_persist = Instant::now();
+        drop(guard);
+        flush_first_seen(state);
     }
 }
 
+/// Save the first-seen times if any were added since the last save
+fn flush_first_seen(state: &AppState) {
+    let result = update_app_data(state, |data| {
+        let mut tracker = state.first_seen.lock().unwrap();
+        if tracker.dirty {
+            data.first_seen = tracker.paths.clone();
+            data.first_seen_baseline = tracker.baseline.clone();
+            tracker.dirty = false;
+        }
+    });
+    if let Err(e) = result {
+        log_error!("Failed to save first-seen times: {}", e);
+        state.first_seen.lock().unwrap().dirty = true;
+    }
+}
+
+/// When an executable was first observed running (RFC 3339), if ever
 #[tauri::command]
-fn get_process_by_pid(state: State<AppState>, pid: u32) -> Option<ProcessInfo> {
+fn get_process_first_seen(state: State<AppState>, exe_path: String) -> Option<String> {
+    state.first_seen.lock().unwrap().paths.get(&exe_path).cloned()
+}
+
+// CPU warm-up - sysinfo computes CPU usage from the delta between two refreshes,
+// so setup primes it and the first polls are flagged as still measuring
+const CPU_WARMUP_POLLS: u32 = 2;
+
+/// Make sure at least one sysinfo CPU interval has passed since priming in setup
+fn wait_for_cpu_baseline(state: &AppState) {
+    let elapsed = state.cpu_primed_at.elapsed();
+    if elapsed < sysinfo::MINIMUM_CPU_UPDATE_INTERVAL {
+        std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL - elapsed);
+    }
+}
+
+/// Sort by CPU usage descending, ties by PID so idle rows keep their order between polls
+fn sort_by_cpu(processes: &mut [ProcessInfo]) {
+    processes.sort_by(|a, b| b.cpu_percent.total_cmp(&a.cpu_percent).then_with(|| a.pid.cmp(&b.pid)));
+}
+
+/// Refresh and collect every running process, sorted by CPU usage
+fn collect_processes(state: &AppState, gpu: &GpuProcessUsage, detail: DetailLevel) -> Vec<ProcessInfo> {
+    let cycle_start = Instant::now();
     let mut system = state.system.lock().unwrap();
-    system.refresh_all();
+    wait_for_cpu_baseline(state);
+    // Clear and refresh processes to ensure dead processes are removed
+    // refresh_all() keeps dead processes in cache, so we need refresh_processes()
+    if detail == DetailLevel::Full {
+        system.refresh_processes(sysinfo::ProcessesToUpdate::All, true);
+    } else {
+        // Basic polls only need CPU and memory, skip exe/cwd/disk resolution
+        system.refresh_processes_specifics(
+            sysinfo::ProcessesToUpdate::All,
+            true,
+            sysinfo::ProcessRefreshKind::new().with_cpu().with_memory(),
+        );
+    }
+    let is_warming_up = state.cpu_polls.fetch_add(1, Ordering::SeqCst) < CPU_WARMUP_POLLS;
 
-    let pid_obj = Pid::from_u32(pid);
-    let total_memory = system.total_memory();
-    let gpu_usage = get_gpu_usage_per_process();
+    // Get CPU core count for normalization (sysinfo reports per-core CPU usage)
+    // We want total system percentage (0-100%)
+    let cpu_cores = system.cpus().len() as f32;
+    let cpu_divisor = if cpu_cores > 0.0 { cpu_cores } else { 1.0 };
 
-    system.process(pid_obj).map(|process| {
-        // Try to get accurate memory from Windows API, fallback to sysinfo
-        let memory_bytes = get_private_working_set(pid)
-            .unwrap_or_else(|| process.memory());
+    let category_rules = state.category_rules.lock().unwrap().clone();
+    let gpu_engines = read_gpu_engines(state);
+
+    let ctx = CollectionContext {
+        total_memory: system.total_memory(),
+        cpu_divisor,
+        gpu,
+        category_rules: &category_rules,
+        version_cache: &state.version_cache,
+        memory_metric: *state.memory_metric.lock().unwrap(),
+        gpu_engines: &gpu_engines,
+        detail,
+    };
+
+    let mut processes: Vec<ProcessInfo> = system
+        .processes()
+        .iter()
+        .map(|(pid, process)| build_process_info(pid.as_u32(), process, &ctx))
+        .collect();
+
+    drop(system);
 
-        let memory_percent = if total_memory > 0 {
-            (memory_bytes as f64 / total_memory as f64 * 100.0) as f32
+    for process in processes.iter_mut() {
+        process.is_warming_up = is_warming_up;
+    }
+    mark_new_binaries(state, &mut processes);
+    attach_service_names(state, &mut processes);
+    mark_visible_windows(&mut processes);
+    let process_list = query_process_list();
+    attach_io_ops(state, &mut processes, process_list.as_deref());
+    mark_frozen_processes(&mut processes, process_list.as_deref());
+    mark_suspended_processes(state, &mut processes);
+    sort_by_cpu(&mut processes);
+
+    *state.last_cycle_ms.lock().unwrap() = Some(cycle_start.elapsed().as_secs_f64() * 1000.0);
+
+    processes
+}
+
+// CPU anomaly detection - flags a process that suddenly jumps well above its own recent usage
+const DEFAULT_ANOMALY_SENSITIVITY: f32 = 3.0;
+// Welford's sample count is capped here so old samples fade out and the window stays short
+const ANOMALY_WINDOW: f64 = 30.0;
+const ANOMALY_MIN_SAMPLES: f64 = 10.0;
+// Ignore "spikes" too small to matter (e.g. 0.1% -> 2%)
+const ANOMALY_MIN_CPU_PERCENT: f64 = 10.0;
+
+#[derive(Default, Clone, Copy)]
+struct RunningStats {
+    count: f64,
+    mean: f64,
+    m2: f64,
+}
+
+impl RunningStats {
+    /// Welford's online mean/variance update
+    fn push(&mut self, value: f64) {
+        if self.count < ANOMALY_WINDOW {
+            self.count += 1.0;
+        } else {
+            // Forget the oldest sample's share so the variance doesn't grow forever
+            self.m2 *= (self.count - 1.0) / self.count;
+        }
+        let delta = value - self.mean;
+        self.mean += delta / self.count;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    fn stddev(&self) -> f64 {
+        if self.count > 1.0 {
+            (self.m2 / (self.count - 1.0)).sqrt()
         } else {
             0.0
-        };
+        }
+    }
+}
 
-        let gpu_percent = gpu_usage.get(&pid).copied().unwrap_or(0.0);
+struct CpuAnomalyDetector {
+    // Standard deviations above the mean that count as an anomaly
+    k: f32,
+    stats: HashMap<u32, RunningStats>,
+}
 
-        ProcessInfo {
-            pid,
-            name: process.name().to_string_lossy().to_string(),
-            cpu_percent: process.cpu_usage(),
-            memory_mb: memory_bytes as f64 / 1024.0 / 1024.0,
-            memory_percent,
-            gpu_percent,
-            status: format!("{:?}", process.status()),
-            create_time: process.start_time(),
-            exe_path: process.exe().map(|p| p.to_string_lossy().to_string()),
-        }
+// Alert silencing - alerts are still emitted while the user is presenting or in a
+// fullscreen game, but flagged so no native notification is shown
+#[derive(Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum NotificationState {
+    // Screen saver, locked or fast user switching
+    NotPresent,
+    // Fullscreen app
+    Busy,
+    FullscreenD3d,
+    Presentation,
+    AcceptsNotifications,
+    // Focus Assist / quiet hours
+    QuietTime,
+    // A Store app is fullscreen
+    App,
+}
+
+#[cfg(windows)]
+fn notification_state() -> Option<NotificationState> {
+    use windows::Win32::UI::Shell::SHQueryUserNotificationState;
+
+    let state = unsafe { SHQueryUserNotificationState() }.ok()?;
+    // QUERY_USER_NOTIFICATION_STATE values
+    Some(match state.0 {
+        1 => NotificationState::NotPresent,
+        2 => NotificationState::Busy,
+        3 => NotificationState::FullscreenD3d,
+        4 => NotificationState::Presentation,
+        6 => NotificationState::QuietTime,
+        7 => NotificationState::App,
+        _ => NotificationState::AcceptsNotifications,
     })
 }
 
-// Performance snapshot for charts
-#[derive(Serialize, Deserialize, Clone)]
-struct PerformanceSnapshot {
-    timestamp: String,
-    cpu_percent: f64,
-    memory_mb: f64,
-    memory_percent: f64,
-    #[serde(default)]
-    gpu_percent: f64,
-    #[serde(default)]
-    user_activity_percent: f64,
-    #[serde(default)]
-    is_foreground: bool,
+#[cfg(not(windows))]
+fn notification_state() -> Option<NotificationState> {
+    None
 }
 
-// Session and whitelist persistence structures
-#[derive(Serialize, Deserialize, Clone)]
-struct SavedSession {
-    id: i64,
-    app_name: String,
-    start_time: String,
-    end_time: Option<String>,
-    duration_seconds: i64,
+fn alerts_silenced() -> bool {
+    matches!(
+        notification_state(),
+        Some(NotificationState::Busy
+            | NotificationState::FullscreenD3d
+            | NotificationState::Presentation
+            | NotificationState::QuietTime
+            | NotificationState::App)
+    )
+}
+
+/// What Windows reports about showing notifications right now (Focus Assist etc.)
+#[tauri::command]
+fn get_notification_state() -> Option<NotificationState> {
+    notification_state()
+}
+
+#[derive(Serialize, Clone)]
+struct CpuAnomaly {
+    pid: u32,
+    name: String,
+    cpu_percent: f32,
+    mean_percent: f32,
+    stddev_percent: f32,
+    // Focus Assist / presentation / fullscreen - record it, don't pop a notification
+    silenced: bool,
+}
+
+/// Emit `cpu-anomaly` for processes whose CPU exceeds mean + k * stddev
+fn detect_cpu_anomalies(app: &tauri::AppHandle, state: &AppState, processes: &[ProcessInfo]) {
+    let mut detector = state.cpu_anomaly.lock().unwrap();
+    let k = detector.k as f64;
+
+    // Drop exited processes
+    let live_pids: std::collections::HashSet<u32> = processes.iter().map(|p| p.pid).collect();
+    detector.stats.retain(|pid, _| live_pids.contains(pid));
+
+    for process in processes {
+        let stats = detector.stats.entry(process.pid).or_default();
+        let cpu = process.cpu_percent as f64;
+
+        if stats.count >= ANOMALY_MIN_SAMPLES && cpu >= ANOMALY_MIN_CPU_PERCENT {
+            let stddev = stats.stddev();
+            if cpu > stats.mean + k * stddev {
+                let _ = app.emit("cpu-anomaly", CpuAnomaly {
+                    pid: process.pid,
+                    name: process.name.clone(),
+                    cpu_percent: process.cpu_percent,
+                    mean_percent: stats.mean as f32,
+                    stddev_percent: stddev as f32,
+                    silenced: alerts_silenced(),
+                });
+            }
+        }
+
+        stats.push(cpu);
+    }
+}
+
+/// Least-squares fit of y over x, returns (slope, r squared)
+fn linear_regression(points: &[(f64, f64)]) -> Option<(f64, f64)> {
+    let n = points.len() as f64;
+    if points.len() < 2 {
+        return None;
+    }
+
+    let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+
+    let mut sxx = 0.0;
+    let mut sxy = 0.0;
+    let mut syy = 0.0;
+    for (x, y) in points {
+        sxx += (x - mean_x) * (x - mean_x);
+        sxy += (x - mean_x) * (y - mean_y);
+        syy += (y - mean_y) * (y - mean_y);
+    }
+
+    if sxx == 0.0 {
+        return None;
+    }
+
+    let slope = sxy / sxx;
+    let r_squared = if syy == 0.0 { 0.0 } else { (sxy * sxy) / (sxx * syy) };
+    Some((slope, r_squared))
+}
+
+// GPU memory leak detection - a steady VRAM climb over the whole window
+const GPU_LEAK_WINDOW: usize = 60;
+const GPU_LEAK_MIN_SLOPE_MB_PER_MIN: f64 = 5.0;
+const GPU_LEAK_MIN_GROWTH_MB: f64 = 100.0;
+// How well the samples must fit a straight line (filters out spiky usage)
+const GPU_LEAK_MIN_R_SQUARED: f64 = 0.8;
+
+#[derive(Serialize, Clone)]
+struct GpuMemoryLeak {
+    pid: u32,
+    name: String,
+    current_mb: f64,
+    growth_mb_per_min: f64,
+    silenced: bool,
+}
+
+/// Emit `gpu-memory-leak-suspected` for processes whose VRAM climbs steadily
+fn detect_gpu_memory_leaks(
+    app: &tauri::AppHandle,
+    state: &AppState,
+    gpu_memory: &HashMap<u32, u64>,
+    processes: &[ProcessInfo],
+) {
+    let mut history = state.gpu_memory_history.lock().unwrap();
+    // Only GPU processes are tracked, so exited ones simply stop reporting memory
+    history.retain(|pid, _| gpu_memory.contains_key(pid));
+
+    let now = Instant::now();
+    for (pid, bytes) in gpu_memory {
+        let samples = history.entry(*pid).or_default();
+        samples.push_back((now, *bytes as f64 / 1024.0 / 1024.0));
+        if samples.len() > GPU_LEAK_WINDOW {
+            samples.pop_front();
+        }
+        if samples.len() < GPU_LEAK_WINDOW {
+            continue;
+        }
+
+        let start = samples[0].0;
+        let points: Vec<(f64, f64)> = samples
+            .iter()
+            .map(|(time, mb)| ((*time - start).as_secs_f64() / 60.0, *mb))
+            .collect();
+        let growth = points[points.len() - 1].1 - points[0].1;
+
+        if let Some((slope, r_squared)) = linear_regression(&points) {
+            if slope >= GPU_LEAK_MIN_SLOPE_MB_PER_MIN
+                && r_squared >= GPU_LEAK_MIN_R_SQUARED
+                && growth >= GPU_LEAK_MIN_GROWTH_MB
+            {
+                let name = processes.iter()
+                    .find(|p| p.pid == *pid)
+                    .map(|p| p.name.clone())
+                    .unwrap_or_default();
+                let _ = app.emit("gpu-memory-leak-suspected", GpuMemoryLeak {
+                    pid: *pid,
+                    name,
+                    current_mb: points[points.len() - 1].1,
+                    growth_mb_per_min: slope,
+                    silenced: alerts_silenced(),
+                });
+                // Start a fresh window so we don't re-report on every poll
+                samples.clear();
+            }
+        }
+    }
+}
+
+// GDI/USER object leaks - each process gets 10,000 of each by default, past that its UI stops drawing
+const GUI_OBJECT_LIMIT: u32 = 10_000;
+const GUI_LEAK_WARN_OBJECTS: u32 = 9_000;
+// Every process needs its own handle, so this runs far less often than the poll
+const GUI_LEAK_CHECK_SECS: u64 = 30;
+
+#[derive(Default, Clone, Copy)]
+struct GuiResources {
+    gdi: u32,
+    user: u32,
+}
+
+#[derive(Default)]
+struct GuiLeakCheck {
+    last_check: Option<Instant>,
+    // PID -> create_time of processes already reported, each is reported once
+    reported: HashMap<u32, u64>,
+}
+
+#[derive(Serialize, Clone)]
+struct GuiResourceLeak {
+    pid: u32,
+    name: String,
+    gdi_objects: u32,
+    user_objects: u32,
+    limit: u32,
+    silenced: bool,
+}
+
+#[cfg(windows)]
+fn read_gui_resources(pid: u32) -> Option<GuiResources> {
+    use windows::Win32::System::Threading::{
+        GetGuiResources, GR_GDIOBJECTS, GR_USEROBJECTS, PROCESS_QUERY_LIMITED_INFORMATION,
+    };
+
+    unsafe {
+        let handle = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid).ok()?;
+        let resources = GuiResources {
+            gdi: GetGuiResources(handle, GR_GDIOBJECTS),
+            user: GetGuiResources(handle, GR_USEROBJECTS),
+        };
+        let _ = CloseHandle(handle);
+        Some(resources)
+    }
+}
+
+#[cfg(not(windows))]
+fn read_gui_resources(_pid: u32) -> Option<GuiResources> {
+    None
+}
+
+/// Emit `gui-resource-leak-suspected` for processes nearing the GDI or USER object quota
+fn detect_gui_resource_leaks(app: &tauri::AppHandle, state: &AppState, processes: &[ProcessInfo]) {
+    let mut check = state.gui_leak_check.lock().unwrap();
+    if check.last_check.is_some_and(|at| at.elapsed().as_secs() < GUI_LEAK_CHECK_SECS) {
+        return;
+    }
+    check.last_check = Some(Instant::now());
+
+    let alive: HashMap<u32, u64> = processes.iter().map(|p| (p.pid, p.create_time)).collect();
+    check.reported.retain(|pid, create_time| alive.get(pid) == Some(create_time));
+
+    for process in processes {
+        if check.reported.contains_key(&process.pid) {
+            continue;
+        }
+        let Some(gui) = read_gui_resources(process.pid) else { continue };
+        if gui.gdi < GUI_LEAK_WARN_OBJECTS && gui.user < GUI_LEAK_WARN_OBJECTS {
+            continue;
+        }
+        check.reported.insert(process.pid, process.create_time);
+        let _ = app.emit("gui-resource-leak-suspected", GuiResourceLeak {
+            pid: process.pid,
+            name: process.name.clone(),
+            gdi_objects: gui.gdi,
+            user_objects: gui.user,
+            limit: GUI_OBJECT_LIMIT,
+            silenced: alerts_silenced(),
+        });
+    }
+}
+
+// Memory growth leaderboard - downsampled per-PID memory history for every process
+const MEMORY_HISTORY_SAMPLE_SECS: u64 = 10;
+const MEMORY_HISTORY_MAX_SECS: u64 = 3600;
+const DEFAULT_MEMORY_GROWTH_LIMIT: usize = 10;
+
+struct MemoryHistory {
+    name: String,
+    // Distinguishes a reused PID from the process we were tracking
+    create_time: u64,
+    samples: std::collections::VecDeque<(Instant, f64)>,
+}
+
+#[derive(Serialize)]
+struct MemoryGrowth {
+    pid: u32,
+    name: String,
+    start_mb: f64,
+    current_mb: f64,
+    growth_mb_per_min: f64,
+}
+
+/// Append this poll's memory to each PID's history and drop exited processes
+fn record_memory_history(state: &AppState, processes: &[ProcessInfo]) {
+    let mut history = state.memory_history.lock().unwrap();
+    let alive: std::collections::HashSet<u32> = processes.iter().map(|p| p.pid).collect();
+    history.retain(|pid, _| alive.contains(pid));
+
+    let now = Instant::now();
+    let sample_every = std::time::Duration::from_secs(MEMORY_HISTORY_SAMPLE_SECS);
+    let keep_for = std::time::Duration::from_secs(MEMORY_HISTORY_MAX_SECS);
+    for process in processes {
+        let entry = history.entry(process.pid).or_insert_with(|| MemoryHistory {
+            name: process.name.clone(),
+            create_time: process.create_time,
+            samples: std::collections::VecDeque::new(),
+        });
+        if entry.create_time != process.create_time {
+            entry.name = process.name.clone();
+            entry.create_time = process.create_time;
+            entry.samples.clear();
+        }
+
+        if entry.samples.back().is_some_and(|(time, _)| now - *time < sample_every) {
+            continue;
+        }
+        entry.samples.push_back((now, process.memory_mb));
+        while entry.samples.front().is_some_and(|(time, _)| now - *time > keep_for) {
+            entry.samples.pop_front();
+        }
+    }
+}
+
+// Display averaging - smooths cpu_percent/gpu_percent over the last N polls
+const MAX_DISPLAY_AVERAGE_WINDOW: usize = 60;
+
+struct DisplayAverage {
+    // Samples averaged, 1 = raw values
+    window: usize,
+    // PID -> (create_time, recent (cpu, gpu) samples), at most `window` long
+    samples: HashMap<u32, (u64, std::collections::VecDeque<(f32, f32)>)>,
+}
+
+/// Push this poll's CPU/GPU into each PID's buffer and fill in the *_avg fields
+fn apply_display_average(state: &AppState, processes: &mut [ProcessInfo]) {
+    let mut average = state.display_average.lock().unwrap();
+    let alive: std::collections::HashSet<u32> = processes.iter().map(|p| p.pid).collect();
+    average.samples.retain(|pid, _| alive.contains(pid));
+
+    let window = average.window;
+    for process in processes.iter_mut() {
+        let (create_time, samples) = average
+            .samples
+            .entry(process.pid)
+            .or_insert_with(|| (process.create_time, std::collections::VecDeque::new()));
+        // PID reused by a new process
+        if *create_time != process.create_time {
+            *create_time = process.create_time;
+            samples.clear();
+        }
+
+        samples.push_back((process.cpu_percent, process.gpu_percent));
+        while samples.len() > window {
+            samples.pop_front();
+        }
+
+        let count = samples.len() as f32;
+        let (cpu_sum, gpu_sum) = samples.iter().fold((0.0, 0.0), |(c, g), (cpu, gpu)| (c + cpu, g + gpu));
+        process.cpu_percent_avg = round_metric_f32(cpu_sum / count);
+        process.gpu_percent_avg = round_metric_f32(gpu_sum / count);
+    }
+}
+
+/// Number of polls averaged into cpu_percent_avg/gpu_percent_avg, 1 = raw (persisted)
+#[tauri::command]
+fn set_display_average_window(state: State<AppState>, samples: usize) -> Result<(), String> {
+    if samples == 0 || samples > MAX_DISPLAY_AVERAGE_WINDOW {
+        return Err(format!("samples must be between 1 and {}", MAX_DISPLAY_AVERAGE_WINDOW));
+    }
+    {
+        let mut average = state.display_average.lock().unwrap();
+        average.window = samples;
+        for (_, buffer) in average.samples.values_mut() {
+            while buffer.len() > samples {
+                buffer.pop_front();
+            }
+        }
+    }
+    update_app_data(&state, |data| data.display_average_window = Some(samples))
+}
+
+/// Processes whose memory grew fastest over the last `window_secs`, fastest first
+#[tauri::command]
+fn get_memory_growth_leaderboard(
+    state: State<AppState>,
+    window_secs: u64,
+    limit: Option<usize>,
+) -> Vec<MemoryGrowth> {
+    let now = Instant::now();
+    let window = std::time::Duration::from_secs(window_secs);
+    let history = state.memory_history.lock().unwrap();
+
+    let mut leaderboard: Vec<MemoryGrowth> = history
+        .iter()
+        .filter_map(|(pid, entry)| {
+            let in_window: Vec<&(Instant, f64)> = entry
+                .samples
+                .iter()
+                .filter(|(time, _)| now - *time <= window)
+                .collect();
+            let (first, last) = (in_window.first()?, in_window.last()?);
+            let points: Vec<(f64, f64)> = in_window
+                .iter()
+                .map(|(time, mb)| ((*time - first.0).as_secs_f64() / 60.0, *mb))
+                .collect();
+            let (slope, _) = linear_regression(&points)?;
+            Some(MemoryGrowth {
+                pid: *pid,
+                name: entry.name.clone(),
+                start_mb: first.1,
+                current_mb: last.1,
+                growth_mb_per_min: slope,
+            })
+        })
+        .collect();
+
+    leaderboard.sort_by(|a, b| b.growth_mb_per_min.total_cmp(&a.growth_mb_per_min).then_with(|| a.pid.cmp(&b.pid)));
+    leaderboard.truncate(limit.unwrap_or(DEFAULT_MEMORY_GROWTH_LIMIT));
+    leaderboard
+}
+
+// Named snapshots - labeled process lists for "what changed since then" comparisons
+const MAX_NAMED_SNAPSHOTS: usize = 10;
+const SNAPSHOT_DIFF_TOP_MOVERS: usize = 10;
+
+#[derive(Serialize, Clone)]
+struct SnapshotProcess {
+    pid: u32,
+    name: String,
+    create_time: u64,
+    cpu_percent: f32,
+    memory_mb: f64,
+}
+
+struct NamedSnapshot {
+    name: String,
+    captured_at: String,
+    processes: Vec<SnapshotProcess>,
+}
+
+#[derive(Serialize)]
+struct SnapshotMover {
+    pid: u32,
+    name: String,
+    before: f64,
+    after: f64,
+    delta: f64,
+}
+
+#[derive(Serialize)]
+struct SnapshotDiff {
+    a_captured_at: String,
+    b_captured_at: String,
+    new_processes: Vec<SnapshotProcess>,
+    gone_processes: Vec<SnapshotProcess>,
+    cpu_movers: Vec<SnapshotMover>,
+    memory_movers: Vec<SnapshotMover>,
+}
+
+/// Store the current process list under `name` (replaces a snapshot with the same name,
+/// the oldest one is dropped past MAX_NAMED_SNAPSHOTS)
+#[tauri::command]
+fn capture_named_snapshot(state: State<AppState>, name: String) -> Result<(), String> {
+    if name.trim().is_empty() {
+        return Err("Snapshot name must not be empty".to_string());
+    }
+
+    // Memory only - CPU is what the last poll measured, a CPU refresh here would skew its next delta
+    let processes = processes_from_last_poll(&state, None, sysinfo::ProcessRefreshKind::new().with_memory(), DetailLevel::Basic)
+        .into_iter()
+        .map(|p| SnapshotProcess {
+            pid: p.pid,
+            name: p.name,
+            create_time: p.create_time,
+            cpu_percent: p.cpu_percent,
+            memory_mb: p.memory_mb,
+        })
+        .collect();
+
+    let mut snapshots = state.named_snapshots.lock().unwrap();
+    snapshots.retain(|s| s.name != name);
+    if snapshots.len() >= MAX_NAMED_SNAPSHOTS {
+        snapshots.remove(0);
+    }
+    snapshots.push(NamedSnapshot {
+        name,
+        captured_at: chrono::Local::now().to_rfc3339(),
+        processes,
+    });
+    Ok(())
+}
+
+#[tauri::command]
+fn get_named_snapshots(state: State<AppState>) -> Vec<String> {
+    state.named_snapshots.lock().unwrap().iter().map(|s| s.name.clone()).collect()
+}
+
+/// Biggest changes of one metric between processes present in both snapshots
+fn top_movers(
+    pairs: &[(&SnapshotProcess, &SnapshotProcess)],
+    metric: impl Fn(&SnapshotProcess) -> f64,
+) -> Vec<SnapshotMover> {
+    let mut movers: Vec<SnapshotMover> = pairs
+        .iter()
+        .map(|(before, after)| SnapshotMover {
+            pid: after.pid,
+            name: after.name.clone(),
+            before: metric(before),
+            after: metric(after),
+            delta: metric(after) - metric(before),
+        })
+        .filter(|m| m.delta != 0.0)
+        .collect();
+    movers.sort_by(|a, b| b.delta.abs().total_cmp(&a.delta.abs()).then_with(|| a.pid.cmp(&b.pid)));
+    movers.truncate(SNAPSHOT_DIFF_TOP_MOVERS);
+    movers
+}
+
+/// New/gone processes and the biggest CPU and memory movers from snapshot `a` to `b`
+#[tauri::command]
+fn diff_named_snapshots(state: State<AppState>, a: String, b: String) -> Result<SnapshotDiff, String> {
+    let snapshots = state.named_snapshots.lock().unwrap();
+    let find = |name: &str| {
+        snapshots
+            .iter()
+            .find(|s| s.name == name)
+            .ok_or_else(|| format!("No snapshot named '{}'", name))
+    };
+    let (a, b) = (find(&a)?, find(&b)?);
+
+    // PID + start time, so a reused PID counts as gone + new
+    let key = |p: &SnapshotProcess| (p.pid, p.create_time);
+    let before: HashMap<(u32, u64), &SnapshotProcess> = a.processes.iter().map(|p| (key(p), p)).collect();
+    let after: HashMap<(u32, u64), &SnapshotProcess> = b.processes.iter().map(|p| (key(p), p)).collect();
+
+    let new_processes = b.processes.iter().filter(|p| !before.contains_key(&key(p))).cloned().collect();
+    let gone_processes = a.processes.iter().filter(|p| !after.contains_key(&key(p))).cloned().collect();
+    let pairs: Vec<(&SnapshotProcess, &SnapshotProcess)> = b
+        .processes
+        .iter()
+        .filter_map(|p| before.get(&key(p)).map(|old| (*old, p)))
+        .collect();
+
+    Ok(SnapshotDiff {
+        a_captured_at: a.captured_at.clone(),
+        b_captured_at: b.captured_at.clone(),
+        new_processes,
+        gone_processes,
+        cpu_movers: top_movers(&pairs, |p| p.cpu_percent as f64),
+        memory_movers: top_movers(&pairs, |p| p.memory_mb),
+    })
+}
+
+/// Set how many standard deviations above normal a CPU spike must be (persisted)
+#[tauri::command]
+fn set_anomaly_sensitivity(state: State<AppState>, k: f32) -> Result<(), String> {
+    if k.is_nan() || k <= 0.0 {
+        return Err("Sensitivity must be greater than 0".to_string());
+    }
+    state.cpu_anomaly.lock().unwrap().k = k;
+    update_app_data(&state, |data| data.anomaly_sensitivity = Some(k))
+}
+
+#[tauri::command]
+fn get_memory_metric(state: State<AppState>) -> MemoryMetric {
+    *state.memory_metric.lock().unwrap()
+}
+
+/// Choose whether `memory_mb` reports private working set or commit (persisted)
+#[tauri::command]
+fn set_memory_metric(state: State<AppState>, metric: MemoryMetric) -> Result<(), String> {
+    *state.memory_metric.lock().unwrap() = metric;
+    update_app_data(&state, |data| data.memory_metric = metric)
+}
+
+/// Local processes, or the latest list of a connected remote when `source` is given.
+/// `detail_level: "basic"` skips exe paths, version info and status for cheaper polls.
+#[tauri::command]
+fn get_processes(
+    app: tauri::AppHandle,
+    state: State<AppState>,
+    source: Option<String>,
+    detail_level: Option<DetailLevel>,
+) -> Result<Vec<ProcessInfo>, String> {
+    if let Some(source) = source {
+        return remote_processes(&state, &source);
+    }
+    Ok(poll_processes(&app, &state, detail_level.unwrap_or_default()))
+}
+
+/// One local process poll with every per-poll side effect (rules, detectors, history)
+fn poll_processes(app: &tauri::AppHandle, state: &AppState, detail: DetailLevel) -> Vec<ProcessInfo> {
+    let gpu = get_gpu_usage_per_process(state);
+    let mut processes = collect_processes(state, &gpu, detail);
+    apply_display_average(state, &mut processes);
+    enforce_process_rules(state, &processes);
+    detect_cpu_anomalies(app, state, &processes);
+    detect_gpu_memory_leaks(app, state, &gpu.memory_bytes, &processes);
+    detect_gui_resource_leaks(app, state, &processes);
+    record_memory_history(state, &processes);
+
+    cache_for_stats_server(state, |cache| cache.processes_json = serde_json::to_string(&processes).ok());
+    processes
+}
+
+/// Like get_processes, but cut to max_list_length rows (highest CPU first) for lists that
+/// only display them. Session tracking must keep using get_processes, which is never cut,
+/// or idle whitelisted apps and their helpers would drop out of their sessions
+#[tauri::command]
+fn get_display_processes(
+    app: tauri::AppHandle,
+    state: State<AppState>,
+    detail_level: Option<DetailLevel>,
+) -> Vec<ProcessInfo> {
+    let mut processes = poll_processes(&app, &state, detail_level.unwrap_or_default());
+    if let Some(max) = *state.max_list_length.lock().unwrap() {
+        processes.truncate(max);
+    }
+    processes
+}
+
+/// Cap how many processes get_display_processes returns, None = all (persisted)
+#[tauri::command]
+fn set_max_list_length(state: State<AppState>, n: Option<usize>) -> Result<(), String> {
+    if n == Some(0) {
+        return Err("n must be at least 1".to_string());
+    }
+    *state.max_list_length.lock().unwrap() = n;
+    update_app_data(&state, |data| data.max_list_length = n)
+}
+
+/// Rows for `only` (every process when None) built from what the last poll measured, after
+/// refreshing just `refresh`. Unlike collect_processes it doesn't touch CPU deltas, GPU
+/// counters, first-seen times or the poll counters, so it's safe outside the poll loop
+fn processes_from_last_poll(
+    state: &AppState,
+    only: Option<&[Pid]>,
+    refresh: sysinfo::ProcessRefreshKind,
+    detail: DetailLevel,
+) -> Vec<ProcessInfo> {
+    let category_rules = state.category_rules.lock().unwrap().clone();
+    let no_gpu = GpuProcessUsage::default();
+    let no_engines = HashMap::new();
+
+    let mut system = state.system.lock().unwrap();
+    let to_update = match only {
+        Some(pids) => sysinfo::ProcessesToUpdate::Some(pids),
+        None => sysinfo::ProcessesToUpdate::All,
+    };
+    system.refresh_processes_specifics(to_update, true, refresh);
+    let ctx = CollectionContext {
+        total_memory: system.total_memory(),
+        cpu_divisor: (system.cpus().len() as f32).max(1.0),
+        gpu: &no_gpu,
+        category_rules: &category_rules,
+        version_cache: &state.version_cache,
+        memory_metric: *state.memory_metric.lock().unwrap(),
+        gpu_engines: &no_engines,
+        detail,
+    };
+    match only {
+        Some(pids) => pids
+            .iter()
+            .filter_map(|pid| system.process(*pid).map(|process| build_process_info(pid.as_u32(), process, &ctx)))
+            .collect(),
+        None => system
+            .processes()
+            .iter()
+            .map(|(pid, process)| build_process_info(pid.as_u32(), process, &ctx))
+            .collect(),
+    }
+}
+
+/// Apps with a visible window, one row per exe, with icons - the "pick an app to track" list.
+/// Only exe paths are refreshed, CPU/GPU figures are whatever the last poll measured
+#[tauri::command]
+fn get_user_apps(state: State<AppState>) -> Vec<ProcessInfo> {
+    let own_pid = OWN_PID.load(Ordering::SeqCst);
+    let window_pids: Vec<Pid> = visible_window_pids()
+        .into_iter()
+        .filter(|&pid| pid != own_pid)
+        .map(Pid::from_u32)
+        .collect();
+    let mut processes = processes_from_last_poll(
+        &state,
+        Some(&window_pids),
+        sysinfo::ProcessRefreshKind::new().with_exe(sysinfo::UpdateKind::OnlyIfNotSet),
+        DetailLevel::Full,
+    );
+    // Busiest instance first, so it's the one kept for a multi-process app
+    sort_by_cpu(&mut processes);
+
+    let mut apps: Vec<ProcessInfo> = Vec::new();
+    let mut seen = std::collections::HashSet::new();
+    for mut process in processes {
+        process.has_visible_window = true;
+        let key = process.exe_path.clone().unwrap_or_else(|| process.name.clone()).to_lowercase();
+        if seen.insert(key) {
+            apps.push(process);
+        }
+    }
+
+    for app in apps.iter_mut() {
+        app.icon = app.exe_path.as_deref().and_then(|path| cached_app_icon(&state, path));
+    }
+    apps.sort_by_cached_key(|app| app.name.to_lowercase());
+    apps
+}
+
+#[derive(Serialize)]
+struct ProcessListWithOther {
+    top: Vec<ProcessInfo>,
+    other: ProcessInfo,
+}
+
+// PID of the synthetic "Other" row, 0 is the System Idle Process
+const OTHER_PROCESSES_PID: u32 = u32::MAX;
+
+/// Top `n` processes by CPU plus one synthetic "Other (K processes)" entry summing the rest.
+/// A full poll like get_processes, with the same side effects
+#[tauri::command]
+fn get_processes_with_other(app: tauri::AppHandle, state: State<AppState>, n: usize) -> ProcessListWithOther {
+    let mut top = poll_processes(&app, &state, DetailLevel::Full);
+    let rest = top.split_off(n.min(top.len()));
+
+    let mut other = ProcessInfo {
+        pid: OTHER_PROCESSES_PID,
+        name: format!("Other ({} processes)", rest.len()),
+        category: "other".to_string(),
+        color_hex: "#808080".to_string(),
+        ..Default::default()
+    };
+    for process in &rest {
+        other.cpu_percent += process.cpu_percent;
+        other.memory_mb += process.memory_mb;
+        other.commit_mb += process.commit_mb;
+        other.peak_working_set_mb += process.peak_working_set_mb;
+        other.peak_commit_mb += process.peak_commit_mb;
+        other.memory_percent += process.memory_percent;
+        other.gpu_percent += process.gpu_percent;
+        other.gpu_memory_mb += process.gpu_memory_mb;
+        other.cpu_percent_avg += process.cpu_percent_avg;
+        other.gpu_percent_avg += process.gpu_percent_avg;
+    }
+    // Sums of rounded values pick up float tails again
+    other.cpu_percent = round_metric_f32(other.cpu_percent);
+    other.memory_mb = round_metric(other.memory_mb);
+    other.commit_mb = round_metric(other.commit_mb);
+    other.peak_working_set_mb = round_metric(other.peak_working_set_mb);
+    other.peak_commit_mb = round_metric(other.peak_commit_mb);
+    other.memory_percent = round_metric_f32(other.memory_percent);
+    other.gpu_percent = round_metric_f32(other.gpu_percent);
+    other.gpu_memory_mb = round_metric(other.gpu_memory_mb);
+    other.cpu_percent_avg = round_metric_f32(other.cpu_percent_avg);
+    other.gpu_percent_avg = round_metric_f32(other.gpu_percent_avg);
+
+    ProcessListWithOther { top, other }
+}
+
+// Tray load icon - system CPU drawn as a bar gauge, rendered at the tray's actual
+// pixel size (16 at 100% scale, 20/24/32 on HiDPI) so it isn't scaled blurry
+const TRAY_ID: &str = "main";
+static TRAY_LOAD_ICON: AtomicBool = AtomicBool::new(false);
+// Last drawn (size << 8 | 5% step), u32::MAX = nothing drawn yet
+static TRAY_LOAD_LEVEL: AtomicU32 = AtomicU32::new(u32::MAX);
+
+/// Small icon size in pixels at the DPI of the monitor showing the taskbar, which can
+/// differ from the system DPI with mixed-DPI displays
+#[cfg(windows)]
+fn tray_icon_size() -> u32 {
+    use windows::Win32::Graphics::Gdi::{MonitorFromWindow, MONITOR_DEFAULTTOPRIMARY};
+    use windows::Win32::UI::HiDpi::{GetDpiForMonitor, GetDpiForSystem, GetSystemMetricsForDpi, MDT_EFFECTIVE_DPI};
+    use windows::Win32::UI::WindowsAndMessaging::{FindWindowW, SM_CXSMICON};
+
+    let dpi = unsafe {
+        let taskbar = FindWindowW(windows::core::w!("Shell_TrayWnd"), None).unwrap_or_default();
+        let monitor = MonitorFromWindow(taskbar, MONITOR_DEFAULTTOPRIMARY);
+        let (mut dpi_x, mut dpi_y) = (0u32, 0u32);
+        match GetDpiForMonitor(monitor, MDT_EFFECTIVE_DPI, &mut dpi_x, &mut dpi_y) {
+            Ok(()) if dpi_x > 0 => dpi_x,
+            _ => GetDpiForSystem(),
+        }
+    };
+    let size = unsafe { GetSystemMetricsForDpi(SM_CXSMICON, dpi) };
+    if size > 0 { size as u32 } else { 16 }
+}
+
+#[cfg(not(windows))]
+fn tray_icon_size() -> u32 {
+    32
+}
+
+/// RGBA pixels of a bordered bar filled from the bottom to `load` percent
+fn render_load_icon(size: u32, load: f32) -> Vec<u8> {
+    let fill = if load >= 85.0 {
+        [232, 72, 72]
+    } else if load >= 60.0 {
+        [240, 180, 40]
+    } else {
+        [64, 200, 120]
+    };
+    let border = (size / 16).max(1);
+    let inner = size - 2 * border;
+    let filled = (inner as f32 * load / 100.0).round() as u32;
+
+    let mut rgba = vec![0u8; (size * size * 4) as usize];
+    for y in 0..size {
+        for x in 0..size {
+            let is_border = x < border || y < border || x >= size - border || y >= size - border;
+            let color = if is_border {
+                [200, 200, 200]
+            } else if y >= size - border - filled {
+                fill
+            } else {
+                continue;
+            };
+            let i = ((y * size + x) * 4) as usize;
+            rgba[i..i + 4].copy_from_slice(&[color[0], color[1], color[2], 255]);
+        }
+    }
+    rgba
+}
+
+/// Redraw the tray icon when the load moves to another 5% step or the DPI changed
+fn update_tray_load_icon(app: &tauri::AppHandle, load: f32) {
+    if !TRAY_LOAD_ICON.load(Ordering::SeqCst) {
+        return;
+    }
+    let size = tray_icon_size();
+    let step = (load.clamp(0.0, 100.0) / 5.0).round() as u32;
+    let level = (size << 8) | step;
+    if TRAY_LOAD_LEVEL.swap(level, Ordering::SeqCst) == level {
+        return;
+    }
+
+    if let Some(tray) = app.tray_by_id(TRAY_ID) {
+        let icon = tauri::image::Image::new_owned(render_load_icon(size, step as f32 * 5.0), size, size);
+        let _ = tray.set_icon(Some(icon));
+    }
+}
+
+/// Show system CPU load in the tray icon instead of the app icon (persisted)
+#[tauri::command]
+fn set_tray_load_icon(app: tauri::AppHandle, state: State<AppState>, enabled: bool) -> Result<(), String> {
+    TRAY_LOAD_ICON.store(enabled, Ordering::SeqCst);
+    TRAY_LOAD_LEVEL.store(u32::MAX, Ordering::SeqCst);
+    if !enabled {
+        if let (Some(tray), Some(icon)) = (app.tray_by_id(TRAY_ID), app.default_window_icon()) {
+            tray.set_icon(Some(icon.clone())).map_err(|e| e.to_string())?;
+        }
+    }
+    update_app_data(&state, |data| data.tray_load_icon = enabled)
+}
+
+/// Local stats, or the latest stats of a connected remote when `source` is given
+#[tauri::command]
+fn get_system_stats(app: tauri::AppHandle, state: State<AppState>, source: Option<String>) -> Result<SystemStats, String> {
+    if let Some(source) = source {
+        return remote_system_stats(&state, &source);
+    }
+    Ok(poll_system_stats(&app, &state))
+}
+
+/// One local stats poll, also driving the self CPU budget and the tray icon
+fn poll_system_stats(app: &tauri::AppHandle, state: &AppState) -> SystemStats {
+    let stats = collect_system_stats(state);
+    regulate_poll_interval(state, stats.cpu_cores);
+    update_tray_load_icon(app, stats.cpu_percent);
+    cache_for_stats_server(state, |cache| cache.stats_json = serde_json::to_string(&stats).ok());
+    stats
+}
+
+fn collect_system_stats(state: &AppState) -> SystemStats {
+    let mut system = state.system.lock().unwrap();
+    wait_for_cpu_baseline(state);
+    system.refresh_all();
+    let is_warming_up = state.stats_polls.fetch_add(1, Ordering::SeqCst) < CPU_WARMUP_POLLS;
+
+    let total_memory = system.total_memory();
+    let used_memory = system.used_memory();
+    let available_memory = system.available_memory();
+
+    // Calculate average CPU usage across all cores
+    // refresh_all covers refresh_cpu_all (usage and frequency), and the baseline wait
+    // above means even the first call has a real interval behind it
+    let cpu_percent = system.global_cpu_usage();
+    let per_core_usage = system.cpus().iter().map(|cpu| round_metric_f32(cpu.cpu_usage())).collect();
+    let cpu_frequency_mhz = system.cpus().iter().map(|cpu| cpu.frequency()).collect();
+    let power = read_power_draw(state);
+    let interfaces = sample_network_interfaces(state);
+    let cpu_temp_c = read_cpu_temperature(state);
+
+    SystemStats {
+        cpu_percent: round_metric_f32(cpu_percent),
+        memory_percent: if total_memory > 0 {
+            round_metric_f32((used_memory as f64 / total_memory as f64 * 100.0) as f32)
+        } else {
+            0.0
+        },
+        total_memory_gb: round_metric(total_memory as f64 / 1024.0 / 1024.0 / 1024.0),
+        used_memory_gb: round_metric(used_memory as f64 / 1024.0 / 1024.0 / 1024.0),
+        available_memory_gb: round_metric(available_memory as f64 / 1024.0 / 1024.0 / 1024.0),
+        cpu_cores: system.cpus().len(),
+        is_warming_up,
+        page_faults_per_sec: read_page_faults_per_sec(state),
+        committed_percent: read_committed_percent().map(round_metric_f32),
+        gpu_watts: power.gpu_watts,
+        cpu_watts: power.cpu_watts,
+        network_received_bytes_per_sec: round_metric(interfaces.iter().map(|i| i.received_bytes_per_sec).sum()),
+        network_transmitted_bytes_per_sec: round_metric(interfaces.iter().map(|i| i.transmitted_bytes_per_sec).sum()),
+        cpu_temp_c: cpu_temp_c.map(round_metric_f32),
+        gpu_temp_c: read_gpu_temperature(state),
+        per_core_usage,
+        cpu_frequency_mhz,
+    }
+}
+
+/// CPU temperature from the best sensor sysinfo exposes: the package sensor, then
+/// AMD's Tctl/Tdie, then the hottest core, then the ACPI thermal zone (all Windows offers without a driver)
+fn read_cpu_temperature(state: &AppState) -> Option<f32> {
+    let mut components = state.components.lock().unwrap();
+    components.refresh();
+
+    // Sensors that can't be read report NaN or 0
+    let readings: Vec<(String, f32)> = components
+        .iter()
+        .map(|c| (c.label().to_lowercase(), c.temperature()))
+        .filter(|(_, temp)| temp.is_finite() && *temp > 0.0)
+        .collect();
+    let hottest = |matches: &dyn Fn(&str) -> bool| {
+        readings
+            .iter()
+            .filter(|(label, _)| matches(label.as_str()))
+            .map(|(_, temp)| *temp)
+            .reduce(f32::max)
+    };
+
+    hottest(&|label| label.contains("package"))
+        .or_else(|| hottest(&|label| label.contains("tctl") || label.contains("tdie")))
+        .or_else(|| hottest(&|label| label.contains("cpu") || label.contains("core")))
+        .or_else(|| hottest(&|label| label.contains("computer") || label.contains("acpi")))
+}
+
+/// Temperature of the hottest NVIDIA device
+#[cfg(windows)]
+fn read_gpu_temperature(state: &AppState) -> Option<f32> {
+    use nvml_wrapper::enum_wrappers::device::TemperatureSensor;
+
+    let nvml = state.nvml.as_ref()?;
+    (0..nvml.device_count().unwrap_or(0))
+        .filter_map(|index| nvml.device_by_index(index).ok()?.temperature(TemperatureSensor::Gpu).ok())
+        .max()
+        .map(|celsius| celsius as f32)
+}
+
+#[cfg(not(windows))]
+fn read_gpu_temperature(_state: &AppState) -> Option<f32> {
+    None
+}
+
+/// System-wide page faults per second, None until the counter has two samples
+#[cfg(windows)]
+fn read_page_faults_per_sec(state: &AppState) -> Option<u64> {
+    let mut counter = state.page_fault_counter.lock().unwrap();
+    if counter.is_none() {
+        *counter = pdh::Counter::open("\\Memory\\Page Faults/sec");
+        return None;
+    }
+    counter.as_ref()?.read().map(|value| value.round() as u64)
+}
+
+#[cfg(not(windows))]
+fn read_page_faults_per_sec(_state: &AppState) -> Option<u64> {
+    None
+}
+
+// Network throughput - sysinfo's per-interface byte counters, diffed between refreshes
+struct NetworkSampler {
+    networks: sysinfo::Networks,
+    last_refresh: Instant,
+}
+
+#[derive(Serialize, Clone)]
+struct NetworkInterface {
+    name: String,
+    received_bytes_per_sec: f64,
+    transmitted_bytes_per_sec: f64,
+    total_received: u64,
+    total_transmitted: u64,
+}
+
+/// Loopback adapters only carry local traffic, "lo" on Linux and "Loopback Pseudo-Interface" on Windows
+fn is_loopback_interface(name: &str) -> bool {
+    name == "lo" || name.to_lowercase().contains("loopback")
+}
+
+/// Refresh the counters and return the rates of every active non-loopback interface.
+/// Rates cover the time since the previous refresh, whichever command made it.
+fn sample_network_interfaces(state: &AppState) -> Vec<NetworkInterface> {
+    let mut sampler = state.network_sampler.lock().unwrap();
+    sampler.networks.refresh();
+    let secs = sampler.last_refresh.elapsed().as_secs_f64();
+    sampler.last_refresh = Instant::now();
+
+    let rate = |bytes: u64| if secs > 0.0 { round_metric(bytes as f64 / secs) } else { 0.0 };
+    let mut interfaces: Vec<NetworkInterface> = sampler
+        .networks
+        .iter()
+        .filter(|(name, _)| !is_loopback_interface(name))
+        // Never passed a byte since boot: disconnected, disabled or virtual leftovers
+        .filter(|(_, data)| data.total_received() > 0 || data.total_transmitted() > 0)
+        .map(|(name, data)| NetworkInterface {
+            name: name.clone(),
+            received_bytes_per_sec: rate(data.received()),
+            transmitted_bytes_per_sec: rate(data.transmitted()),
+            total_received: data.total_received(),
+            total_transmitted: data.total_transmitted(),
+        })
+        .collect();
+    interfaces.sort_by(|a, b| a.name.cmp(&b.name));
+    interfaces
+}
+
+/// Throughput per active network interface, the system-wide total is on get_system_stats
+#[tauri::command]
+fn get_network_stats(state: State<AppState>) -> Vec<NetworkInterface> {
+    sample_network_interfaces(&state)
+}
+
+// Power draw - GPU from NVML, CPU package from the Energy Meter counters Windows
+// exposes for RAPL-capable processors
+#[derive(Serialize, Clone, Copy, Default)]
+struct PowerDraw {
+    gpu_watts: Option<f32>,
+    cpu_watts: Option<f32>,
+}
+
+#[cfg(windows)]
+fn read_power_draw(state: &AppState) -> PowerDraw {
+    // Summed over every NVIDIA device, None when none of them reports power
+    let gpu_watts = state.nvml.as_ref().and_then(|nvml| {
+        let readings: Vec<u32> = (0..nvml.device_count().unwrap_or(0))
+            .filter_map(|index| nvml.device_by_index(index).ok()?.power_usage().ok())
+            .collect();
+        // NVML reports milliwatts
+        (!readings.is_empty()).then(|| readings.iter().map(|&milliwatts| milliwatts as f32).sum::<f32>() / 1000.0)
+    });
+
+    let cpu_watts = {
+        let mut counter = state.energy_meter_counter.lock().unwrap();
+        if counter.is_none() {
+            *counter = pdh::Counter::open("\\Energy Meter(*)\\Power");
+        }
+        // Instances look like "RAPL_Package0_PKG", Power is in milliwatts
+        counter.as_ref().and_then(|counter| {
+            let packages: Vec<f64> = counter
+                .read_array()
+                .into_iter()
+                .filter(|(name, _)| name.to_ascii_uppercase().ends_with("_PKG"))
+                .map(|(_, milliwatts)| milliwatts)
+                .collect();
+            (!packages.is_empty()).then(|| (packages.iter().sum::<f64>() / 1000.0) as f32)
+        })
+    };
+
+    PowerDraw {
+        gpu_watts: gpu_watts.map(round_metric_f32),
+        cpu_watts: cpu_watts.map(round_metric_f32),
+    }
+}
+
+#[cfg(not(windows))]
+fn read_power_draw(_state: &AppState) -> PowerDraw {
+    PowerDraw::default()
+}
+
+/// Current GPU and CPU package power draw in watts
+#[tauri::command]
+fn get_power_draw(state: State<AppState>) -> PowerDraw {
+    read_power_draw(&state)
+}
+
+// Power plans - powrprof schemes, e.g. a one-click High Performance before gaming
+#[derive(Serialize, Clone)]
+struct PowerPlan {
+    // Lowercase, no braces: "8c5e7fda-e8bf-4a96-9a85-a6e23a8c635c"
+    guid: String,
+    name: String,
+}
+
+#[cfg(windows)]
+fn format_guid(guid: &windows::core::GUID) -> String {
+    format!("{:?}", guid).to_lowercase()
+}
+
+#[cfg(windows)]
+fn power_plan(guid: &windows::core::GUID) -> PowerPlan {
+    use windows::Win32::System::Power::PowerReadFriendlyName;
+    use windows::Win32::System::Registry::HKEY;
+
+    // Friendly names come back as a NUL-terminated UTF-16 string in a byte buffer
+    let mut size = 0u32;
+    let mut name = String::new();
+    unsafe {
+        if PowerReadFriendlyName(HKEY::default(), Some(guid), None, None, None, &mut size).is_ok() && size > 0 {
+            let mut buffer = vec![0u16; (size as usize).div_ceil(2)];
+            if PowerReadFriendlyName(HKEY::default(), Some(guid), None, None, Some(buffer.as_mut_ptr() as *mut u8), &mut size).is_ok() {
+                let len = buffer.iter().position(|&c| c == 0).unwrap_or(buffer.len());
+                name = String::from_utf16_lossy(&buffer[..len]);
+            }
+        }
+    }
+    PowerPlan { guid: format_guid(guid), name }
+}
+
+/// Every power scheme with its GUID value, in PowerEnumerate order
+#[cfg(windows)]
+fn enumerate_power_schemes() -> Vec<windows::core::GUID> {
+    use windows::core::GUID;
+    use windows::Win32::System::Power::{PowerEnumerate, ACCESS_SCHEME};
+    use windows::Win32::System::Registry::HKEY;
+
+    let mut schemes = Vec::new();
+    for index in 0.. {
+        let mut guid = GUID::zeroed();
+        let mut size = std::mem::size_of::<GUID>() as u32;
+        let result = unsafe {
+            PowerEnumerate(HKEY::default(), None, None, ACCESS_SCHEME, index, Some(&mut guid as *mut GUID as *mut u8), &mut size)
+        };
+        // ERROR_NO_MORE_ITEMS ends the list
+        if result.is_err() {
+            break;
+        }
+        schemes.push(guid);
+    }
+    schemes
+}
+
+/// Active power plan, None if it couldn't be read
+#[tauri::command]
+#[cfg(windows)]
+fn get_power_plan() -> Option<PowerPlan> {
+    use windows::core::GUID;
+    use windows::Win32::Foundation::{LocalFree, HLOCAL};
+    use windows::Win32::System::Power::PowerGetActiveScheme;
+    use windows::Win32::System::Registry::HKEY;
+
+    unsafe {
+        let mut active: *mut GUID = std::ptr::null_mut();
+        if PowerGetActiveScheme(HKEY::default(), &mut active).is_err() || active.is_null() {
+            return None;
+        }
+        let plan = power_plan(&*active);
+        let _ = LocalFree(HLOCAL(active as *mut _));
+        Some(plan)
+    }
+}
+
+#[tauri::command]
+#[cfg(windows)]
+fn list_power_plans() -> Vec<PowerPlan> {
+    enumerate_power_schemes().iter().map(power_plan).collect()
+}
+
+/// Activate a power plan by GUID (as returned by list_power_plans)
+#[tauri::command]
+#[cfg(windows)]
+fn set_power_plan(guid: String) -> Result<(), String> {
+    use windows::Win32::System::Power::PowerSetActiveScheme;
+    use windows::Win32::System::Registry::HKEY;
+
+    let wanted = guid.trim().trim_start_matches('{').trim_end_matches('}').to_lowercase();
+    let scheme = enumerate_power_schemes()
+        .into_iter()
+        .find(|scheme| format_guid(scheme) == wanted)
+        .ok_or_else(|| format!("No power plan with GUID {}", guid))?;
+
+    unsafe { PowerSetActiveScheme(HKEY::default(), Some(&scheme)) }
+        .ok()
+        .map_err(|e| format!("Failed to set power plan: {}", e))?;
+    log_info!("Power plan set to {}", wanted);
+    Ok(())
+}
+
+#[tauri::command]
+#[cfg(not(windows))]
+fn get_power_plan() -> Option<PowerPlan> {
+    None
+}
+
+#[tauri::command]
+#[cfg(not(windows))]
+fn list_power_plans() -> Vec<PowerPlan> {
+    Vec::new()
+}
+
+#[tauri::command]
+#[cfg(not(windows))]
+fn set_power_plan(_guid: String) -> Result<(), String> {
+    Err("Not supported on this platform".to_string())
+}
+
+/// Commit charge as a percentage of the commit limit (RAM + page file)
+#[cfg(windows)]
+fn read_committed_percent() -> Option<f32> {
+    use windows::Win32::System::ProcessStatus::{GetPerformanceInfo, PERFORMANCE_INFORMATION};
+
+    let mut info = PERFORMANCE_INFORMATION::default();
+    let size = std::mem::size_of::<PERFORMANCE_INFORMATION>() as u32;
+    unsafe { GetPerformanceInfo(&mut info, size) }.ok()?;
+    if info.CommitLimit == 0 {
+        return None;
+    }
+    Some((info.CommitTotal as f64 / info.CommitLimit as f64 * 100.0) as f32)
+}
+
+#[cfg(not(windows))]
+fn read_committed_percent() -> Option<f32> {
+    None
+}
+
+// Memory compression - Windows 10+ keeps compressed pages in the working set of the
+// "Memory Compression" process, which grows under pressure before "available" drops
+#[derive(Serialize)]
+struct MemoryCompression {
+    // RAM the compressed store occupies right now
+    compressed_mb: f64,
+    // Address space of the store, including compressed pages written out to the page file
+    store_size_mb: f64,
+}
+
+#[cfg(windows)]
+fn read_memory_compression() -> Option<MemoryCompression> {
+    let buffer = ntdll::query_process_list()?;
+    // Started by System, read from the process list since it refuses OpenProcess
+    let entry = ntdll::processes(&buffer)
+        .into_iter()
+        .find(|entry| entry.inherited_from_unique_process_id == 4 && ntdll::image_name(entry) == "Memory Compression")?;
+    let mb = |bytes: usize| round_metric(bytes as f64 / 1024.0 / 1024.0);
+    Some(MemoryCompression {
+        compressed_mb: mb(entry.working_set_size),
+        store_size_mb: mb(entry.virtual_size),
+    })
+}
+
+#[cfg(not(windows))]
+fn read_memory_compression() -> Option<MemoryCompression> {
+    None
+}
+
+/// Size of the compressed memory store, None when compression is off or unsupported
+#[tauri::command]
+fn get_memory_compression() -> Option<MemoryCompression> {
+    read_memory_compression()
+}
+
+// Disk activity - per physical disk PDH counters, kept open so rates span polls
+#[derive(Serialize)]
+struct DiskActivity {
+    // PDH instance name, e.g. "0 C:"
+    drive: String,
+    busy_percent: f64,
+    queue_length: f64,
+    read_bytes_per_sec: f64,
+    write_bytes_per_sec: f64,
+}
+
+#[cfg(windows)]
+struct DiskCounters {
+    disk_time: pdh::Counter,
+    queue_length: pdh::Counter,
+    read_bytes: pdh::Counter,
+    write_bytes: pdh::Counter,
+}
+
+#[cfg(windows)]
+impl DiskCounters {
+    fn open() -> Option<DiskCounters> {
+        Some(DiskCounters {
+            disk_time: pdh::Counter::open("\\PhysicalDisk(*)\\% Disk Time")?,
+            queue_length: pdh::Counter::open("\\PhysicalDisk(*)\\Current Disk Queue Length")?,
+            read_bytes: pdh::Counter::open("\\PhysicalDisk(*)\\Disk Read Bytes/sec")?,
+            write_bytes: pdh::Counter::open("\\PhysicalDisk(*)\\Disk Write Bytes/sec")?,
+        })
+    }
+}
+
+/// Busy %, queue length and throughput per physical disk
+/// The first call only primes the counters and returns an empty list
+#[tauri::command]
+#[cfg(windows)]
+fn get_disk_activity(state: State<AppState>) -> Vec<DiskActivity> {
+    let mut guard = state.disk_counters.lock().unwrap();
+    if guard.is_none() {
+        *guard = DiskCounters::open();
+        return Vec::new();
+    }
+    let Some(counters) = guard.as_ref() else { return Vec::new() };
+
+    let queue_length: HashMap<String, f64> = counters.queue_length.read_array().into_iter().collect();
+    let read_bytes: HashMap<String, f64> = counters.read_bytes.read_array().into_iter().collect();
+    let write_bytes: HashMap<String, f64> = counters.write_bytes.read_array().into_iter().collect();
+
+    let mut disks: Vec<DiskActivity> = counters
+        .disk_time
+        .read_array()
+        .into_iter()
+        .filter(|(drive, _)| drive != "_Total")
+        .map(|(drive, disk_time)| DiskActivity {
+            busy_percent: disk_time.clamp(0.0, 100.0),
+            queue_length: queue_length.get(&drive).copied().unwrap_or(0.0),
+            read_bytes_per_sec: read_bytes.get(&drive).copied().unwrap_or(0.0),
+            write_bytes_per_sec: write_bytes.get(&drive).copied().unwrap_or(0.0),
+            drive,
+        })
+        .collect();
+    disks.sort_by(|a, b| a.drive.cmp(&b.drive));
+    disks
+}
+
+#[tauri::command]
+#[cfg(not(windows))]
+fn get_disk_activity() -> Vec<DiskActivity> {
+    Vec::new()
+}
+
+/// Full details of one process. Runs off the main thread, the refresh and the
+/// I/O wait sampling take long enough to freeze the UI
+#[tauri::command]
+async fn get_process_by_pid(app: tauri::AppHandle, pid: u32) -> Option<ProcessInfo> {
+    tauri::async_runtime::spawn_blocking(move || collect_process_by_pid(&app.state::<AppState>(), pid))
+        .await
+        .ok()
+        .flatten()
+}
+
+fn collect_process_by_pid(state: &AppState, pid: u32) -> Option<ProcessInfo> {
+    let mut system = state.system.lock().unwrap();
+    system.refresh_all();
+
+    let gpu = cached_gpu_usage(state);
+    let category_rules = state.category_rules.lock().unwrap().clone();
+    let gpu_engines = read_gpu_engines(state);
+
+    // Single-process lookups report raw per-core CPU usage
+    let ctx = CollectionContext {
+        total_memory: system.total_memory(),
+        cpu_divisor: 1.0,
+        gpu: &gpu,
+        category_rules: &category_rules,
+        version_cache: &state.version_cache,
+        memory_metric: *state.memory_metric.lock().unwrap(),
+        gpu_engines: &gpu_engines,
+        detail: DetailLevel::Full,
+    };
+
+    let mut info = system.process(Pid::from_u32(pid)).map(|process| {
+        let mut info = build_process_info(pid, process, &ctx);
+        info.ancestor_chain = ancestor_chain(&system, process);
+        info
+    });
+    drop(system);
+
+    if let Some(info) = info.as_mut() {
+        mark_new_binaries(state, std::slice::from_mut(info));
+        attach_service_names(state, std::slice::from_mut(info));
+        mark_visible_windows(std::slice::from_mut(info));
+        mark_suspended_processes(state, std::slice::from_mut(info));
+        info.io_wait_percent = sample_io_wait_percent(pid).map(round_metric_f32);
+        info.context_switches_per_sec = context_switch_rate(state, pid, info.create_time);
+        let sockets = read_socket_counts().get(&pid).copied().unwrap_or_default();
+        info.tcp_connections = sockets.tcp;
+        info.udp_connections = sockets.udp;
+        let gui = read_gui_resources(pid).unwrap_or_default();
+        info.gdi_objects = gui.gdi;
+        info.user_objects = gui.user;
+    }
+    info
+}
+
+// Socket counts - rows per owning PID in the TCP/UDP tables, a cheap connection leak signal
+#[derive(Default, Clone, Copy)]
+struct SocketCounts {
+    tcp: u32,
+    udp: u32,
+}
+
+/// PID -> TCP connections and UDP endpoints over IPv4 and IPv6, from one pass over each table
+#[cfg(windows)]
+fn read_socket_counts() -> HashMap<u32, SocketCounts> {
+    use std::ffi::c_void;
+    use windows::Win32::NetworkManagement::IpHelper::{
+        GetExtendedTcpTable, GetExtendedUdpTable, MIB_TCP6TABLE_OWNER_PID, MIB_TCPTABLE_OWNER_PID,
+        MIB_UDP6TABLE_OWNER_PID, MIB_UDPTABLE_OWNER_PID, TCP_TABLE_OWNER_PID_ALL, UDP_TABLE_OWNER_PID,
+    };
+    use windows::Win32::Networking::WinSock::{AF_INET, AF_INET6};
+    const ERROR_INSUFFICIENT_BUFFER: u32 = 122;
+
+    /// Table bytes (u64 storage keeps the rows aligned), retried if it grew between calls
+    fn table(query: impl Fn(Option<*mut c_void>, *mut u32) -> u32) -> Option<Vec<u64>> {
+        let mut size = 0u32;
+        for _ in 0..4 {
+            let mut buffer: Vec<u64> = vec![0; (size as usize).div_ceil(8).max(1)];
+            match query(Some(buffer.as_mut_ptr() as *mut c_void), &mut size) {
+                0 => return Some(buffer),
+                ERROR_INSUFFICIENT_BUFFER => continue,
+                _ => return None,
+            }
+        }
+        None
+    }
+
+    let (ipv4, ipv6) = (AF_INET.0 as u32, AF_INET6.0 as u32);
+    let mut counts: HashMap<u32, SocketCounts> = HashMap::new();
+    unsafe {
+        if let Some(buffer) = table(|b, s| GetExtendedTcpTable(b, s, false, ipv4, TCP_TABLE_OWNER_PID_ALL, 0)) {
+            let table = &*(buffer.as_ptr() as *const MIB_TCPTABLE_OWNER_PID);
+            for row in std::slice::from_raw_parts(table.table.as_ptr(), table.dwNumEntries as usize) {
+                counts.entry(row.dwOwningPid).or_default().tcp += 1;
+            }
+        }
+        if let Some(buffer) = table(|b, s| GetExtendedTcpTable(b, s, false, ipv6, TCP_TABLE_OWNER_PID_ALL, 0)) {
+            let table = &*(buffer.as_ptr() as *const MIB_TCP6TABLE_OWNER_PID);
+            for row in std::slice::from_raw_parts(table.table.as_ptr(), table.dwNumEntries as usize) {
+                counts.entry(row.dwOwningPid).or_default().tcp += 1;
+            }
+        }
+        if let Some(buffer) = table(|b, s| GetExtendedUdpTable(b, s, false, ipv4, UDP_TABLE_OWNER_PID, 0)) {
+            let table = &*(buffer.as_ptr() as *const MIB_UDPTABLE_OWNER_PID);
+            for row in std::slice::from_raw_parts(table.table.as_ptr(), table.dwNumEntries as usize) {
+                counts.entry(row.dwOwningPid).or_default().udp += 1;
+            }
+        }
+        if let Some(buffer) = table(|b, s| GetExtendedUdpTable(b, s, false, ipv6, UDP_TABLE_OWNER_PID, 0)) {
+            let table = &*(buffer.as_ptr() as *const MIB_UDP6TABLE_OWNER_PID);
+            for row in std::slice::from_raw_parts(table.table.as_ptr(), table.dwNumEntries as usize) {
+                counts.entry(row.dwOwningPid).or_default().udp += 1;
+            }
+        }
+    }
+    counts
+}
+
+#[cfg(not(windows))]
+fn read_socket_counts() -> HashMap<u32, SocketCounts> {
+    HashMap::new()
+}
+
+// Ancestor chain - who started a process, for triage ("winword.exe -> cmd.exe -> ...")
+const MAX_ANCESTOR_DEPTH: usize = 32;
+
+/// Names of the live ancestors of `process`, root first, stopping at the first one that exited
+fn ancestor_chain(system: &System, process: &sysinfo::Process) -> Vec<String> {
+    let mut chain = Vec::new();
+    let mut current = process;
+    // Start times only go back in time, the depth cap is a backstop against odd parent data
+    while chain.len() < MAX_ANCESTOR_DEPTH {
+        let Some(parent) = live_parent(system, current) else { break };
+        chain.push(canonical_name(parent));
+        current = parent;
+    }
+    chain.reverse();
+    chain
+}
+
+// Context switches - summed over a process's threads, diffed between detail lookups
+const CONTEXT_SWITCH_SAMPLE_TTL_SECS: u64 = 300;
+
+struct ContextSwitchSample {
+    create_time: u64,
+    switches: u64,
+    at: Instant,
+}
+
+/// Total context switches of the threads currently alive in a process
+#[cfg(windows)]
+fn read_context_switches(pid: u32) -> Option<u64> {
+    let buffer = ntdll::query_process_list()?;
+    let threads = ntdll::process_threads(&buffer, pid)?;
+    Some(threads.iter().map(|thread| thread.context_switches as u64).sum())
+}
+
+#[cfg(not(windows))]
+fn read_context_switches(_pid: u32) -> Option<u64> {
+    None
+}
+
+/// Switches per second since the previous lookup of `pid`, None on the first one
+fn context_switch_rate(state: &AppState, pid: u32, create_time: u64) -> Option<u64> {
+    let switches = read_context_switches(pid)?;
+    let now = Instant::now();
+    let mut samples = state.context_switches.lock().unwrap();
+    // Processes that stopped being looked at
+    samples.retain(|_, sample| (now - sample.at).as_secs() < CONTEXT_SWITCH_SAMPLE_TTL_SECS);
+
+    let previous = samples.insert(pid, ContextSwitchSample { create_time, switches, at: now })?;
+    // PID reused by another process
+    if previous.create_time != create_time {
+        return None;
+    }
+    let secs = (now - previous.at).as_secs_f64();
+    // Exited threads take their counts with them, so the total can drop
+    (secs > 0.0).then(|| (switches.saturating_sub(previous.switches) as f64 / secs).round() as u64)
+}
+
+// I/O wait - sample thread states a few times and count threads parked in the
+// kernel waits used for synchronous I/O and hard page faults
+#[cfg(windows)]
+const IO_WAIT_SAMPLES: u32 = 5;
+#[cfg(windows)]
+const IO_WAIT_SAMPLE_INTERVAL_MS: u64 = 20;
+// KWAIT_REASON: Executive, FreePage, PageIn, WrExecutive, WrFreePage, WrPageIn
+#[cfg(windows)]
+const IO_WAIT_REASONS: [u32; 6] = [0, 1, 2, 7, 8, 9];
+
+#[cfg(windows)]
+fn sample_io_wait_percent(pid: u32) -> Option<f32> {
+    let mut thread_samples = 0u32;
+    let mut io_waits = 0u32;
+    for sample in 0..IO_WAIT_SAMPLES {
+        if sample > 0 {
+            std::thread::sleep(std::time::Duration::from_millis(IO_WAIT_SAMPLE_INTERVAL_MS));
+        }
+        let buffer = ntdll::query_process_list()?;
+        let threads = ntdll::process_threads(&buffer, pid)?;
+        for thread in threads {
+            thread_samples += 1;
+            if thread.thread_state == ntdll::THREAD_STATE_WAITING && IO_WAIT_REASONS.contains(&thread.wait_reason) {
+                io_waits += 1;
+            }
+        }
+    }
+    (thread_samples > 0).then(|| io_waits as f32 / thread_samples as f32 * 100.0)
+}
+
+#[cfg(not(windows))]
+fn sample_io_wait_percent(_pid: u32) -> Option<f32> {
+    None
+}
+
+#[derive(Serialize)]
+struct NumaNode {
+    node: u32,
+    // None when the node's installed size is unknown (Windows only reports available memory per node)
+    total_mb: Option<u64>,
+    available_mb: u64,
+}
+
+/// Available memory per NUMA node, None on non-NUMA machines. Nodes whose available
+/// memory can't be read are left out rather than reported as empty
+#[cfg(windows)]
+fn read_numa_nodes() -> Option<Vec<NumaNode>> {
+    use windows::Win32::System::SystemInformation::{GetNumaAvailableMemoryNodeEx, GetNumaHighestNodeNumber};
+
+    let mut highest_node: u32 = 0;
+    unsafe { GetNumaHighestNodeNumber(&mut highest_node) }.ok()?;
+    if highest_node == 0 {
+        return None;
+    }
+
+    let nodes: Vec<NumaNode> = (0..=highest_node)
+        .filter_map(|node| {
+            let mut available: u64 = 0;
+            unsafe { GetNumaAvailableMemoryNodeEx(node as u16, &mut available) }.ok()?;
+            Some(NumaNode {
+                node,
+                total_mb: None,
+                available_mb: available / 1024 / 1024,
+            })
+        })
+        .collect();
+
+    (!nodes.is_empty()).then_some(nodes)
+}
+
+#[cfg(not(windows))]
+fn read_numa_nodes() -> Option<Vec<NumaNode>> {
+    None
+}
+
+#[tauri::command]
+fn get_numa_stats(state: State<AppState>) -> Vec<NumaNode> {
+    let (total_bytes, available_bytes) = {
+        let mut system = state.system.lock().unwrap();
+        system.refresh_memory();
+        (system.total_memory(), system.available_memory())
+    };
+
+    read_numa_nodes().unwrap_or_else(|| vec![NumaNode {
+        node: 0,
+        total_mb: Some(total_bytes / 1024 / 1024),
+        available_mb: available_bytes / 1024 / 1024,
+    }])
+}
+
+// Performance snapshot for charts
+#[derive(Serialize, Deserialize, Clone)]
+struct PerformanceSnapshot {
+    timestamp: String,
+    cpu_percent: f64,
+    memory_mb: f64,
+    memory_percent: f64,
+    #[serde(default)]
+    gpu_percent: f64,
+    #[serde(default)]
+    user_activity_percent: f64,
+    #[serde(default)]
+    is_foreground: bool,
+    // App in the foreground at the time, whichever it was
+    #[serde(default)]
+    foreground_app: Option<String>,
+}
+
+// Session and whitelist persistence structures
+#[derive(Serialize, Deserialize, Clone)]
+struct SavedSession {
+    id: i64,
+    app_name: String,
+    start_time: String,
+    end_time: Option<String>,
+    duration_seconds: i64,
     avg_cpu_percent: f64,
     avg_memory_mb: f64,
     #[serde(default)]
@@ -542,116 +3753,5304 @@ struct SavedSession {
     peak_memory_mb: f64,
     #[serde(default)]
     peak_gpu_percent: f64,
+    // CPU time consumed, in seconds on one core
+    #[serde(default)]
+    cpu_seconds: f64,
+    // Highest OS-reported peaks of the app's processes while the session ran
+    #[serde(default)]
+    peak_working_set_mb: f64,
+    #[serde(default)]
+    peak_commit_mb: f64,
     is_current: bool,
     #[serde(default)]
     performance_history: Vec<PerformanceSnapshot>,
+    // Owned by the backend (set_session_tags / set_session_notes), save_app_data keeps the stored ones
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(default)]
+    notes: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct SavedWhitelistEntry {
+    id: i64,
+    name: String,
+    exe_path: Option<String>,
+    added_date: String,
+    is_tracked: bool,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct AppData {
+    whitelist: Vec<SavedWhitelistEntry>,
+    sessions: Vec<SavedSession>,
+    next_session_id: i64,
+    #[serde(default)]
+    category_rules: Vec<(String, String)>,
+    #[serde(default)]
+    anomaly_sensitivity: Option<f32>,
+    #[serde(default)]
+    first_seen: HashMap<String, String>,
+    // Time of the first scan, exe paths first seen then were already installed
+    #[serde(default)]
+    first_seen_baseline: Option<String>,
+    // Manual display scale override, None = detect
+    #[serde(default)]
+    screen_scale: Option<f32>,
+    #[serde(default)]
+    protected_processes: Vec<String>,
+    #[serde(default)]
+    memory_metric: MemoryMetric,
+    #[serde(default)]
+    memory_units: MemoryUnits,
+    #[serde(default)]
+    auto_track_foreground: bool,
+    #[serde(default)]
+    auto_track_grace_secs: Option<u64>,
+    #[serde(default)]
+    log_level: Option<String>,
+    // None = default (excluded)
+    #[serde(default)]
+    exclude_own_input: Option<bool>,
+    #[serde(default)]
+    metric_precision: Option<u8>,
+    #[serde(default)]
+    tray_load_icon: bool,
+    #[serde(default)]
+    display_average_window: Option<usize>,
+    #[serde(default)]
+    auto_optimize_on_idle: bool,
+    #[serde(default)]
+    auto_optimize_idle_secs: Option<u64>,
+    #[serde(default)]
+    activity_app_filter: Vec<String>,
+    // Local date (YYYY-MM-DD) -> activity totals
+    #[serde(default)]
+    daily_activity: std::collections::BTreeMap<String, DayActivity>,
+    #[serde(default)]
+    process_rules: Vec<ProcessRule>,
+    #[serde(default)]
+    max_list_length: Option<usize>,
+    #[serde(default)]
+    crash_reports: bool,
+    #[serde(default)]
+    stats_server_port: Option<u16>,
+    // Listen on every interface instead of loopback only, needs stats_server_token
+    #[serde(default)]
+    stats_server_lan: bool,
+    #[serde(default)]
+    stats_server_token: Option<String>,
+    #[serde(default)]
+    gpu_smoothing_alpha: Option<f32>,
+    #[serde(default)]
+    resume_sessions: bool,
+    #[serde(default)]
+    watched_directories: Vec<WatchedDirectory>,
+    #[serde(default)]
+    self_cpu_budget: Option<f32>,
+}
+
+const DATA_FILE_NAME: &str = "performance_guard_data.json";
+
+fn get_data_file_path(state: &AppState) -> PathBuf {
+    state.data_path.join(DATA_FILE_NAME)
+}
+
+fn read_app_data(data_file: &Path) -> Result<AppData, String> {
+    if !data_file.exists() {
+        return Ok(AppData::default());
+    }
+
+    let content = fs::read_to_string(data_file).map_err(|e| e.to_string())?;
+    serde_json::from_str(&content).map_err(|e| e.to_string())
+}
+
+/// `path` with `suffix` appended to the file name, e.g. "data.json" -> "data.json.bak"
+fn with_name_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(suffix);
+    path.with_file_name(name)
+}
+
+// Makes every temp file name unique, so two writers never share one
+static DATA_WRITE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Write to a temp file next to the data file and rename it over, so a crash mid-write
+/// never leaves a truncated file and the data file never goes missing. The replaced file
+/// is copied to .bak first if it still parsed. Callers hold AppState::data_file_lock
+fn write_app_data(data_file: &Path, data: &AppData) -> Result<(), String> {
+    use std::io::Write;
+
+    // Ensure directory exists
+    if let Some(parent) = data_file.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+
+    let json = serde_json::to_string_pretty(data).map_err(|e| e.to_string())?;
+    let temp_file = with_name_suffix(
+        data_file,
+        &format!(".{}-{}.tmp", std::process::id(), DATA_WRITE_COUNTER.fetch_add(1, Ordering::SeqCst)),
+    );
+    let written = fs::File::create(&temp_file).and_then(|mut file| {
+        file.write_all(json.as_bytes())?;
+        // Flushed to disk before the rename makes it the real file
+        file.sync_all()
+    });
+    if let Err(e) = written {
+        let _ = fs::remove_file(&temp_file);
+        return Err(e.to_string());
+    }
+
+    // A corrupt file must not overwrite the last good backup
+    let previous_is_valid = fs::read_to_string(data_file)
+        .is_ok_and(|content| serde_json::from_str::<serde::de::IgnoredAny>(&content).is_ok());
+    if previous_is_valid {
+        if let Err(e) = fs::copy(data_file, with_name_suffix(data_file, ".bak")) {
+            log_warn!("Failed to back up {}: {}", data_file.display(), e);
+        }
+    }
+    // Same directory, so the rename is atomic (and replaces the target on Windows too)
+    fs::rename(&temp_file, data_file).map_err(|e| {
+        let _ = fs::remove_file(&temp_file);
+        e.to_string()
+    })
+}
+
+/// What recover_app_data did about an unreadable data file
+#[derive(Serialize, Clone)]
+struct DataRecovery {
+    // "backup" when the .bak was restored, "defaults" when nothing could be read
+    restored_from: String,
+    // Where the unreadable file was moved, None when it was missing rather than corrupt
+    corrupt_file: Option<String>,
+    error: String,
+}
+
+/// Read the data file, falling back to the .bak and then to defaults instead of failing.
+/// A corrupt file is moved aside as performance_guard_data.corrupt-<timestamp>.json
+fn recover_app_data(data_file: &Path) -> (AppData, Option<DataRecovery>) {
+    let backup_file = with_name_suffix(data_file, ".bak");
+    let read_backup = || if backup_file.exists() { read_app_data(&backup_file).ok() } else { None };
+
+    let (error, corrupt_file) = match read_app_data(data_file) {
+        Ok(data) if data_file.exists() || !backup_file.exists() => return (data, None),
+        // Missing but backed up: deleted by hand, or lost to a disk error
+        Ok(_) => ("Data file missing".to_string(), None),
+        Err(e) => {
+            let stem = data_file.file_stem().unwrap_or_default().to_string_lossy().to_string();
+            let corrupt = data_file.with_file_name(format!(
+                "{}.corrupt-{}.json",
+                stem,
+                chrono::Local::now().format("%Y%m%d-%H%M%S")
+            ));
+            match fs::rename(data_file, &corrupt) {
+                Ok(()) => (e, Some(corrupt.to_string_lossy().to_string())),
+                Err(rename_error) => {
+                    log_error!("Failed to move aside {}: {}", data_file.display(), rename_error);
+                    (e, None)
+                }
+            }
+        }
+    };
+
+    let (data, restored_from) = match read_backup() {
+        Some(data) => {
+            if let Err(e) = write_app_data(data_file, &data) {
+                log_error!("Failed to restore {} from backup: {}", data_file.display(), e);
+            }
+            (data, "backup")
+        }
+        None => (AppData::default(), "defaults"),
+    };
+    log_warn!(
+        "Recovered {} from {} ({}), unreadable file kept at {}",
+        data_file.display(),
+        restored_from,
+        error,
+        corrupt_file.as_deref().unwrap_or("-")
+    );
+    let recovery = DataRecovery { restored_from: restored_from.to_string(), corrupt_file, error };
+    (data, Some(recovery))
+}
+
+/// Data file contents for a read-modify-write, recovering an unreadable file the way
+/// load_app_data does instead of failing every write until restart. Callers hold data_file_lock
+fn read_app_data_for_update(state: &AppState, data_file: &Path) -> AppData {
+    let (data, recovery) = recover_app_data(data_file);
+    if recovery.is_some() {
+        *state.data_recovery.lock().unwrap() = recovery;
+        reload_daily_activity(state, &data.daily_activity);
+    }
+    data
+}
+
+/// Read-modify-write the data file for backend-owned settings
+fn update_app_data(state: &AppState, update: impl FnOnce(&mut AppData)) -> Result<(), String> {
+    let _lock = state.data_file_lock.lock().unwrap();
+    let data_file = get_data_file_path(state);
+    let mut data = read_app_data_for_update(state, &data_file);
+    update(&mut data);
+    write_app_data(&data_file, &data)
+        .inspect_err(|e| log_error!("Failed to write {}: {}", data_file.display(), e))
+}
+
+/// Lowercase absolute form of an exe path, so different spellings of one file compare equal
+fn canonical_exe_path(path: &str) -> String {
+    let resolved = fs::canonicalize(path)
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or_else(|_| path.to_string());
+    if cfg!(windows) {
+        // canonicalize returns verbatim (\\?\) paths on Windows
+        let resolved = resolved.strip_prefix(r"\\?\").unwrap_or(&resolved);
+        resolved.replace('/', "\\").to_lowercase()
+    } else {
+        resolved
+    }
+}
+
+/// Collapse entries for the same executable into the first one, which keeps its id and
+/// name and stays tracked if any of the duplicates was. Also returns dropped name -> kept
+/// name, for moving sessions recorded under a dropped entry's name over to the kept one
+fn dedup_whitelist(entries: Vec<SavedWhitelistEntry>) -> (Vec<SavedWhitelistEntry>, HashMap<String, String>) {
+    let mut deduped: Vec<SavedWhitelistEntry> = Vec::with_capacity(entries.len());
+    let mut renamed: HashMap<String, String> = HashMap::new();
+    let mut by_path: HashMap<String, usize> = HashMap::new();
+    for entry in entries {
+        let Some(path) = entry.exe_path.as_deref().map(canonical_exe_path) else {
+            deduped.push(entry);
+            continue;
+        };
+        match by_path.get(&path) {
+            Some(&index) => {
+                let kept = &mut deduped[index];
+                kept.is_tracked |= entry.is_tracked;
+                log_info!("Merged duplicate whitelist entry {} into {}", entry.name, kept.name);
+                if entry.name != kept.name {
+                    renamed.insert(entry.name, kept.name.clone());
+                }
+            }
+            None => {
+                by_path.insert(path, deduped.len());
+                deduped.push(entry);
+            }
+        }
+    }
+    // A name some kept entry still uses keeps its sessions
+    renamed.retain(|dropped, _| !deduped.iter().any(|entry| entry.name == *dropped));
+    (deduped, renamed)
+}
+
+/// Returns the whitelist as stored, with entries for the same executable merged
+#[tauri::command]
+fn save_app_data(
+    state: State<AppState>,
+    whitelist: Vec<SavedWhitelistEntry>,
+    sessions: Vec<SavedSession>,
+    next_session_id: i64,
+) -> Result<Vec<SavedWhitelistEntry>, String> {
+    let (whitelist, renamed) = dedup_whitelist(whitelist);
+    // Backend-owned settings live in the same file, keep them intact
+    update_app_data(&state, |data| {
+        let mut annotations: HashMap<i64, (Vec<String>, String)> = data
+            .sessions
+            .drain(..)
+            .map(|session| (session.id, (session.tags, session.notes)))
+            .collect();
+        data.sessions = sessions
+            .into_iter()
+            .map(|mut session| {
+                if let Some((tags, notes)) = annotations.remove(&session.id) {
+                    session.tags = tags;
+                    session.notes = notes;
+                }
+                if let Some(kept) = renamed.get(&session.app_name) {
+                    session.app_name = kept.clone();
+                }
+                session
+            })
+            .collect();
+        data.whitelist = whitelist.clone();
+        data.next_session_id = next_session_id;
+    })?;
+    Ok(whitelist)
+}
+
+/// Apply `update` to a saved session and persist it, Err when no saved session has `id`
+fn update_saved_session(state: &AppState, id: i64, update: impl FnOnce(&mut SavedSession)) -> Result<(), String> {
+    let _lock = state.data_file_lock.lock().unwrap();
+    let data_file = get_data_file_path(state);
+    let mut data = read_app_data_for_update(state, &data_file);
+    // The running session is only written once it ends
+    let session = data
+        .sessions
+        .iter_mut()
+        .find(|session| session.id == id)
+        .ok_or_else(|| format!("No saved session with id {}", id))?;
+    update(session);
+    write_app_data(&data_file, &data)
+}
+
+/// Replace a session's tags (trimmed, empty and duplicate tags dropped)
+#[tauri::command]
+fn set_session_tags(state: State<AppState>, id: i64, tags: Vec<String>) -> Result<(), String> {
+    let mut cleaned: Vec<String> = Vec::new();
+    for tag in tags {
+        let tag = tag.trim();
+        if !tag.is_empty() && !cleaned.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+            cleaned.push(tag.to_string());
+        }
+    }
+    update_saved_session(&state, id, |session| session.tags = cleaned)
+}
+
+#[tauri::command]
+fn set_session_notes(state: State<AppState>, id: i64, notes: String) -> Result<(), String> {
+    update_saved_session(&state, id, |session| session.notes = notes)
+}
+
+// Chart downsampling - largest-triangle-three-buckets keeps the visual peaks of long sessions
+/// The series the charts draw, all on a 0-100 scale so their triangle areas add up fairly
+fn chart_values(snapshot: &PerformanceSnapshot) -> [f64; 3] {
+    [snapshot.cpu_percent, snapshot.memory_percent, snapshot.gpu_percent]
+}
+
+/// LTTB over evenly spaced samples, always keeping the first and last one
+fn downsample_lttb(points: &[PerformanceSnapshot], max_points: usize) -> Vec<PerformanceSnapshot> {
+    let n = points.len();
+    if n <= max_points {
+        return points.to_vec();
+    }
+    if max_points < 3 {
+        return [&points[0], &points[n - 1]].into_iter().take(max_points).cloned().collect();
+    }
+
+    // Middle points split into max_points - 2 buckets, one survivor each
+    let every = (n - 2) as f64 / (max_points - 2) as f64;
+    let mut sampled = Vec::with_capacity(max_points);
+    sampled.push(points[0].clone());
+    let mut previous = 0;
+
+    for bucket in 0..max_points - 2 {
+        // Average of the next bucket (or the last point) is the third triangle corner
+        let next_start = ((bucket + 1) as f64 * every) as usize + 1;
+        let next_end = (((bucket + 2) as f64 * every) as usize + 1).min(n);
+        let next_len = (next_end - next_start) as f64;
+        let avg_x = (next_start + next_end - 1) as f64 / 2.0;
+        let mut avg_y = [0.0; 3];
+        for point in &points[next_start..next_end] {
+            for (sum, value) in avg_y.iter_mut().zip(chart_values(point)) {
+                *sum += value / next_len;
+            }
+        }
+
+        let start = (bucket as f64 * every) as usize + 1;
+        let prev_x = previous as f64;
+        let prev_y = chart_values(&points[previous]);
+
+        let mut best = start;
+        let mut best_area = -1.0;
+        for (i, point) in points.iter().enumerate().take(next_start).skip(start) {
+            let area: f64 = chart_values(point)
+                .iter()
+                .zip(prev_y.iter().zip(avg_y))
+                .map(|(y, (prev, avg))| ((prev_x - avg_x) * (y - prev) - (prev_x - i as f64) * (avg - prev)).abs())
+                .sum();
+            if area > best_area {
+                best_area = area;
+                best = i;
+            }
+        }
+
+        sampled.push(points[best].clone());
+        previous = best;
+    }
+
+    sampled.push(points[n - 1].clone());
+    sampled
+}
+
+/// A saved session's performance history reduced to at most `max_points` for charting
+#[tauri::command]
+fn get_session_history_downsampled(
+    state: State<AppState>,
+    session_id: i64,
+    max_points: usize,
+) -> Result<Vec<PerformanceSnapshot>, String> {
+    let data = read_app_data(&get_data_file_path(&state))?;
+    let session = data
+        .sessions
+        .into_iter()
+        .find(|session| session.id == session_id)
+        .ok_or_else(|| format!("No saved session with id {}", session_id))?;
+    Ok(downsample_lttb(&session.performance_history, max_points))
+}
+
+/// Saved sessions carrying `tag` (case-insensitive), oldest first
+#[tauri::command]
+fn find_sessions_by_tag(state: State<AppState>, tag: String) -> Result<Vec<SavedSession>, String> {
+    let tag = tag.trim();
+    let data = read_app_data(&get_data_file_path(&state))?;
+    Ok(data
+        .sessions
+        .into_iter()
+        .filter(|session| session.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)))
+        .collect())
+}
+
+// Open sessions - the frontend checkpoints running sessions with is_current set, so one still
+// marked current at startup was cut off by a crash, update or reboot
+static RESUME_SESSIONS: AtomicBool = AtomicBool::new(false);
+
+/// Keep sessions left open by the previous run going if their app is still running (persisted)
+/// Off: they are closed at their last snapshot
+#[tauri::command]
+fn set_resume_sessions(state: State<AppState>, enabled: bool) -> Result<(), String> {
+    RESUME_SESSIONS.store(enabled, Ordering::SeqCst);
+    update_app_data(&state, |data| data.resume_sessions = enabled)
+}
+
+#[tauri::command]
+fn get_resume_sessions() -> bool {
+    RESUME_SESSIONS.load(Ordering::SeqCst)
+}
+
+/// Close or resume sessions still marked current, before the frontend loads them
+fn reconcile_open_sessions(state: &AppState) {
+    let _lock = state.data_file_lock.lock().unwrap();
+    let data_file = get_data_file_path(state);
+    let mut data = read_app_data_for_update(state, &data_file);
+    if !data.sessions.iter().any(|session| session.is_current) {
+        return;
+    }
+
+    // (name, lowercase exe path) of everything running now, only needed when resuming
+    let running: Vec<(String, Option<String>)> = if RESUME_SESSIONS.load(Ordering::SeqCst) {
+        let mut system = state.system.lock().unwrap();
+        // Exe only - refreshing CPU here would skew the first poll's usage delta
+        system.refresh_processes_specifics(
+            sysinfo::ProcessesToUpdate::All,
+            true,
+            sysinfo::ProcessRefreshKind::new().with_exe(sysinfo::UpdateKind::OnlyIfNotSet),
+        );
+        system
+            .processes()
+            .values()
+            .map(|process| (canonical_name(process), process.exe().map(|p| p.to_string_lossy().to_lowercase())))
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    let (mut resumed, mut closed) = (0, 0);
+    for session in data.sessions.iter_mut().filter(|session| session.is_current) {
+        // Match by the whitelisted exe path where known, by name otherwise
+        let exe_path = data
+            .whitelist
+            .iter()
+            .find(|entry| entry.name.eq_ignore_ascii_case(&session.app_name))
+            .and_then(|entry| entry.exe_path.as_deref())
+            .map(str::to_lowercase);
+        let still_running = running.iter().any(|(name, exe)| match &exe_path {
+            Some(path) => exe.as_deref() == Some(path.as_str()),
+            None => same_process_name(name, &session.app_name),
+        });
+
+        if still_running {
+            resumed += 1;
+            continue;
+        }
+        let end_time = session
+            .performance_history
+            .last()
+            .map(|snapshot| snapshot.timestamp.clone())
+            .unwrap_or_else(|| session.start_time.clone());
+        if let (Ok(start), Ok(end)) = (
+            chrono::DateTime::parse_from_rfc3339(&session.start_time),
+            chrono::DateTime::parse_from_rfc3339(&end_time),
+        ) {
+            session.duration_seconds = (end - start).num_seconds().max(0);
+        }
+        session.end_time = Some(end_time);
+        session.is_current = false;
+        closed += 1;
+    }
+
+    log_info!("Open sessions from the last run: {} closed, {} resumed", closed, resumed);
+    if closed > 0 {
+        let _ = write_app_data(&data_file, &data)
+            .inspect_err(|e| log_error!("Failed to write {}: {}", data_file.display(), e));
+    }
+}
+
+/// Set the minimum level written to the log file: error, warn, info or debug (persisted)
+#[tauri::command]
+fn set_log_level(state: State<AppState>, level: String) -> Result<(), String> {
+    let parsed = logger::Level::parse(&level).ok_or_else(|| format!("Unknown log level: {}", level))?;
+    logger::set_level(parsed);
+    update_app_data(&state, |data| data.log_level = Some(level.to_lowercase()))
+}
+
+/// Write a crash report when a panic happens (persisted, off by default)
+#[tauri::command]
+fn set_crash_reporting(state: State<AppState>, enabled: bool) -> Result<(), String> {
+    CRASH_REPORTS.store(enabled, Ordering::SeqCst);
+    update_app_data(&state, |data| data.crash_reports = enabled)
+}
+
+#[tauri::command]
+fn get_crash_reporting() -> bool {
+    CRASH_REPORTS.load(Ordering::SeqCst)
+}
+
+/// Contents of the most recent crash reports, newest first
+#[tauri::command]
+fn get_recent_crashes() -> Vec<String> {
+    let Some(dir) = crash_dir() else { return Vec::new() };
+    crash_log_files(&dir)
+        .iter()
+        .rev()
+        .take(MAX_RECENT_CRASHES)
+        .filter_map(|path| fs::read_to_string(path).ok())
+        .collect()
+}
+
+/// Full path of the current log file, for attaching to bug reports
+#[tauri::command]
+fn get_log_path() -> Result<String, String> {
+    logger::path()
+        .map(|path| path.to_string_lossy().to_string())
+        .ok_or_else(|| "Logging is not initialized".to_string())
+}
+
+// UI preferences - opaque JSON owned by the frontend (columns, sort order, visible metrics),
+// kept out of AppData so the backend never needs to know its schema
+const UI_PREFERENCES_FILE_NAME: &str = "ui_preferences.json";
+
+#[tauri::command]
+fn save_ui_preferences(state: State<AppState>, json: String) -> Result<(), String> {
+    // Reject garbage so a bad write can't break the next load
+    serde_json::from_str::<serde_json::Value>(&json).map_err(|e| format!("Invalid JSON: {}", e))?;
+    fs::create_dir_all(&state.data_path).map_err(|e| e.to_string())?;
+    fs::write(state.data_path.join(UI_PREFERENCES_FILE_NAME), json).map_err(|e| e.to_string())
+}
+
+/// Saved UI preferences, "{}" when nothing was saved yet
+#[tauri::command]
+fn load_ui_preferences(state: State<AppState>) -> Result<String, String> {
+    let path = state.data_path.join(UI_PREFERENCES_FILE_NAME);
+    if !path.exists() {
+        return Ok("{}".to_string());
+    }
+    fs::read_to_string(path).map_err(|e| e.to_string())
+}
+
+/// Clamp `value` into `min..=max`, NaN/infinite become `min`. Returns whether it changed
+fn clamp_metric(value: &mut f64, min: f64, max: f64) -> bool {
+    let clamped = if value.is_finite() { value.clamp(min, max) } else { min };
+    let changed = clamped != *value;
+    *value = clamped;
+    changed
+}
+
+/// Repair hand-edited or corrupted session data, returns a description of each kind of fix
+fn sanitize_app_data(data: &mut AppData) -> Vec<String> {
+    let mut fixes = Vec::new();
+
+    let mut clamped = 0;
+    let mut durations = 0;
+    for session in data.sessions.iter_mut() {
+        for percent in [
+            &mut session.avg_cpu_percent,
+            &mut session.avg_gpu_percent,
+            &mut session.peak_cpu_percent,
+            &mut session.peak_gpu_percent,
+        ] {
+            clamped += clamp_metric(percent, 0.0, 100.0) as usize;
+        }
+        for amount in [
+            &mut session.avg_memory_mb,
+            &mut session.peak_memory_mb,
+            &mut session.peak_working_set_mb,
+            &mut session.peak_commit_mb,
+            &mut session.cpu_seconds,
+        ] {
+            clamped += clamp_metric(amount, 0.0, f64::MAX) as usize;
+        }
+        for snapshot in session.performance_history.iter_mut() {
+            for percent in [
+                &mut snapshot.cpu_percent,
+                &mut snapshot.memory_percent,
+                &mut snapshot.gpu_percent,
+                &mut snapshot.user_activity_percent,
+            ] {
+                clamped += clamp_metric(percent, 0.0, 100.0) as usize;
+            }
+            clamped += clamp_metric(&mut snapshot.memory_mb, 0.0, f64::MAX) as usize;
+        }
+        if session.duration_seconds < 0 {
+            session.duration_seconds = 0;
+            durations += 1;
+        }
+    }
+    if clamped > 0 {
+        fixes.push(format!("clamped {} out-of-range or non-numeric values", clamped));
+    }
+    if durations > 0 {
+        fixes.push(format!("reset {} negative durations", durations));
+    }
+
+    // Ids are also Firestore document keys, so they only ever move forward
+    let after_max_id = data.sessions.iter().map(|session| session.id + 1).max().unwrap_or(0);
+    let mut next_id = data.next_session_id.max(after_max_id);
+    let mut seen = std::collections::HashSet::new();
+    let mut reassigned = 0;
+    for session in data.sessions.iter_mut() {
+        if !seen.insert(session.id) {
+            session.id = next_id;
+            seen.insert(next_id);
+            next_id += 1;
+            reassigned += 1;
+        }
+    }
+    if reassigned > 0 {
+        fixes.push(format!("gave {} sessions with duplicate ids new ids", reassigned));
+    }
+    if next_id != data.next_session_id {
+        fixes.push(format!("moved next_session_id from {} to {}", data.next_session_id, next_id));
+        data.next_session_id = next_id;
+    }
+
+    fixes
+}
+
+/// The saved data, repaired and written back first if it held invalid values.
+/// Never fails on an unreadable file, see get_data_recovery
+#[tauri::command]
+fn load_app_data(state: State<AppState>) -> Result<AppData, String> {
+    let _lock = state.data_file_lock.lock().unwrap();
+    let data_file = get_data_file_path(&state);
+    // Setup normally recovered already, this covers a file corrupted while running
+    let mut data = read_app_data_for_update(&state, &data_file);
+    let fixes = sanitize_app_data(&mut data);
+    if !fixes.is_empty() {
+        log_warn!("Repaired {}: {}", data_file.display(), fixes.join(", "));
+        write_app_data(&data_file, &data)?;
+    }
+    Ok(data)
+}
+
+/// How the data file was recovered this launch, None when it loaded normally
+#[tauri::command]
+fn get_data_recovery(state: State<AppState>) -> Option<DataRecovery> {
+    state.data_recovery.lock().unwrap().clone()
+}
+
+#[tauri::command]
+fn get_category_rules(state: State<AppState>) -> Vec<(String, String)> {
+    state.category_rules.lock().unwrap().clone()
+}
+
+/// Replace the user category rules (pattern, category) and persist them
+#[tauri::command]
+fn set_category_rules(state: State<AppState>, rules: Vec<(String, String)>) -> Result<(), String> {
+    *state.category_rules.lock().unwrap() = rules.clone();
+    update_app_data(&state, |data| data.category_rules = rules)
+}
+
+// Protected processes - destructive commands (suspend, throttle, kill...) refuse
+// to touch these so the app can't take down the machine
+const PROTECTED_PROCESSES: &[&str] = &[
+    "system", "registry", "secure system", "memory compression", "smss.exe", "csrss.exe",
+    "wininit.exe", "winlogon.exe", "services.exe", "lsass.exe", "lsaiso.exe", "svchost.exe",
+    "dwm.exe", "explorer.exe", "fontdrvhost.exe", "audiodg.exe",
+];
+
+/// Built-in and user-protected names, PID 0/4 (Idle/System) and ourselves
+fn is_protected_process(pid: u32, name: &str, user_protected: &[String]) -> bool {
+    pid == 0
+        || pid == 4
+        || pid == std::process::id()
+        || PROTECTED_PROCESSES.iter().any(|p| same_process_name(p, name))
+        || user_protected.iter().any(|p| same_process_name(p, name))
+}
+
+/// Shared guard for every mutating process command
+/// Unknown PIDs pass - the command itself reports "process not found"
+fn ensure_not_protected(state: &AppState, pid: u32) -> Result<(), String> {
+    let name = {
+        let mut system = state.system.lock().unwrap();
+        let pid_obj = Pid::from_u32(pid);
+        system.refresh_processes(sysinfo::ProcessesToUpdate::Some(&[pid_obj]), true);
+        system.process(pid_obj).map(canonical_name)
+    };
+
+    let user_protected = state.protected_processes.lock().unwrap();
+    if is_protected_process(pid, name.as_deref().unwrap_or(""), &user_protected) {
+        return Err("protected process".to_string());
+    }
+    Ok(())
+}
+
+/// User additions to the built-in protected list (exe names)
+#[tauri::command]
+fn get_protected_processes(state: State<AppState>) -> Vec<String> {
+    state.protected_processes.lock().unwrap().clone()
+}
+
+#[tauri::command]
+fn set_protected_processes(state: State<AppState>, names: Vec<String>) -> Result<(), String> {
+    *state.protected_processes.lock().unwrap() = names.clone();
+    update_app_data(&state, |data| data.protected_processes = names)
+}
+
+// Soft CPU cap - emulates a per-process CPU limit by suspending the process
+// for part of every period (duty cycle), like the Unix cpulimit tool
+const CPU_CAP_PERIOD_MS: u64 = 100;
+
+#[derive(Clone, Copy)]
+struct CpuCap {
+    max_percent: f32,
+    // Fraction of each period the process spends suspended (0.0 - 0.95)
+    throttle: f32,
+    // The throttle thread has the process suspended right now, only then is it ours to resume
+    suspended: bool,
+    // Identifies the throttle thread, one left over from a quick clear/set exits
+    generation: u64,
+}
+
+static CPU_CAP_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Call `f` with the ID of every thread owned by the process
+#[cfg(windows)]
+fn for_each_thread(pid: u32, mut f: impl FnMut(u32)) -> Result<(), String> {
+    use windows::Win32::System::Diagnostics::ToolHelp::{
+        CreateToolhelp32Snapshot, Thread32First, Thread32Next, TH32CS_SNAPTHREAD, THREADENTRY32,
+    };
+
+    unsafe {
+        let snapshot = CreateToolhelp32Snapshot(TH32CS_SNAPTHREAD, 0).map_err(|e| e.to_string())?;
+
+        let mut entry = THREADENTRY32 {
+            dwSize: std::mem::size_of::<THREADENTRY32>() as u32,
+            ..Default::default()
+        };
+
+        if Thread32First(snapshot, &mut entry).is_ok() {
+            loop {
+                if entry.th32OwnerProcessID == pid {
+                    f(entry.th32ThreadID);
+                }
+                if Thread32Next(snapshot, &mut entry).is_err() {
+                    break;
+                }
+            }
+        }
+
+        let _ = CloseHandle(snapshot);
+    }
+    Ok(())
+}
+
+/// Suspend or resume every thread of a process
+#[cfg(windows)]
+fn set_threads_suspended(pid: u32, suspend: bool) -> Result<(), String> {
+    use windows::Win32::System::Threading::{OpenThread, ResumeThread, SuspendThread, THREAD_SUSPEND_RESUME};
+
+    for_each_thread(pid, |tid| unsafe {
+        if let Ok(thread) = OpenThread(THREAD_SUSPEND_RESUME, false, tid) {
+            if suspend {
+                let _ = SuspendThread(thread);
+            } else {
+                let _ = ResumeThread(thread);
+            }
+            let _ = CloseHandle(thread);
+        }
+    })
+}
+
+#[cfg(not(windows))]
+fn set_threads_suspended(_pid: u32, _suspend: bool) -> Result<(), String> {
+    Err("Not supported on this platform".to_string())
+}
+
+/// Total kernel + user CPU time of a process in 100ns units
+#[cfg(windows)]
+fn get_process_cpu_time(handle: windows::Win32::Foundation::HANDLE) -> Option<u64> {
+    use windows::Win32::Foundation::FILETIME;
+    use windows::Win32::System::Threading::GetProcessTimes;
+
+    let mut creation = FILETIME::default();
+    let mut exit = FILETIME::default();
+    let mut kernel = FILETIME::default();
+    let mut user = FILETIME::default();
+
+    unsafe { GetProcessTimes(handle, &mut creation, &mut exit, &mut kernel, &mut user) }.ok()?;
+
+    let to_u64 = |ft: FILETIME| ((ft.dwHighDateTime as u64) << 32) | ft.dwLowDateTime as u64;
+    Some(to_u64(kernel) + to_u64(user))
+}
+
+// Hybrid CPU attribution - the CPU time each thread used over a short window is credited
+// to the efficiency class of its ideal processor. Approximate: the scheduler keeps threads
+// on or near their ideal core but is free to run them anywhere
+const CORE_ATTRIBUTION_WINDOW_MS: u64 = 1000;
+
+#[derive(Serialize)]
+struct CoreAttribution {
+    // CPU has more than one efficiency class (P-cores + E-cores)
+    hybrid: bool,
+    pcore_percent: f32,
+    ecore_percent: f32,
+}
+
+/// (processor group, logical index) -> efficiency class, higher = faster core
+#[cfg(windows)]
+fn cpu_efficiency_classes() -> HashMap<(u16, u8), u8> {
+    use windows::Win32::Foundation::HANDLE;
+    use windows::Win32::System::SystemInformation::{
+        CpuSetInformation, GetSystemCpuSetInformation, SYSTEM_CPU_SET_INFORMATION,
+    };
+
+    let mut classes = HashMap::new();
+    unsafe {
+        let mut length = 0u32;
+        let _ = GetSystemCpuSetInformation(None, 0, &mut length, HANDLE::default(), 0);
+        if length == 0 {
+            return classes;
+        }
+
+        // u64 storage keeps the variable-size records aligned
+        let mut buffer: Vec<u64> = vec![0; (length as usize).div_ceil(8)];
+        let base = buffer.as_mut_ptr() as *mut u8;
+        if !GetSystemCpuSetInformation(
+            Some(base as *mut SYSTEM_CPU_SET_INFORMATION),
+            length,
+            &mut length,
+            HANDLE::default(),
+            0,
+        ).as_bool() {
+            return classes;
+        }
+
+        let mut offset = 0usize;
+        while offset < length as usize {
+            let info = &*(base.add(offset) as *const SYSTEM_CPU_SET_INFORMATION);
+            if info.Size == 0 {
+                break;
+            }
+            if info.Type == CpuSetInformation {
+                let cpu_set = &info.Anonymous.CpuSet;
+                classes.insert((cpu_set.Group, cpu_set.LogicalProcessorIndex), cpu_set.EfficiencyClass);
+            }
+            offset += info.Size as usize;
+        }
+    }
+    classes
+}
+
+/// Thread ID -> (CPU time in 100ns units, efficiency class of its ideal processor)
+#[cfg(windows)]
+fn thread_core_times(pid: u32, classes: &HashMap<(u16, u8), u8>) -> Result<HashMap<u32, (u64, Option<u8>)>, String> {
+    use windows::Win32::Foundation::FILETIME;
+    use windows::Win32::System::Kernel::PROCESSOR_NUMBER;
+    use windows::Win32::System::Threading::{GetThreadIdealProcessorEx, GetThreadTimes, OpenThread, THREAD_QUERY_INFORMATION};
+
+    let mut times = HashMap::new();
+    for_each_thread(pid, |tid| unsafe {
+        let Ok(thread) = OpenThread(THREAD_QUERY_INFORMATION, false, tid) else { return };
+
+        let mut creation = FILETIME::default();
+        let mut exit = FILETIME::default();
+        let mut kernel = FILETIME::default();
+        let mut user = FILETIME::default();
+        let mut ideal = PROCESSOR_NUMBER::default();
+        if GetThreadTimes(thread, &mut creation, &mut exit, &mut kernel, &mut user).is_ok()
+            && GetThreadIdealProcessorEx(thread, &mut ideal).is_ok()
+        {
+            let to_u64 = |ft: FILETIME| ((ft.dwHighDateTime as u64) << 32) | ft.dwLowDateTime as u64;
+            times.insert(tid, (to_u64(kernel) + to_u64(user), classes.get(&(ideal.Group, ideal.Number)).copied()));
+        }
+        let _ = CloseHandle(thread);
+    })?;
+    Ok(times)
+}
+
+/// Approximate share of a process's recent CPU time on performance vs efficiency cores,
+/// from per-thread deltas over CORE_ATTRIBUTION_WINDOW_MS
+#[cfg(windows)]
+fn read_core_attribution(pid: u32) -> Result<CoreAttribution, String> {
+    let classes = cpu_efficiency_classes();
+    let Some(&pcore_class) = classes.values().max() else {
+        return Err("CPU set information is unavailable".to_string());
+    };
+    let hybrid = classes.values().any(|&class| class != pcore_class);
+
+    let before = thread_core_times(pid, &classes)?;
+    std::thread::sleep(std::time::Duration::from_millis(CORE_ATTRIBUTION_WINDOW_MS));
+    let after = thread_core_times(pid, &classes)?;
+
+    let mut pcore_time = 0u64;
+    let mut ecore_time = 0u64;
+    for (tid, (time, class)) in after {
+        // Threads started during the window count from zero
+        let delta = time.saturating_sub(before.get(&tid).map_or(0, |(time, _)| *time));
+        match class {
+            Some(class) if class != pcore_class => ecore_time += delta,
+            _ => pcore_time += delta,
+        }
+    }
+
+    let total = (pcore_time + ecore_time) as f64;
+    let share = |time: u64| if total > 0.0 { (time as f64 / total * 100.0) as f32 } else { 0.0 };
+    Ok(CoreAttribution {
+        hybrid,
+        pcore_percent: round_metric_f32(share(pcore_time)),
+        ecore_percent: round_metric_f32(share(ecore_time)),
+    })
+}
+
+#[cfg(not(windows))]
+fn read_core_attribution(_pid: u32) -> Result<CoreAttribution, String> {
+    Err("Not supported on this platform".to_string())
+}
+
+/// Approximate share of a process's CPU time on performance vs efficiency cores over the
+/// next second (credited by each thread's ideal processor). Runs off the main thread
+#[tauri::command]
+async fn get_process_core_attribution(pid: u32) -> Result<CoreAttribution, String> {
+    tauri::async_runtime::spawn_blocking(move || read_core_attribution(pid))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+/// Throttle loop for one capped process, runs until the cap is cleared or the process exits
+#[cfg(windows)]
+fn run_cpu_cap(app: tauri::AppHandle, pid: u32, generation: u64) {
+    use windows::Win32::System::Threading::PROCESS_QUERY_LIMITED_INFORMATION;
+    use std::time::Duration;
+
+    let state = app.state::<AppState>();
+    let handle = match unsafe { OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid) } {
+        Ok(handle) => handle,
+        Err(_) => {
+            state.cpu_caps.lock().unwrap().remove(&pid);
+            return;
+        }
+    };
+
+    let cores = std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1) as f64;
+    let period = Duration::from_millis(CPU_CAP_PERIOD_MS);
+    let mut last_cpu = get_process_cpu_time(handle).unwrap_or(0);
+    let mut last_wall = Instant::now();
+
+    loop {
+        let alive = !process_exited(handle);
+
+        // Suspend while holding the lock so release_cpu_caps() can't miss us
+        let suspend_for = {
+            let mut caps = state.cpu_caps.lock().unwrap();
+            if !alive {
+                caps.remove(&pid);
+            }
+            let Some(cap) = caps.get_mut(&pid).filter(|cap| cap.generation == generation) else { break };
+
+            let cpu = get_process_cpu_time(handle).unwrap_or(last_cpu);
+            let now = Instant::now();
+            let wall_secs = (now - last_wall).as_secs_f64();
+            // CPU time is in 100ns units, normalize to 0-100% of the whole system
+            let usage = if wall_secs > 0.0 {
+                cpu.saturating_sub(last_cpu) as f64 / 10_000_000.0 / wall_secs / cores * 100.0
+            } else {
+                0.0
+            };
+            last_cpu = cpu;
+            last_wall = now;
+
+            // Integral controller: throttle harder while above the cap, ease off below it
+            let error = (usage as f32 - cap.max_percent) / 100.0;
+            cap.throttle = (cap.throttle + error * 0.5).clamp(0.0, 0.95);
+
+            let off = period.mul_f32(cap.throttle);
+            if !off.is_zero() {
+                cap.suspended = set_threads_suspended(pid, true).is_ok();
+            }
+            off
+        };
+
+        if suspend_for.is_zero() {
+            std::thread::sleep(period);
+            continue;
+        }
+
+        std::thread::sleep(suspend_for);
+        {
+            // Cleared or released meanwhile means the process was already resumed for us
+            let mut caps = state.cpu_caps.lock().unwrap();
+            if let Some(cap) = caps.get_mut(&pid).filter(|cap| cap.generation == generation && cap.suspended) {
+                let _ = set_threads_suspended(pid, false);
+                cap.suspended = false;
+            }
+        }
+        std::thread::sleep(period - suspend_for);
+    }
+
+    unsafe {
+        let _ = CloseHandle(handle);
+    }
+}
+
+/// Cap a process's CPU usage (0-100% of the whole system)
+#[tauri::command]
+#[cfg(windows)]
+fn set_process_cpu_cap(app: tauri::AppHandle, state: State<AppState>, pid: u32, max_percent: f32) -> Result<(), String> {
+    if !(max_percent > 0.0 && max_percent < 100.0) {
+        return Err("max_percent must be between 0 and 100".to_string());
+    }
+    ensure_not_protected(&state, pid)?;
+
+    // Resuming every period would undo a suspend held by another feature. Locked in the
+    // same order as mark_suspended_processes, then cpu_caps like suspend_process does
+    let manual = state.manually_suspended.lock().unwrap();
+    let rules = state.rule_suspended.lock().unwrap();
+    let game_mode = state.game_mode_suspended.lock().unwrap();
+    if manual.contains_key(&pid)
+        || rules.contains_key(&pid)
+        || game_mode.as_ref().is_some_and(|pids| pids.contains(&pid))
+    {
+        return Err("Process is suspended, resume it first".to_string());
+    }
+
+    let mut caps = state.cpu_caps.lock().unwrap();
+    if let Some(cap) = caps.get_mut(&pid) {
+        // Throttle thread already running, just move the target
+        cap.max_percent = max_percent;
+        return Ok(());
+    }
+    let generation = CPU_CAP_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    caps.insert(pid, CpuCap { max_percent, throttle: 0.0, suspended: false, generation });
+
+    std::thread::spawn(move || run_cpu_cap(app, pid, generation));
+    Ok(())
+}
+
+#[tauri::command]
+#[cfg(not(windows))]
+fn set_process_cpu_cap(_pid: u32, _max_percent: f32) -> Result<(), String> {
+    Err("Not supported on this platform".to_string())
+}
+
+/// Remove a CPU cap, resuming the process if the cap has it suspended right now
+#[tauri::command]
+fn clear_process_cpu_cap(state: State<AppState>, pid: u32) {
+    let mut caps = state.cpu_caps.lock().unwrap();
+    if caps.remove(&pid).is_some_and(|cap| cap.suspended) {
+        let _ = set_threads_suspended(pid, false);
+    }
+}
+
+#[tauri::command]
+fn get_process_cpu_caps(state: State<AppState>) -> HashMap<u32, f32> {
+    state.cpu_caps.lock().unwrap()
+        .iter()
+        .map(|(pid, cap)| (*pid, cap.max_percent))
+        .collect()
+}
+
+// Cap verification - measures what a process actually used, e.g. "capped at 20%, measured 21.3%"
+const MEASURE_CPU_SAMPLE_MS: u64 = 100;
+const MAX_MEASURE_CPU_SECS: u64 = 60;
+
+/// Average CPU use of `pid` (0-100% of the whole system) over `duration_secs`,
+/// cut short if the process exits
+#[cfg(windows)]
+fn measure_cpu_usage(pid: u32, duration_secs: u64) -> Result<f32, String> {
+    use std::time::Duration;
+    use windows::Win32::System::Threading::PROCESS_QUERY_LIMITED_INFORMATION;
+
+    let handle = unsafe { OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid) }
+        .map_err(|e| format!("Failed to open process {}: {}", pid, e))?;
+    let cores = std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1) as f64;
+
+    let start_cpu = get_process_cpu_time(handle);
+    let start = Instant::now();
+    let mut last_cpu = start_cpu;
+    let mut last_at = start;
+    // Sample throughout the window so a process that exits midway still reports the time it ran
+    while start.elapsed() < Duration::from_secs(duration_secs) {
+        std::thread::sleep(Duration::from_millis(MEASURE_CPU_SAMPLE_MS));
+        match get_process_cpu_time(handle) {
+            Some(cpu) if !process_exited(handle) => {
+                last_cpu = Some(cpu);
+                last_at = Instant::now();
+            }
+            _ => break,
+        }
+    }
+    unsafe {
+        let _ = CloseHandle(handle);
+    }
+
+    let (Some(start_cpu), Some(end_cpu)) = (start_cpu, last_cpu) else {
+        return Err(format!("CPU time of process {} is unavailable", pid));
+    };
+    let wall_secs = (last_at - start).as_secs_f64();
+    if wall_secs <= 0.0 {
+        return Err(format!("Process {} exited before it could be measured", pid));
+    }
+    // CPU time is in 100ns units
+    let usage = end_cpu.saturating_sub(start_cpu) as f64 / 10_000_000.0 / wall_secs / cores * 100.0;
+    Ok(round_metric_f32(usage as f32))
+}
+
+/// The process behind `handle` has exited (or can't be queried any more)
+#[cfg(windows)]
+fn process_exited(handle: windows::Win32::Foundation::HANDLE) -> bool {
+    use windows::Win32::System::Threading::GetExitCodeProcess;
+
+    // GetExitCodeProcess reports STILL_ACTIVE while the process is running
+    const STILL_ACTIVE: u32 = 259;
+    let mut exit_code = 0u32;
+    !(unsafe { GetExitCodeProcess(handle, &mut exit_code) }.is_ok() && exit_code == STILL_ACTIVE)
+}
+
+#[cfg(not(windows))]
+fn measure_cpu_usage(_pid: u32, _duration_secs: u64) -> Result<f32, String> {
+    Err("Not supported on this platform".to_string())
+}
+
+/// Measure a process's average CPU use (0-100% of the whole system) over `duration_secs`,
+/// e.g. to check a CPU cap is holding. Runs off the main thread
+#[tauri::command]
+async fn measure_effective_cpu(pid: u32, duration_secs: u64) -> Result<f32, String> {
+    if duration_secs == 0 || duration_secs > MAX_MEASURE_CPU_SECS {
+        return Err(format!("duration_secs must be between 1 and {}", MAX_MEASURE_CPU_SECS));
+    }
+    tauri::async_runtime::spawn_blocking(move || measure_cpu_usage(pid, duration_secs))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+/// Drop every CPU cap and resume the processes a cap has suspended right now,
+/// suspends held by anything else are left alone
+fn release_cpu_caps(state: &AppState) {
+    let mut caps = state.cpu_caps.lock().unwrap();
+    for (pid, cap) in caps.iter() {
+        if cap.suspended {
+            let _ = set_threads_suspended(*pid, false);
+        }
+    }
+    caps.clear();
+}
+
+// Self benchmark - times the hot path of a process poll, to show what the monitoring costs
+const SELF_BENCHMARK_ITERATIONS: u32 = 5;
+
+#[derive(Serialize)]
+struct SelfBenchmark {
+    iterations: u32,
+    // sysinfo process refresh
+    avg_refresh_ms: f64,
+    // Building ProcessInfo for every process
+    avg_collect_ms: f64,
+    // NVML per-process query
+    avg_gpu_query_ms: f64,
+    // Our own peak working set since start, None where unavailable
+    peak_memory_mb: Option<f64>,
+}
+
+#[cfg(windows)]
+fn own_peak_working_set() -> Option<u64> {
+    use windows::Win32::System::Threading::GetCurrentProcess;
+
+    let mut counters = PROCESS_MEMORY_COUNTERS::default();
+    let size = std::mem::size_of::<PROCESS_MEMORY_COUNTERS>() as u32;
+    unsafe { GetProcessMemoryInfo(GetCurrentProcess(), &mut counters, size) }.ok()?;
+    Some(counters.PeakWorkingSetSize as u64)
+}
+
+#[cfg(not(windows))]
+fn own_peak_working_set() -> Option<u64> {
+    None
+}
+
+/// Time the stages of a process poll over a few iterations
+#[tauri::command]
+fn run_self_benchmark(state: State<AppState>) -> SelfBenchmark {
+    let (mut refresh, mut collect, mut gpu_query) = (0.0, 0.0, 0.0);
+    let category_rules = state.category_rules.lock().unwrap().clone();
+    let memory_metric = *state.memory_metric.lock().unwrap();
+    let elapsed_ms = |started: Instant| started.elapsed().as_secs_f64() * 1000.0;
+    // Own sample cursor so the benchmark doesn't take samples from the main poll
+    let mut gpu_cursor = HashMap::new();
+
+    for _ in 0..SELF_BENCHMARK_ITERATIONS {
+        let started = Instant::now();
+        let gpu = query_gpu_usage(&state, &mut gpu_cursor, false);
+        gpu_query += elapsed_ms(started);
+        let gpu_engines = read_gpu_engines(&state);
+
+        let mut system = state.system.lock().unwrap();
+        let started = Instant::now();
+        system.refresh_processes(sysinfo::ProcessesToUpdate::All, true);
+        refresh += elapsed_ms(started);
+
+        let cpu_cores = system.cpus().len().max(1) as f32;
+        let ctx = CollectionContext {
+            total_memory: system.total_memory(),
+            cpu_divisor: cpu_cores,
+            gpu: &gpu,
+            category_rules: &category_rules,
+            version_cache: &state.version_cache,
+            memory_metric,
+            gpu_engines: &gpu_engines,
+            detail: DetailLevel::Full,
+        };
+        let started = Instant::now();
+        let processes: Vec<ProcessInfo> = system
+            .processes()
+            .iter()
+            .map(|(pid, process)| build_process_info(pid.as_u32(), process, &ctx))
+            .collect();
+        collect += elapsed_ms(started);
+        drop(processes);
+    }
+
+    let iterations = SELF_BENCHMARK_ITERATIONS as f64;
+    SelfBenchmark {
+        iterations: SELF_BENCHMARK_ITERATIONS,
+        avg_refresh_ms: round_metric(refresh / iterations),
+        avg_collect_ms: round_metric(collect / iterations),
+        avg_gpu_query_ms: round_metric(gpu_query / iterations),
+        peak_memory_mb: own_peak_working_set().map(|bytes| round_metric(bytes as f64 / (1024.0 * 1024.0))),
+    }
+}
+
+#[derive(Serialize)]
+struct InternalMapStats {
+    name: String,
+    entries: usize,
+    approx_bytes: usize,
+}
+
+#[derive(Serialize)]
+struct InternalStats {
+    maps: Vec<InternalMapStats>,
+    last_cycle_ms: Option<f64>,
+}
+
+/// Rough heap size of a HashMap: bucket storage only, not what the values point to
+fn approx_map_bytes<K, V>(map: &HashMap<K, V>) -> usize {
+    map.capacity() * (std::mem::size_of::<K>() + std::mem::size_of::<V>())
+}
+
+/// Entry counts and rough sizes of the backend's internal state, for leak hunting
+#[tauri::command]
+fn get_internal_stats(state: State<AppState>) -> InternalStats {
+    let mut maps = Vec::new();
+
+    {
+        let system = state.system.lock().unwrap();
+        let processes = system.processes();
+        maps.push(InternalMapStats {
+            name: "processes".to_string(),
+            entries: processes.len(),
+            approx_bytes: approx_map_bytes(processes),
+        });
+    }
+
+    {
+        let rules = state.category_rules.lock().unwrap();
+        maps.push(InternalMapStats {
+            name: "category_rules".to_string(),
+            entries: rules.len(),
+            approx_bytes: rules.iter().map(|(p, c)| p.capacity() + c.capacity()).sum::<usize>()
+                + rules.capacity() * std::mem::size_of::<(String, String)>(),
+        });
+    }
+
+    {
+        let caps = state.cpu_caps.lock().unwrap();
+        maps.push(InternalMapStats {
+            name: "cpu_caps".to_string(),
+            entries: caps.len(),
+            approx_bytes: approx_map_bytes(&*caps),
+        });
+    }
+
+    {
+        let cache = state.version_cache.lock().unwrap();
+        maps.push(InternalMapStats {
+            name: "version_cache".to_string(),
+            entries: cache.len(),
+            approx_bytes: approx_map_bytes(&*cache)
+                + cache.iter().map(|(path, strings)| {
+                    path.capacity()
+                        + strings.description.as_ref().map_or(0, |d| d.capacity())
+                        + strings.company.as_ref().map_or(0, |c| c.capacity())
+                }).sum::<usize>(),
+        });
+    }
+
+    {
+        let cache = state.signature_cache.lock().unwrap();
+        maps.push(InternalMapStats {
+            name: "signature_cache".to_string(),
+            entries: cache.len(),
+            approx_bytes: approx_map_bytes(&*cache)
+                + cache.iter().map(|(path, (_, info))| {
+                    path.capacity() + info.signer.as_ref().map_or(0, |s| s.capacity())
+                }).sum::<usize>(),
+        });
+    }
+
+    {
+        let detector = state.cpu_anomaly.lock().unwrap();
+        maps.push(InternalMapStats {
+            name: "cpu_anomaly_stats".to_string(),
+            entries: detector.stats.len(),
+            approx_bytes: approx_map_bytes(&detector.stats),
+        });
+    }
+
+    {
+        let history = state.gpu_memory_history.lock().unwrap();
+        maps.push(InternalMapStats {
+            name: "gpu_memory_history".to_string(),
+            entries: history.len(),
+            approx_bytes: approx_map_bytes(&*history)
+                + history.values().map(|samples| samples.capacity() * std::mem::size_of::<(Instant, f64)>()).sum::<usize>(),
+        });
+    }
+
+    {
+        let check = state.gui_leak_check.lock().unwrap();
+        maps.push(InternalMapStats {
+            name: "gui_leak_reported".to_string(),
+            entries: check.reported.len(),
+            approx_bytes: approx_map_bytes(&check.reported),
+        });
+    }
+
+    {
+        let history = state.memory_history.lock().unwrap();
+        maps.push(InternalMapStats {
+            name: "memory_history".to_string(),
+            entries: history.len(),
+            approx_bytes: approx_map_bytes(&*history)
+                + history.values().map(|entry| {
+                    entry.name.capacity() + entry.samples.capacity() * std::mem::size_of::<(Instant, f64)>()
+                }).sum::<usize>(),
+        });
+    }
+
+    {
+        let samples = state.io_ops.lock().unwrap();
+        maps.push(InternalMapStats {
+            name: "io_ops".to_string(),
+            entries: samples.len(),
+            approx_bytes: approx_map_bytes(&*samples),
+        });
+    }
+
+    {
+        let cache = state.service_names.lock().unwrap();
+        maps.push(InternalMapStats {
+            name: "service_names".to_string(),
+            entries: cache.by_pid.len(),
+            approx_bytes: approx_map_bytes(&cache.by_pid)
+                + cache.by_pid.values().flatten().map(String::capacity).sum::<usize>(),
+        });
+    }
+
+    {
+        let samples = state.context_switches.lock().unwrap();
+        maps.push(InternalMapStats {
+            name: "context_switches".to_string(),
+            entries: samples.len(),
+            approx_bytes: approx_map_bytes(&*samples),
+        });
+    }
+
+    {
+        let tracker = state.first_seen.lock().unwrap();
+        let first_seen = &tracker.paths;
+        maps.push(InternalMapStats {
+            name: "first_seen".to_string(),
+            entries: first_seen.len(),
+            approx_bytes: approx_map_bytes(first_seen)
+                + first_seen.iter().map(|(path, time)| path.capacity() + time.capacity()).sum::<usize>(),
+        });
+    }
+
+    {
+        let timestamps = state.gpu_sample_timestamps.lock().unwrap();
+        maps.push(InternalMapStats {
+            name: "gpu_sample_timestamps".to_string(),
+            entries: timestamps.len(),
+            approx_bytes: approx_map_bytes(&*timestamps) + timestamps.keys().map(|k| k.capacity()).sum::<usize>(),
+        });
+    }
+
+    {
+        let suspended = state.manually_suspended.lock().unwrap();
+        maps.push(InternalMapStats {
+            name: "manually_suspended".to_string(),
+            entries: suspended.len(),
+            approx_bytes: approx_map_bytes(&*suspended),
+        });
+    }
+
+    {
+        let cache = state.icon_cache.lock().unwrap();
+        maps.push(InternalMapStats {
+            name: "icon_cache".to_string(),
+            entries: cache.icons.len(),
+            approx_bytes: approx_map_bytes(&cache.icons)
+                + cache.order.capacity() * std::mem::size_of::<String>()
+                + cache.icons.iter().map(|(path, icon)| {
+                    // Each path is stored twice, in the map and in the eviction order
+                    2 * path.capacity() + icon.as_ref().map_or(0, |i| i.capacity())
+                }).sum::<usize>(),
+        });
+    }
+
+    {
+        let average = state.display_average.lock().unwrap();
+        maps.push(InternalMapStats {
+            name: "display_average".to_string(),
+            entries: average.samples.len(),
+            approx_bytes: approx_map_bytes(&average.samples)
+                + average.samples.values().map(|(_, samples)| samples.capacity() * std::mem::size_of::<(f32, f32)>()).sum::<usize>(),
+        });
+    }
+
+    {
+        let boosts = state.boosts.lock().unwrap();
+        maps.push(InternalMapStats {
+            name: "boosts".to_string(),
+            entries: boosts.len(),
+            approx_bytes: approx_map_bytes(&*boosts),
+        });
+    }
+
+    {
+        let suspended = state.rule_suspended.lock().unwrap();
+        maps.push(InternalMapStats {
+            name: "rule_suspended".to_string(),
+            entries: suspended.len(),
+            approx_bytes: approx_map_bytes(&*suspended),
+        });
+    }
+
+    {
+        let ema = GPU_UTILIZATION_EMA.lock().unwrap();
+        maps.push(InternalMapStats {
+            name: "gpu_utilization_ema".to_string(),
+            entries: ema.len(),
+            approx_bytes: ema.keys().map(|k| k.capacity() + std::mem::size_of::<(String, Option<f32>)>()).sum::<usize>(),
+        });
+    }
+
+    {
+        let gpu = state.gpu_usage.lock().unwrap();
+        maps.push(InternalMapStats {
+            name: "gpu_usage".to_string(),
+            entries: gpu.utilization.len(),
+            approx_bytes: approx_map_bytes(&gpu.utilization)
+                + approx_map_bytes(&gpu.memory_bytes)
+                + approx_map_bytes(&gpu.adapters)
+                + gpu.adapters.values().map(String::capacity).sum::<usize>(),
+        });
+    }
+
+    {
+        let remotes = state.remotes.lock().unwrap();
+        maps.push(InternalMapStats {
+            name: "remotes".to_string(),
+            entries: remotes.len(),
+            approx_bytes: approx_map_bytes(&*remotes)
+                + remotes.iter().map(|(id, remote)| {
+                    id.capacity() + remote.url.capacity() + remote.processes.capacity() * std::mem::size_of::<ProcessInfo>()
+                }).sum::<usize>(),
+        });
+    }
+
+    {
+        let snapshots = state.named_snapshots.lock().unwrap();
+        maps.push(InternalMapStats {
+            name: "named_snapshots".to_string(),
+            entries: snapshots.len(),
+            approx_bytes: snapshots.capacity() * std::mem::size_of::<NamedSnapshot>()
+                + snapshots.iter().map(|snapshot| {
+                    snapshot.name.capacity() + snapshot.processes.capacity() * std::mem::size_of::<SnapshotProcess>()
+                }).sum::<usize>(),
+        });
+    }
+
+    {
+        let watched = state.watched_directories.lock().unwrap();
+        maps.push(InternalMapStats {
+            name: "watched_directories".to_string(),
+            entries: watched.len(),
+            approx_bytes: watched.capacity() * std::mem::size_of::<WatchedDirectory>()
+                + watched.iter().map(|dir| dir.path.capacity()).sum::<usize>(),
+        });
+    }
+
+    InternalStats {
+        maps,
+        last_cycle_ms: *state.last_cycle_ms.lock().unwrap(),
+    }
+}
+
+/// How byte counts are formatted for display
+#[derive(Serialize, Deserialize, Clone, Copy, Default, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum MemoryUnits {
+    // 1024-based, "1.1 GiB"
+    #[default]
+    Binary,
+    // 1000-based, "1.2 GB"
+    Decimal,
+}
+
+#[tauri::command]
+fn get_memory_units(state: State<AppState>) -> MemoryUnits {
+    *state.memory_units.lock().unwrap()
+}
+
+/// Choose binary or decimal units for format_bytes (persisted)
+#[tauri::command]
+fn set_memory_units(state: State<AppState>, units: MemoryUnits) -> Result<(), String> {
+    *state.memory_units.lock().unwrap() = units;
+    update_app_data(&state, |data| data.memory_units = units)
+}
+
+/// Format a byte count with one decimal, e.g. "1.1 GiB" (binary) or "1.2 GB" (decimal)
+fn format_byte_count(bytes: u64, units: MemoryUnits) -> String {
+    let (base, labels) = match units {
+        MemoryUnits::Binary => (1024.0, ["B", "KiB", "MiB", "GiB", "TiB"]),
+        MemoryUnits::Decimal => (1000.0, ["B", "KB", "MB", "GB", "TB"]),
+    };
+
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= base && unit < labels.len() - 1 {
+        value /= base;
+        unit += 1;
+    }
+    // 999.95 KB would print as "1000.0 KB", move up a unit once rounding reaches the base
+    if unit > 0 && unit < labels.len() - 1 && (value * 10.0).round() / 10.0 >= base {
+        value /= base;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{} {}", bytes, labels[0])
+    } else {
+        format!("{:.1} {}", value, labels[unit])
+    }
+}
+
+/// Format a byte count in the memory-units setting
+#[tauri::command]
+fn format_bytes(state: State<AppState>, bytes: u64) -> String {
+    format_byte_count(bytes, *state.memory_units.lock().unwrap())
+}
+
+/// Format a duration like the dashboard does: "45s", "12m", "3h 12m"
+#[tauri::command]
+fn format_duration(secs: u64) -> String {
+    if secs < 60 {
+        return format!("{}s", secs);
+    }
+
+    let hours = secs / 3600;
+    let minutes = (secs % 3600) / 60;
+
+    if hours > 0 {
+        format!("{}h {}m", hours, minutes)
+    } else {
+        format!("{}m", minutes)
+    }
+}
+
+// Self CPU budget - get_system_stats measures what each poll cycle cost us and stretches
+// the frontend's poll interval while that stays over budget, easing back once there's headroom
+const DEFAULT_POLL_INTERVAL_MS: u64 = 2000;
+const MAX_POLL_INTERVAL_MS: u64 = 30_000;
+
+struct SelfCpuBudget {
+    // Percent of total machine CPU, None = no budget
+    max_percent: Option<f32>,
+    interval_ms: u64,
+    // When and at what CPU time (100ns units) the previous cycle was measured
+    last_sample: Option<(Instant, u64)>,
+}
+
+#[cfg(windows)]
+fn own_cpu_time() -> Option<u64> {
+    use windows::Win32::System::Threading::GetCurrentProcess;
+    get_process_cpu_time(unsafe { GetCurrentProcess() })
+}
+
+#[cfg(not(windows))]
+fn own_cpu_time() -> Option<u64> {
+    None
+}
+
+/// Compare our CPU use since the previous cycle to the budget and adjust the poll interval
+fn regulate_poll_interval(state: &AppState, cpu_cores: usize) {
+    let mut budget = state.self_cpu_budget.lock().unwrap();
+    let Some(max_percent) = budget.max_percent else { return };
+    let Some(cpu_time) = own_cpu_time() else { return };
+    let now = Instant::now();
+    let Some((at, previous)) = budget.last_sample.replace((now, cpu_time)) else { return };
+
+    let wall_100ns = (now - at).as_secs_f64() * 10_000_000.0 * cpu_cores.max(1) as f64;
+    if wall_100ns <= 0.0 {
+        return;
+    }
+    let cost_percent = (cpu_time.saturating_sub(previous) as f64 / wall_100ns * 100.0) as f32;
+
+    let interval = budget.interval_ms;
+    budget.interval_ms = if cost_percent > max_percent {
+        (interval * 3 / 2).min(MAX_POLL_INTERVAL_MS)
+    } else if cost_percent < max_percent / 2.0 {
+        // Well under budget, step back toward the normal rate
+        (interval * 2 / 3).max(DEFAULT_POLL_INTERVAL_MS)
+    } else {
+        interval
+    };
+    if budget.interval_ms != interval {
+        log_info!(
+            "Own CPU {:.2}% vs budget {:.2}%, poll interval {} -> {} ms",
+            cost_percent, max_percent, interval, budget.interval_ms
+        );
+    }
+}
+
+/// Cap the monitor's own CPU use in percent of the machine, None to poll at the normal rate (persisted)
+#[tauri::command]
+fn set_self_cpu_budget(state: State<AppState>, max_percent: Option<f32>) -> Result<(), String> {
+    if let Some(percent) = max_percent {
+        if !(percent > 0.0 && percent <= 100.0) {
+            return Err("max_percent must be greater than 0 and at most 100".to_string());
+        }
+    }
+    let mut budget = state.self_cpu_budget.lock().unwrap();
+    budget.max_percent = max_percent;
+    budget.last_sample = None;
+    if max_percent.is_none() {
+        budget.interval_ms = DEFAULT_POLL_INTERVAL_MS;
+    }
+    drop(budget);
+    update_app_data(&state, |data| data.self_cpu_budget = max_percent)
+}
+
+#[tauri::command]
+fn get_self_cpu_budget(state: State<AppState>) -> Option<f32> {
+    state.self_cpu_budget.lock().unwrap().max_percent
+}
+
+/// Milliseconds the frontend should wait before its next stats poll
+#[tauri::command]
+fn get_effective_poll_interval(state: State<AppState>) -> u64 {
+    state.self_cpu_budget.lock().unwrap().interval_ms
+}
+
+// Backend monitoring - polls on a thread and emits processes-updated / stats-updated,
+// so the frontend can listen instead of invoking get_processes / get_system_stats on a timer
+const MIN_MONITORING_INTERVAL_MS: u64 = 250;
+
+#[derive(Default)]
+struct MonitoringLoop {
+    // None = stopped, the running thread exits on its next wake-up
+    interval_ms: Option<u64>,
+    // Bumped on every start so a thread left over from a quick stop/start exits too
+    generation: u64,
+}
+
+fn run_monitoring(app: tauri::AppHandle, generation: u64) {
+    use std::time::Duration;
+
+    let state = app.state::<AppState>();
+    loop {
+        let interval_ms = {
+            let monitoring = state.monitoring.lock().unwrap();
+            match monitoring.interval_ms {
+                Some(interval_ms) if monitoring.generation == generation => interval_ms,
+                _ => break,
+            }
+        };
+        let started = Instant::now();
+
+        let processes = poll_processes(&app, &state, DetailLevel::Full);
+        let _ = app.emit("processes-updated", &processes);
+        let stats = poll_system_stats(&app, &state);
+        let _ = app.emit("stats-updated", &stats);
+
+        // The self CPU budget can stretch the interval, never shrink it
+        let effective = interval_ms.max(state.self_cpu_budget.lock().unwrap().interval_ms);
+        std::thread::sleep(Duration::from_millis(effective).saturating_sub(started.elapsed()));
+    }
+}
+
+/// Poll in the backend every `interval_ms`, emitting processes-updated and stats-updated.
+/// Calling it while running only changes the interval
+#[tauri::command]
+fn start_monitoring(app: tauri::AppHandle, state: State<AppState>, interval_ms: u64) -> Result<(), String> {
+    if interval_ms < MIN_MONITORING_INTERVAL_MS {
+        return Err(format!("interval_ms must be at least {}", MIN_MONITORING_INTERVAL_MS));
+    }
+    let mut monitoring = state.monitoring.lock().unwrap();
+    let start_thread = monitoring.interval_ms.is_none();
+    monitoring.interval_ms = Some(interval_ms);
+    if start_thread {
+        monitoring.generation += 1;
+        let generation = monitoring.generation;
+        std::thread::spawn(move || run_monitoring(app, generation));
+        log_info!("Monitoring started every {} ms", interval_ms);
+    }
+    Ok(())
+}
+
+/// Stop the monitoring loop, a no-op when it isn't running
+#[tauri::command]
+fn stop_monitoring(state: State<AppState>) {
+    if state.monitoring.lock().unwrap().interval_ms.take().is_some() {
+        log_info!("Monitoring stopped");
+    }
+}
+
+// Auto-track - follows the foreground app and reports session start/end as events,
+// the frontend records the sessions like it does for whitelisted apps
+const AUTO_TRACK_POLL_MS: u64 = 1000;
+const DEFAULT_AUTO_TRACK_GRACE_SECS: u64 = 30;
+
+struct AutoTrackLoop {
+    // None = off, the running thread exits on its next wake-up
+    grace_seconds: Option<u64>,
+    // Bumped on every start so a thread left over from a quick off/on exits too
+    generation: u64,
+}
+
+#[derive(Serialize, Clone, Copy)]
+struct AutoTrackSettings {
+    enabled: bool,
+    grace_seconds: u64,
+}
+
+#[derive(Serialize, Clone)]
+struct AutoTrackSession {
+    app_name: String,
+    exe_path: Option<String>,
+    start_time: String,
+    end_time: Option<String>,
+    duration_seconds: i64,
+}
+
+/// Name and exe path of the foreground app, ignoring our own window
+fn foreground_app(state: &AppState) -> Option<(String, Option<String>)> {
+    let pid = get_foreground_process_id().filter(|&pid| pid != std::process::id())?;
+    let pid_obj = Pid::from_u32(pid);
+
+    let mut system = state.system.lock().unwrap();
+    // Exe only - refreshing CPU here would skew the next poll's usage delta
+    system.refresh_processes_specifics(
+        sysinfo::ProcessesToUpdate::Some(&[pid_obj]),
+        true,
+        sysinfo::ProcessRefreshKind::new().with_exe(sysinfo::UpdateKind::OnlyIfNotSet),
+    );
+    system
+        .process(pid_obj)
+        .map(|p| (canonical_name(p), p.exe().map(|path| path.to_string_lossy().to_string())))
+}
+
+fn end_auto_track_session(app: &tauri::AppHandle, mut session: AutoTrackSession) {
+    let now = chrono::Local::now();
+    session.duration_seconds = chrono::DateTime::parse_from_rfc3339(&session.start_time)
+        .map(|start| (now - start.with_timezone(&chrono::Local)).num_seconds())
+        .unwrap_or(0);
+    session.end_time = Some(now.to_rfc3339());
+    let _ = app.emit("auto-track-session-ended", session);
+}
+
+/// Runs while auto-track is enabled, switching sessions once focus has been
+/// away from the tracked app for longer than the grace period
+fn run_auto_track(app: tauri::AppHandle, generation: u64) {
+    use std::time::Duration;
+
+    let state = app.state::<AppState>();
+    let mut current: Option<AutoTrackSession> = None;
+    let mut away_since: Option<Instant> = None;
+
+    loop {
+        let grace_seconds = {
+            let auto_track = state.auto_track.lock().unwrap();
+            match auto_track.grace_seconds {
+                Some(grace_seconds) if auto_track.generation == generation => grace_seconds,
+                _ => break,
+            }
+        };
+        let foreground = foreground_app(&state);
+
+        let focused = match (&current, &foreground) {
+            (Some(session), Some((name, _))) => same_process_name(&session.app_name, name),
+            _ => false,
+        };
+        if focused {
+            away_since = None;
+        } else {
+            let away_for = away_since.get_or_insert_with(Instant::now).elapsed();
+            if current.is_none() || away_for >= Duration::from_secs(grace_seconds) {
+                if let Some(session) = current.take() {
+                    end_auto_track_session(&app, session);
+                }
+                if let Some((app_name, exe_path)) = foreground {
+                    let session = AutoTrackSession {
+                        app_name,
+                        exe_path,
+                        start_time: chrono::Local::now().to_rfc3339(),
+                        end_time: None,
+                        duration_seconds: 0,
+                    };
+                    let _ = app.emit("auto-track-session-started", session.clone());
+                    current = Some(session);
+                }
+                away_since = None;
+            }
+        }
+
+        std::thread::sleep(Duration::from_millis(AUTO_TRACK_POLL_MS));
+    }
+
+    if let Some(session) = current {
+        end_auto_track_session(&app, session);
+    }
+}
+
+/// Turn auto-track on/off (grace None), starting the loop when it was off
+fn apply_auto_track(app: &tauri::AppHandle, state: &AppState, grace_seconds: Option<u64>) {
+    let mut auto_track = state.auto_track.lock().unwrap();
+    // The tracking thread is already running when grace was set, it picks up the new value
+    let start_thread = grace_seconds.is_some() && auto_track.grace_seconds.is_none();
+    auto_track.grace_seconds = grace_seconds;
+    if start_thread {
+        auto_track.generation += 1;
+        let generation = auto_track.generation;
+        let app = app.clone();
+        std::thread::spawn(move || run_auto_track(app, generation));
+    }
+}
+
+#[tauri::command]
+fn get_auto_track_foreground(state: State<AppState>) -> AutoTrackSettings {
+    let grace = state.auto_track.lock().unwrap().grace_seconds;
+    AutoTrackSettings {
+        enabled: grace.is_some(),
+        grace_seconds: grace.unwrap_or(DEFAULT_AUTO_TRACK_GRACE_SECS),
+    }
+}
+
+/// Start/stop following the foreground app (persisted)
+/// Emits auto-track-session-started / auto-track-session-ended
+#[tauri::command]
+fn set_auto_track_foreground(
+    app: tauri::AppHandle,
+    state: State<AppState>,
+    enabled: bool,
+    grace_seconds: Option<u64>,
+) -> Result<(), String> {
+    let grace_seconds = grace_seconds.unwrap_or(DEFAULT_AUTO_TRACK_GRACE_SECS);
+    apply_auto_track(&app, &state, enabled.then_some(grace_seconds));
+    update_app_data(&state, |data| {
+        data.auto_track_foreground = enabled;
+        data.auto_track_grace_secs = Some(grace_seconds);
+    })
+}
+
+// Idle auto-optimize - trims background working sets once per idle period
+const AUTO_OPTIMIZE_POLL_MS: u64 = 5000;
+const DEFAULT_AUTO_OPTIMIZE_IDLE_SECS: u64 = 300;
+
+struct AutoOptimizeLoop {
+    // None = off, the running thread exits on its next wake-up
+    idle_secs: Option<u64>,
+    // Bumped on every start so a thread left over from a quick off/on exits too
+    generation: u64,
+}
+
+#[derive(Serialize, Clone, Copy, Default)]
+struct TrimResult {
+    trimmed: usize,
+    freed_mb: f64,
+}
+
+/// Seconds since the last keyboard/mouse input in any app
+#[cfg(windows)]
+fn idle_seconds() -> Option<u64> {
+    use windows::Win32::System::SystemInformation::GetTickCount;
+    use windows::Win32::UI::Input::KeyboardAndMouse::{GetLastInputInfo, LASTINPUTINFO};
+
+    let mut info = LASTINPUTINFO {
+        cbSize: std::mem::size_of::<LASTINPUTINFO>() as u32,
+        dwTime: 0,
+    };
+    unsafe {
+        if !GetLastInputInfo(&mut info).as_bool() {
+            return None;
+        }
+        // Both are 32-bit tick counts, wrapping_sub survives the 49.7 day rollover
+        Some(GetTickCount().wrapping_sub(info.dwTime) as u64 / 1000)
+    }
+}
+
+#[cfg(not(windows))]
+fn idle_seconds() -> Option<u64> {
+    None
+}
+
+/// Empty the working set of every non-protected process except the foreground one
+#[cfg(windows)]
+fn trim_background_memory(state: &AppState) -> TrimResult {
+    use windows::Win32::System::ProcessStatus::EmptyWorkingSet;
+    use windows::Win32::System::Threading::PROCESS_SET_QUOTA;
+
+    let processes: Vec<(u32, String)> = {
+        let mut system = state.system.lock().unwrap();
+        system.refresh_processes(sysinfo::ProcessesToUpdate::All, true);
+        system
+            .processes()
+            .iter()
+            .map(|(pid, process)| (pid.as_u32(), canonical_name(process)))
+            .collect()
+    };
+    let user_protected = state.protected_processes.lock().unwrap().clone();
+    let foreground = get_foreground_process_id();
+
+    let mut result = TrimResult::default();
+    let mut freed_bytes = 0u64;
+    for (pid, name) in processes {
+        if Some(pid) == foreground || is_protected_process(pid, &name, &user_protected) {
+            continue;
+        }
+        let before = get_process_memory(pid).map(|m| m.private_working_set).unwrap_or(0);
+        unsafe {
+            let Ok(handle) = OpenProcess(PROCESS_QUERY_INFORMATION | PROCESS_SET_QUOTA, false, pid) else { continue };
+            let trimmed = EmptyWorkingSet(handle).is_ok();
+            let _ = CloseHandle(handle);
+            if !trimmed {
+                continue;
+            }
+        }
+        let after = get_process_memory(pid).map(|m| m.private_working_set).unwrap_or(before);
+        freed_bytes += before.saturating_sub(after);
+        result.trimmed += 1;
+    }
+    result.freed_mb = round_metric(freed_bytes as f64 / (1024.0 * 1024.0));
+    result
+}
+
+#[cfg(not(windows))]
+fn trim_background_memory(_state: &AppState) -> TrimResult {
+    TrimResult::default()
+}
+
+/// Runs while auto-optimize is enabled, trims once each time the system has been
+/// idle past the threshold and re-arms when input resumes
+fn run_auto_optimize(app: tauri::AppHandle, generation: u64) {
+    let state = app.state::<AppState>();
+    let mut ran_this_idle = false;
+
+    loop {
+        let threshold = {
+            let auto_optimize = state.auto_optimize.lock().unwrap();
+            match auto_optimize.idle_secs {
+                Some(threshold) if auto_optimize.generation == generation => threshold,
+                _ => break,
+            }
+        };
+
+        match idle_seconds() {
+            Some(idle) if idle >= threshold => {
+                if !ran_this_idle {
+                    ran_this_idle = true;
+                    let result = trim_background_memory(&state);
+                    log_info!("Idle auto-optimize trimmed {} processes, freed {} MB", result.trimmed, result.freed_mb);
+                    let _ = app.emit("auto-optimize-ran", result);
+                }
+            }
+            _ => ran_this_idle = false,
+        }
+
+        std::thread::sleep(std::time::Duration::from_millis(AUTO_OPTIMIZE_POLL_MS));
+    }
+}
+
+/// Turn auto-optimize on/off (threshold None), starting the loop when it was off
+fn apply_auto_optimize(app: &tauri::AppHandle, state: &AppState, idle_secs: Option<u64>) {
+    let mut auto_optimize = state.auto_optimize.lock().unwrap();
+    // The loop is already running when a threshold was set, it picks up the new value
+    let start_thread = idle_secs.is_some() && auto_optimize.idle_secs.is_none();
+    auto_optimize.idle_secs = idle_secs;
+    if start_thread {
+        auto_optimize.generation += 1;
+        let generation = auto_optimize.generation;
+        let app = app.clone();
+        std::thread::spawn(move || run_auto_optimize(app, generation));
+    }
+}
+
+/// Trim background memory automatically after `idle_threshold_secs` without input (persisted)
+/// Emits auto-optimize-ran with the TrimResult
+#[tauri::command]
+fn set_auto_optimize_on_idle(
+    app: tauri::AppHandle,
+    state: State<AppState>,
+    enabled: bool,
+    idle_threshold_secs: Option<u64>,
+) -> Result<(), String> {
+    let threshold = idle_threshold_secs.unwrap_or(DEFAULT_AUTO_OPTIMIZE_IDLE_SECS);
+    if threshold == 0 {
+        return Err("idle_threshold_secs must be greater than 0".to_string());
+    }
+
+    apply_auto_optimize(&app, &state, enabled.then_some(threshold));
+    update_app_data(&state, |data| {
+        data.auto_optimize_on_idle = enabled;
+        data.auto_optimize_idle_secs = Some(threshold);
+    })
+}
+
+// Directory whitelisting - bulk-add the executables in a folder, optionally keep watching it
+const WHITELIST_WATCH_POLL_MS: u64 = 10_000;
+
+#[derive(Serialize, Deserialize, Clone)]
+struct WatchedDirectory {
+    path: String,
+    recursive: bool,
+}
+
+/// Every `.exe` in `dir`, descending into subdirectories when `recursive`
+fn find_executables(dir: &Path, recursive: bool, found: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else { return };
+    for entry in entries.flatten() {
+        let Ok(file_type) = entry.file_type() else { continue };
+        let path = entry.path();
+        if file_type.is_dir() {
+            if recursive {
+                find_executables(&path, true, found);
+            }
+        } else if path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("exe")) {
+            found.push(path);
+        }
+    }
+}
+
+/// Whitelist the executables in `dir` that aren't listed yet and return them.
+/// New entries are also emitted as whitelist-updated so the frontend keeps them when it saves.
+fn whitelist_directory(
+    app: &tauri::AppHandle,
+    state: &AppState,
+    dir: &Path,
+    recursive: bool,
+) -> Result<Vec<SavedWhitelistEntry>, String> {
+    if !dir.is_dir() {
+        return Err(format!("{} is not a directory", dir.display()));
+    }
+    let mut executables = Vec::new();
+    find_executables(dir, recursive, &mut executables);
+
+    let _lock = state.data_file_lock.lock().unwrap();
+    let data_file = get_data_file_path(state);
+    let mut data = read_app_data_for_update(state, &data_file);
+    // Ids follow the frontend's Date.now() scheme, bumped past anything already taken
+    let mut next_id = data
+        .whitelist
+        .iter()
+        .map(|entry| entry.id + 1)
+        .max()
+        .unwrap_or(0)
+        .max(chrono::Local::now().timestamp_millis());
+    let added_date = chrono::Utc::now().to_rfc3339();
+
+    let mut listed_paths: std::collections::HashSet<String> = data
+        .whitelist
+        .iter()
+        .filter_map(|entry| entry.exe_path.as_deref().map(canonical_exe_path))
+        .collect();
+
+    let mut added = Vec::new();
+    for path in executables {
+        let exe_path = path.to_string_lossy().to_string();
+        let Some(name) = path.file_name().map(|name| name.to_string_lossy().to_string()) else { continue };
+        // The same file, or a name-only entry for it, means it's already listed
+        let name_listed = data
+            .whitelist
+            .iter()
+            .any(|entry| entry.exe_path.is_none() && entry.name.eq_ignore_ascii_case(&name));
+        if name_listed || !listed_paths.insert(canonical_exe_path(&exe_path)) {
+            continue;
+        }
+        let entry = SavedWhitelistEntry {
+            id: next_id,
+            name,
+            exe_path: Some(exe_path),
+            added_date: added_date.clone(),
+            is_tracked: true,
+        };
+        next_id += 1;
+        data.whitelist.push(entry.clone());
+        added.push(entry);
+    }
+
+    if !added.is_empty() {
+        write_app_data(&data_file, &data)?;
+        log_info!("Whitelisted {} executables from {}", added.len(), dir.display());
+        let _ = app.emit("whitelist-updated", added.clone());
+    }
+    Ok(added)
+}
+
+/// Whitelist every `.exe` in `dir` (and its subdirectories when `recursive`), skipping listed paths
+#[tauri::command]
+fn add_whitelist_from_directory(
+    app: tauri::AppHandle,
+    state: State<AppState>,
+    dir: String,
+    recursive: bool,
+) -> Result<Vec<SavedWhitelistEntry>, String> {
+    whitelist_directory(&app, &state, Path::new(&dir), recursive)
+}
+
+/// Runs while any directory is watched, whitelisting executables dropped into them
+fn run_whitelist_watch(app: tauri::AppHandle) {
+    let state = app.state::<AppState>();
+    loop {
+        let watched = state.watched_directories.lock().unwrap().clone();
+        if watched.is_empty() {
+            break;
+        }
+        for dir in watched {
+            // A folder that's gone (unplugged drive, deleted) is picked up again once it's back
+            let path = Path::new(&dir.path);
+            if path.is_dir() {
+                if let Err(e) = whitelist_directory(&app, &state, path, dir.recursive) {
+                    log_error!("Failed to whitelist {}: {}", dir.path, e);
+                }
+            }
+        }
+        std::thread::sleep(std::time::Duration::from_millis(WHITELIST_WATCH_POLL_MS));
+    }
+}
+
+/// Whitelist `dir` now and keep adding new executables that appear in it (persisted)
+#[tauri::command]
+fn watch_whitelist_directory(
+    app: tauri::AppHandle,
+    state: State<AppState>,
+    dir: String,
+    recursive: bool,
+) -> Result<Vec<SavedWhitelistEntry>, String> {
+    let added = whitelist_directory(&app, &state, Path::new(&dir), recursive)?;
+
+    let mut watched = state.watched_directories.lock().unwrap();
+    // The watch thread is already running when something was watched, it picks up the new entry
+    let start_thread = watched.is_empty();
+    watched.retain(|entry| !entry.path.eq_ignore_ascii_case(&dir));
+    watched.push(WatchedDirectory { path: dir, recursive });
+    let saved = watched.clone();
+    drop(watched);
+
+    if start_thread {
+        std::thread::spawn(move || run_whitelist_watch(app));
+    }
+    update_app_data(&state, |data| data.watched_directories = saved)?;
+    Ok(added)
+}
+
+/// Stop watching `dir`, entries it already added stay whitelisted
+#[tauri::command]
+fn unwatch_whitelist_directory(state: State<AppState>, dir: String) -> Result<(), String> {
+    let mut watched = state.watched_directories.lock().unwrap();
+    watched.retain(|entry| !entry.path.eq_ignore_ascii_case(&dir));
+    let saved = watched.clone();
+    drop(watched);
+    update_app_data(&state, |data| data.watched_directories = saved)
+}
+
+#[tauri::command]
+fn get_watched_directories(state: State<AppState>) -> Vec<WatchedDirectory> {
+    state.watched_directories.lock().unwrap().clone()
+}
+
+// Game mode - freezes whitelisted apps that aren't in the foreground
+/// Does a process match a whitelist entry (by exe path when both have one, else by name)
+fn matches_whitelist(whitelist: &[SavedWhitelistEntry], name: &str, exe_path: Option<&str>) -> bool {
+    whitelist.iter().any(|entry| match (entry.exe_path.as_deref(), exe_path) {
+        (Some(entry_path), Some(path)) => entry_path.eq_ignore_ascii_case(path),
+        _ => same_process_name(&entry.name, name),
+    })
+}
+
+#[derive(Serialize)]
+struct GameModeResult {
+    suspended: Vec<u32>,
+}
+
+/// Suspend every whitelisted process except the foreground one, `keep_pids` and system processes
+#[tauri::command]
+fn enter_game_mode(state: State<AppState>, keep_pids: Vec<u32>) -> Result<GameModeResult, String> {
+    let mut game_mode = state.game_mode_suspended.lock().unwrap();
+    if game_mode.is_some() {
+        return Err("Game mode is already active".to_string());
+    }
+
+    let whitelist = read_app_data(&get_data_file_path(&state))?.whitelist;
+    let protected = state.protected_processes.lock().unwrap().clone();
+    let foreground_pid = get_foreground_process_id();
+
+    let mut system = state.system.lock().unwrap();
+    system.refresh_processes(sysinfo::ProcessesToUpdate::All, true);
+
+    let mut suspended = Vec::new();
+    for (pid, process) in system.processes() {
+        let pid = pid.as_u32();
+        if Some(pid) == foreground_pid || keep_pids.contains(&pid) {
+            continue;
+        }
+
+        let name = canonical_name(process);
+        if is_protected_process(pid, &name, &protected) {
+            continue;
+        }
+
+        let exe_path = process.exe().map(|p| p.to_string_lossy().to_string());
+        if matches_whitelist(&whitelist, &name, exe_path.as_deref())
+            && set_threads_suspended(pid, true).is_ok()
+        {
+            suspended.push(pid);
+        }
+    }
+
+    *game_mode = Some(suspended.clone());
+    Ok(GameModeResult { suspended })
+}
+
+/// Resume exactly the processes game mode suspended, returns their PIDs
+fn release_game_mode(state: &AppState) -> Vec<u32> {
+    let suspended = state.game_mode_suspended.lock().unwrap().take().unwrap_or_default();
+    for pid in &suspended {
+        let _ = set_threads_suspended(*pid, false);
+    }
+    suspended
+}
+
+#[tauri::command]
+fn exit_game_mode(state: State<AppState>) -> Vec<u32> {
+    release_game_mode(&state)
+}
+
+// Process rules - automation evaluated on every poll
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[serde(tag = "type")]
+enum ProcessRule {
+    // Keep `target_name` suspended while any `trigger_name` process is running
+    SuspendWhileRunning { target_name: String, trigger_name: String },
+}
+
+/// Suspend rule targets whose trigger is running and resume the ones whose trigger
+/// exited. Only PIDs suspended here are ever resumed, game mode and CPU caps are left alone
+fn enforce_process_rules(state: &AppState, processes: &[ProcessInfo]) {
+    let rules = state.process_rules.lock().unwrap().clone();
+    let mut suspended = state.rule_suspended.lock().unwrap();
+    if rules.is_empty() && suspended.is_empty() {
+        return;
+    }
+
+    let user_protected = state.protected_processes.lock().unwrap().clone();
+    let game_mode: Vec<u32> = state.game_mode_suspended.lock().unwrap().clone().unwrap_or_default();
+    let capped: Vec<u32> = state.cpu_caps.lock().unwrap().keys().copied().collect();
+
+    let mut wanted: HashMap<u32, u64> = HashMap::new();
+    for rule in &rules {
+        let ProcessRule::SuspendWhileRunning { target_name, trigger_name } = rule;
+        if !processes.iter().any(|p| same_process_name(trigger_name, &p.name)) {
+            continue;
+        }
+        for process in processes.iter().filter(|p| same_process_name(target_name, &p.name)) {
+            // A process can't be both trigger and target
+            if same_process_name(trigger_name, &process.name)
+                || is_protected_process(process.pid, &process.name, &user_protected)
+            {
+                continue;
+            }
+            wanted.insert(process.pid, process.create_time);
+        }
+    }
+
+    // Resume what no rule wants anymore (exited processes just drop out)
+    let alive: HashMap<u32, u64> = processes.iter().map(|p| (p.pid, p.create_time)).collect();
+    suspended.retain(|pid, create_time| {
+        if wanted.get(pid) == Some(create_time) {
+            return true;
+        }
+        if alive.get(pid) == Some(create_time) {
+            let _ = set_threads_suspended(*pid, false);
+            log_info!("Rule resumed PID {}", pid);
+        }
+        false
+    });
+
+    for (pid, create_time) in wanted {
+        if suspended.contains_key(&pid) || game_mode.contains(&pid) || capped.contains(&pid) {
+            continue;
+        }
+        if set_threads_suspended(pid, true).is_ok() {
+            log_info!("Rule suspended PID {}", pid);
+            suspended.insert(pid, create_time);
+        }
+    }
+}
+
+/// Resume every process a rule suspended
+fn release_rule_suspends(state: &AppState) {
+    let mut suspended = state.rule_suspended.lock().unwrap();
+    for pid in suspended.keys() {
+        let _ = set_threads_suspended(*pid, false);
+    }
+    suspended.clear();
+}
+
+#[tauri::command]
+fn get_process_rules(state: State<AppState>) -> Vec<ProcessRule> {
+    state.process_rules.lock().unwrap().clone()
+}
+
+/// Replace the rule list (persisted), removed rules release their processes on the next poll
+#[tauri::command]
+fn set_process_rules(state: State<AppState>, rules: Vec<ProcessRule>) -> Result<(), String> {
+    for rule in &rules {
+        let ProcessRule::SuspendWhileRunning { target_name, trigger_name } = rule;
+        if target_name.trim().is_empty() || trigger_name.trim().is_empty() {
+            return Err("Rule names must not be empty".to_string());
+        }
+        if same_process_name(target_name, trigger_name) {
+            return Err(format!("{} can't suspend itself", target_name));
+        }
+    }
+    *state.process_rules.lock().unwrap() = rules.clone();
+    update_app_data(&state, |data| data.process_rules = rules)
+}
+
+#[derive(Serialize, Clone)]
+struct SignatureInfo {
+    signed: bool,
+    signer: Option<String>,
+    trusted: bool,
+}
+
+// WinVerifyTrust results meaning "there is no signature at all"
+#[cfg(windows)]
+const TRUST_E_NOSIGNATURE: i32 = 0x800B0100_u32 as i32;
+#[cfg(windows)]
+const TRUST_E_SUBJECT_FORM_UNKNOWN: i32 = 0x800B0003_u32 as i32;
+#[cfg(windows)]
+const TRUST_E_PROVIDER_UNKNOWN: i32 = 0x800B0001_u32 as i32;
+
+#[cfg(windows)]
+fn no_signature(status: i32) -> bool {
+    matches!(status, TRUST_E_NOSIGNATURE | TRUST_E_SUBJECT_FORM_UNKNOWN | TRUST_E_PROVIDER_UNKNOWN)
+}
+
+/// Run WinVerifyTrust on a file or catalog member, returns the status and the name
+/// on the leaf certificate of the first signer
+#[cfg(windows)]
+unsafe fn verify_trust(
+    union_choice: windows::Win32::Security::WinTrust::WINTRUST_DATA_UNION_CHOICE,
+    subject: windows::Win32::Security::WinTrust::WINTRUST_DATA_0,
+) -> (i32, Option<String>) {
+    use windows::Win32::Foundation::HWND;
+    use windows::Win32::Security::WinTrust::{
+        WinVerifyTrust, WTHelperGetProvSignerFromChain, WTHelperProvDataFromStateData,
+        WINTRUST_ACTION_GENERIC_VERIFY_V2, WINTRUST_DATA, WTD_REVOKE_NONE, WTD_STATEACTION_CLOSE,
+        WTD_STATEACTION_VERIFY, WTD_UI_NONE,
+    };
+    use windows::Win32::Security::Cryptography::{CertGetNameStringW, CERT_NAME_SIMPLE_DISPLAY_TYPE};
+
+    let mut trust_data = WINTRUST_DATA {
+        cbStruct: std::mem::size_of::<WINTRUST_DATA>() as u32,
+        dwUIChoice: WTD_UI_NONE,
+        fdwRevocationChecks: WTD_REVOKE_NONE,
+        dwUnionChoice: union_choice,
+        Anonymous: subject,
+        dwStateAction: WTD_STATEACTION_VERIFY,
+        ..Default::default()
+    };
+
+    let mut action = WINTRUST_ACTION_GENERIC_VERIFY_V2;
+    let status = WinVerifyTrust(HWND::default(), &mut action, &mut trust_data as *mut _ as *mut _);
+
+    let mut signer = None;
+    if !no_signature(status) {
+        let provider_data = WTHelperProvDataFromStateData(trust_data.hWVTStateData);
+        if !provider_data.is_null() {
+            let provider_signer = WTHelperGetProvSignerFromChain(provider_data, 0, false, 0);
+            if !provider_signer.is_null() && !(*provider_signer).pasCertChain.is_null() {
+                let cert = (*(*provider_signer).pasCertChain).pCert;
+                let mut name = [0u16; 256];
+                let len = CertGetNameStringW(cert, CERT_NAME_SIMPLE_DISPLAY_TYPE, 0, None, Some(&mut name));
+                if len > 1 {
+                    signer = Some(String::from_utf16_lossy(&name[..len as usize - 1]));
+                }
+            }
+        }
+    }
+
+    // Release the state WinVerifyTrust allocated for WTD_STATEACTION_VERIFY
+    trust_data.dwStateAction = WTD_STATEACTION_CLOSE;
+    WinVerifyTrust(HWND::default(), &mut action, &mut trust_data as *mut _ as *mut _);
+
+    (status, signer)
+}
+
+/// Verify `exe_path` against the system catalog that lists its hash, None when no
+/// catalog does. Most inbox Windows binaries are signed this way instead of embedded
+#[cfg(windows)]
+fn check_catalog_signature(exe_path: &str) -> Option<(i32, Option<String>)> {
+    use std::os::windows::io::AsRawHandle;
+    use windows::Win32::Foundation::HANDLE;
+    use windows::Win32::Security::WinTrust::{
+        CryptCATAdminAcquireContext2, CryptCATAdminCalcHashFromFileHandle2, CryptCATAdminEnumCatalogFromHash,
+        CryptCATAdminReleaseCatalogContext, CryptCATAdminReleaseContext, CryptCATCatalogInfoFromContext,
+        CATALOG_INFO, WINTRUST_CATALOG_INFO, WINTRUST_DATA_0, WTD_CHOICE_CATALOG,
+    };
+
+    let file = fs::File::open(exe_path).ok()?;
+    let file_handle = HANDLE(file.as_raw_handle());
+    let wide_path = to_wide(exe_path);
+
+    unsafe {
+        let mut admin = 0isize;
+        CryptCATAdminAcquireContext2(&mut admin, None, windows::core::w!("SHA256"), None, 0).ok()?;
+
+        let mut result = None;
+        let mut hash_len = 0u32;
+        let _ = CryptCATAdminCalcHashFromFileHandle2(admin, file_handle, &mut hash_len, None, 0);
+        let mut hash = vec![0u8; hash_len as usize];
+        if hash_len > 0
+            && CryptCATAdminCalcHashFromFileHandle2(admin, file_handle, &mut hash_len, Some(hash.as_mut_ptr()), 0).is_ok()
+        {
+            let catalog = CryptCATAdminEnumCatalogFromHash(admin, &hash, 0, None);
+            if catalog != 0 {
+                let mut info = CATALOG_INFO {
+                    cbStruct: std::mem::size_of::<CATALOG_INFO>() as u32,
+                    ..Default::default()
+                };
+                if CryptCATCatalogInfoFromContext(catalog, &mut info, 0).is_ok() {
+                    // Catalogs list members by their hash as uppercase hex
+                    let tag: String = hash.iter().map(|byte| format!("{:02X}", byte)).collect();
+                    let wide_tag = to_wide(&tag);
+                    let mut catalog_info = WINTRUST_CATALOG_INFO {
+                        cbStruct: std::mem::size_of::<WINTRUST_CATALOG_INFO>() as u32,
+                        pcwszCatalogFilePath: PCWSTR::from_raw(info.wszCatalogFile.as_ptr()),
+                        pcwszMemberTag: PCWSTR::from_raw(wide_tag.as_ptr()),
+                        pcwszMemberFilePath: PCWSTR::from_raw(wide_path.as_ptr()),
+                        hMemberFile: file_handle,
+                        pbCalculatedFileHash: hash.as_mut_ptr(),
+                        cbCalculatedFileHash: hash_len,
+                        hCatAdmin: admin,
+                        ..Default::default()
+                    };
+                    result = Some(verify_trust(WTD_CHOICE_CATALOG, WINTRUST_DATA_0 { pCatalog: &mut catalog_info }));
+                }
+                let _ = CryptCATAdminReleaseCatalogContext(admin, catalog, 0);
+            }
+        }
+        let _ = CryptCATAdminReleaseContext(admin, 0);
+        result
+    }
+}
+
+/// Check an exe's Authenticode signature with WinVerifyTrust, falling back to the
+/// system catalogs for files without an embedded signature
+#[cfg(windows)]
+fn check_file_signature(exe_path: &str) -> SignatureInfo {
+    use windows::Win32::Foundation::HANDLE;
+    use windows::Win32::Security::WinTrust::{WINTRUST_DATA_0, WINTRUST_FILE_INFO, WTD_CHOICE_FILE};
+
+    let wide_path = to_wide(exe_path);
+
+    let mut file_info = WINTRUST_FILE_INFO {
+        cbStruct: std::mem::size_of::<WINTRUST_FILE_INFO>() as u32,
+        pcwszFilePath: PCWSTR::from_raw(wide_path.as_ptr()),
+        hFile: HANDLE::default(),
+        pgKnownSubject: std::ptr::null_mut(),
+    };
+    let (mut status, mut signer) = unsafe { verify_trust(WTD_CHOICE_FILE, WINTRUST_DATA_0 { pFile: &mut file_info }) };
+
+    if no_signature(status) {
+        if let Some((catalog_status, catalog_signer)) = check_catalog_signature(exe_path) {
+            status = catalog_status;
+            signer = catalog_signer;
+        }
+    }
+
+    SignatureInfo {
+        signed: !no_signature(status),
+        signer,
+        trusted: status == 0,
+    }
+}
+
+#[cfg(not(windows))]
+fn check_file_signature(_exe_path: &str) -> SignatureInfo {
+    SignatureInfo { signed: false, signer: None, trusted: false }
+}
+
+/// Verify the digital signature of a running process's executable
+/// Results are cached by exe path + modification time
+#[tauri::command]
+fn verify_process_signature(state: State<AppState>, pid: u32) -> Result<SignatureInfo, String> {
+    let exe_path = {
+        let mut system = state.system.lock().unwrap();
+        let pid_obj = Pid::from_u32(pid);
+        system.refresh_processes(sysinfo::ProcessesToUpdate::Some(&[pid_obj]), true);
+        let process = system.process(pid_obj).ok_or("Process not found")?;
+        process.exe()
+            .map(|p| p.to_string_lossy().to_string())
+            .ok_or("Executable path unavailable")?
+    };
+
+    let modified = fs::metadata(&exe_path)
+        .and_then(|m| m.modified())
+        .map_err(|e| e.to_string())?;
+
+    if let Some((cached_modified, info)) = state.signature_cache.lock().unwrap().get(&exe_path) {
+        if *cached_modified == modified {
+            return Ok(info.clone());
+        }
+    }
+
+    let info = check_file_signature(&exe_path);
+    state.signature_cache.lock().unwrap().insert(exe_path, (modified, info.clone()));
+    Ok(info)
+}
+
+/// Turn an OpenProcess/process API error into a message the UI can show
+#[cfg(windows)]
+fn describe_process_error(error: windows::core::Error) -> String {
+    use windows::Win32::Foundation::{E_ACCESSDENIED, E_INVALIDARG};
+
+    log_warn!("Process command failed: {} ({:?})", error.message(), error.code());
+    if error.code() == E_ACCESSDENIED {
+        "Access denied (try running as administrator)".to_string()
+    } else if error.code() == E_INVALIDARG {
+        // OpenProcess fails with ERROR_INVALID_PARAMETER for PIDs that don't exist
+        "Process not found".to_string()
+    } else {
+        error.message().to_string()
+    }
+}
+
+// PDH (performance counters) - one query per counter, rate counters need two
+// collections before they return a value
+#[cfg(windows)]
+mod pdh {
+    use windows::core::PCWSTR;
+    use windows::Win32::System::Performance::{
+        PdhAddEnglishCounterW, PdhCloseQuery, PdhCollectQueryData, PdhGetFormattedCounterValue,
+        PdhOpenQueryW, PDH_FMT_COUNTERVALUE, PDH_FMT_DOUBLE, PDH_HCOUNTER, PDH_HQUERY,
+    };
+
+    pub struct Counter {
+        query: PDH_HQUERY,
+        counter: PDH_HCOUNTER,
+    }
+
+    // PDH handles are plain process-local identifiers, only used under AppState's Mutex
+    unsafe impl Send for Counter {}
+
+    impl Counter {
+        pub fn open(path: &str) -> Option<Counter> {
+            let path = super::to_wide(path);
+            unsafe {
+                let mut query = PDH_HQUERY::default();
+                if PdhOpenQueryW(PCWSTR::null(), 0, &mut query) != 0 {
+                    return None;
+                }
+                let mut counter = PDH_HCOUNTER::default();
+                if PdhAddEnglishCounterW(query, PCWSTR::from_raw(path.as_ptr()), 0, &mut counter) != 0 {
+                    PdhCloseQuery(query);
+                    return None;
+                }
+                // Prime rate counters
+                PdhCollectQueryData(query);
+                Some(Counter { query, counter })
+            }
+        }
+
+        pub fn read(&self) -> Option<f64> {
+            unsafe {
+                if PdhCollectQueryData(self.query) != 0 {
+                    return None;
+                }
+                let mut value = PDH_FMT_COUNTERVALUE::default();
+                if PdhGetFormattedCounterValue(self.counter, PDH_FMT_DOUBLE, None, &mut value) != 0 {
+                    return None;
+                }
+                Some(value.Anonymous.doubleValue)
+            }
+        }
+
+        /// Values of every instance of a wildcard counter path, as (instance name, value)
+        pub fn read_array(&self) -> Vec<(String, f64)> {
+            use windows::Win32::System::Performance::{PdhGetFormattedCounterArrayW, PDH_FMT_COUNTERVALUE_ITEM_W};
+            const PDH_MORE_DATA: u32 = 0x8000_07D2;
+
+            unsafe {
+                if PdhCollectQueryData(self.query) != 0 {
+                    return Vec::new();
+                }
+                let mut buffer_size = 0u32;
+                let mut item_count = 0u32;
+                let status = PdhGetFormattedCounterArrayW(
+                    self.counter,
+                    PDH_FMT_DOUBLE,
+                    &mut buffer_size,
+                    &mut item_count,
+                    None,
+                );
+                if status != PDH_MORE_DATA || buffer_size == 0 {
+                    return Vec::new();
+                }
+
+                // u64 storage keeps the items aligned, names are stored after them in the same buffer
+                let mut buffer: Vec<u64> = vec![0; (buffer_size as usize).div_ceil(8)];
+                let items = buffer.as_mut_ptr() as *mut PDH_FMT_COUNTERVALUE_ITEM_W;
+                let status = PdhGetFormattedCounterArrayW(
+                    self.counter,
+                    PDH_FMT_DOUBLE,
+                    &mut buffer_size,
+                    &mut item_count,
+                    Some(items),
+                );
+                if status != 0 {
+                    return Vec::new();
+                }
+
+                std::slice::from_raw_parts(items, item_count as usize)
+                    .iter()
+                    .map(|item| (
+                        item.szName.to_string().unwrap_or_default(),
+                        item.FmtValue.Anonymous.doubleValue,
+                    ))
+                    .collect()
+            }
+        }
+    }
+
+    impl Drop for Counter {
+        fn drop(&mut self) {
+            unsafe {
+                PdhCloseQuery(self.query);
+            }
+        }
+    }
+}
+
+// ntdll entry points the windows crate doesn't expose as stable Win32 APIs
+#[cfg(windows)]
+mod ntdll {
+    use std::ffi::c_void;
+    use windows::Win32::Foundation::HANDLE;
+
+    // PROCESSINFOCLASS values
+    pub const PROCESS_BASIC_INFORMATION: u32 = 0;
+    pub const PROCESS_IO_PRIORITY: u32 = 33;
+
+    #[repr(C)]
+    #[derive(Default)]
+    pub struct ProcessBasicInformation {
+        pub exit_status: i32,
+        pub peb_base_address: usize,
+        pub affinity_mask: usize,
+        pub base_priority: i32,
+        pub unique_process_id: usize,
+        pub inherited_from_unique_process_id: usize,
+    }
+
+    /// Returned for PROCESS_BASIC_INFORMATION when `size` is set to the larger struct
+    #[repr(C)]
+    #[derive(Default)]
+    pub struct ProcessExtendedBasicInformation {
+        pub size: usize,
+        pub basic_info: ProcessBasicInformation,
+        pub flags: u32,
+    }
+
+    // ProcessExtendedBasicInformation flag bits
+    pub const PROCESS_FLAG_IS_FROZEN: u32 = 1 << 4;
+
+    // SYSTEM_INFORMATION_CLASS values
+    pub const SYSTEM_PROCESS_INFORMATION: u32 = 5;
+    pub const STATUS_INFO_LENGTH_MISMATCH: i32 = 0xC000_0004_u32 as i32;
+
+    #[repr(C)]
+    pub struct UnicodeString {
+        pub length: u16,
+        pub maximum_length: u16,
+        pub buffer: *mut u16,
+    }
+
+    /// One process in the SystemProcessInformation list, followed by its threads
+    #[repr(C)]
+    pub struct SystemProcessInformation {
+        pub next_entry_offset: u32,
+        pub number_of_threads: u32,
+        pub working_set_private_size: i64,
+        pub hard_fault_count: u32,
+        pub number_of_threads_high_watermark: u32,
+        pub cycle_time: u64,
+        pub create_time: i64,
+        pub user_time: i64,
+        pub kernel_time: i64,
+        pub image_name: UnicodeString,
+        pub base_priority: i32,
+        pub unique_process_id: usize,
+        pub inherited_from_unique_process_id: usize,
+        pub handle_count: u32,
+        pub session_id: u32,
+        pub unique_process_key: usize,
+        pub peak_virtual_size: usize,
+        pub virtual_size: usize,
+        pub page_fault_count: u32,
+        pub peak_working_set_size: usize,
+        pub working_set_size: usize,
+        pub quota_peak_paged_pool_usage: usize,
+        pub quota_paged_pool_usage: usize,
+        pub quota_peak_non_paged_pool_usage: usize,
+        pub quota_non_paged_pool_usage: usize,
+        pub pagefile_usage: usize,
+        pub peak_pagefile_usage: usize,
+        pub private_page_count: usize,
+        pub read_operation_count: i64,
+        pub write_operation_count: i64,
+        pub other_operation_count: i64,
+        pub read_transfer_count: i64,
+        pub write_transfer_count: i64,
+        pub other_transfer_count: i64,
+    }
+
+    #[repr(C)]
+    pub struct SystemThreadInformation {
+        pub kernel_time: i64,
+        pub user_time: i64,
+        pub create_time: i64,
+        pub wait_time: u32,
+        pub start_address: *mut c_void,
+        pub unique_process: usize,
+        pub unique_thread: usize,
+        pub priority: i32,
+        pub base_priority: i32,
+        pub context_switches: u32,
+        pub thread_state: u32,
+        pub wait_reason: u32,
+    }
+
+    // KTHREAD_STATE / KWAIT_REASON values
+    pub const THREAD_STATE_WAITING: u32 = 5;
+    pub const WAIT_REASON_SUSPENDED: u32 = 5;
+
+    /// Raw SystemProcessInformation buffer, u64 storage keeps the entries aligned
+    pub fn query_process_list() -> Option<Vec<u64>> {
+        let mut buffer: Vec<u64> = vec![0; 64 * 1024];
+        loop {
+            let byte_len = (buffer.len() * 8) as u32;
+            let mut needed = 0u32;
+            let status = unsafe {
+                NtQuerySystemInformation(SYSTEM_PROCESS_INFORMATION, buffer.as_mut_ptr() as *mut c_void, byte_len, &mut needed)
+            };
+            match status {
+                0 => return Some(buffer),
+                // The list can grow between calls, leave some headroom
+                STATUS_INFO_LENGTH_MISMATCH => {
+                    let words = (needed as usize).div_ceil(8) + 8 * 1024;
+                    buffer = vec![0; words.max(buffer.len() * 2)];
+                }
+                _ => return None,
+            }
+        }
+    }
+
+    /// Image name of a process entry, empty for the idle process
+    pub fn image_name(entry: &SystemProcessInformation) -> String {
+        if entry.image_name.buffer.is_null() {
+            return String::new();
+        }
+        let chars = unsafe { std::slice::from_raw_parts(entry.image_name.buffer, entry.image_name.length as usize / 2) };
+        String::from_utf16_lossy(chars)
+    }
+
+    /// Every process entry in a buffer from query_process_list
+    pub fn processes(buffer: &[u64]) -> Vec<&SystemProcessInformation> {
+        let base = buffer.as_ptr() as *const u8;
+        let mut entries = Vec::new();
+        let mut offset = 0usize;
+        unsafe {
+            loop {
+                let entry = &*(base.add(offset) as *const SystemProcessInformation);
+                entries.push(entry);
+                if entry.next_entry_offset == 0 {
+                    return entries;
+                }
+                offset += entry.next_entry_offset as usize;
+            }
+        }
+    }
+
+    /// The thread array stored right after a process entry
+    pub fn threads(entry: &SystemProcessInformation) -> &[SystemThreadInformation] {
+        unsafe {
+            let first = (entry as *const SystemProcessInformation).add(1) as *const SystemThreadInformation;
+            std::slice::from_raw_parts(first, entry.number_of_threads as usize)
+        }
+    }
+
+    /// Threads of one process in a buffer from query_process_list
+    pub fn process_threads(buffer: &[u64], pid: u32) -> Option<&[SystemThreadInformation]> {
+        processes(buffer)
+            .into_iter()
+            .find(|entry| entry.unique_process_id == pid as usize)
+            .map(threads)
+    }
+
+    #[link(name = "ntdll")]
+    extern "system" {
+        pub fn NtQuerySystemInformation(class: u32, info: *mut c_void, info_len: u32, return_len: *mut u32) -> i32;
+
+        pub fn NtQueryInformationProcess(
+            process: HANDLE,
+            class: u32,
+            info: *mut c_void,
+            info_len: u32,
+            return_len: *mut u32,
+        ) -> i32;
+
+        pub fn NtSetInformationProcess(process: HANDLE, class: u32, info: *const c_void, info_len: u32) -> i32;
+    }
+}
+
+/// Set a process's memory priority (0-7, Windows default is 5)
+/// Lower priority pages are trimmed from the working set first
+#[tauri::command]
+#[cfg(windows)]
+fn set_process_memory_priority(state: State<AppState>, pid: u32, priority: u8) -> Result<(), String> {
+    use windows::Win32::System::Threading::{
+        SetProcessInformation, ProcessMemoryPriority, MEMORY_PRIORITY, MEMORY_PRIORITY_INFORMATION,
+        PROCESS_SET_INFORMATION,
+    };
+
+    if priority > 7 {
+        return Err("Memory priority must be between 0 and 7".to_string());
+    }
+    ensure_not_protected(&state, pid)?;
+
+    unsafe {
+        let handle = OpenProcess(PROCESS_SET_INFORMATION, false, pid).map_err(describe_process_error)?;
+
+        let info = MEMORY_PRIORITY_INFORMATION {
+            MemoryPriority: MEMORY_PRIORITY(priority as u32),
+        };
+        let result = SetProcessInformation(
+            handle,
+            ProcessMemoryPriority,
+            &info as *const _ as *const _,
+            std::mem::size_of::<MEMORY_PRIORITY_INFORMATION>() as u32,
+        );
+
+        let _ = CloseHandle(handle);
+        result.map_err(describe_process_error)
+    }
+}
+
+#[tauri::command]
+#[cfg(not(windows))]
+fn set_process_memory_priority(_pid: u32, _priority: u8) -> Result<(), String> {
+    Err("Not supported on this platform".to_string())
+}
+
+// Process environment - read from the target's PEB. Offsets are for a native
+// process of our own bitness (PEB.ProcessParameters -> RTL_USER_PROCESS_PARAMETERS)
+#[cfg(all(windows, target_pointer_width = "64"))]
+const PEB_PROCESS_PARAMETERS_OFFSET: usize = 0x20;
+#[cfg(all(windows, target_pointer_width = "64"))]
+const PARAMS_ENVIRONMENT_OFFSET: usize = 0x80;
+#[cfg(all(windows, target_pointer_width = "64"))]
+const PARAMS_ENVIRONMENT_SIZE_OFFSET: usize = 0x3F0;
+#[cfg(all(windows, target_pointer_width = "32"))]
+const PEB_PROCESS_PARAMETERS_OFFSET: usize = 0x10;
+#[cfg(all(windows, target_pointer_width = "32"))]
+const PARAMS_ENVIRONMENT_OFFSET: usize = 0x48;
+#[cfg(all(windows, target_pointer_width = "32"))]
+const PARAMS_ENVIRONMENT_SIZE_OFFSET: usize = 0x290;
+#[cfg(windows)]
+const MAX_ENVIRONMENT_BYTES: usize = 1024 * 1024;
+
+/// Read `len` bytes at `address` in another process
+#[cfg(windows)]
+unsafe fn read_remote(handle: windows::Win32::Foundation::HANDLE, address: usize, len: usize) -> Result<Vec<u8>, String> {
+    use windows::Win32::System::Diagnostics::Debug::ReadProcessMemory;
+
+    let mut buffer = vec![0u8; len];
+    let mut read = 0usize;
+    ReadProcessMemory(handle, address as *const _, buffer.as_mut_ptr() as *mut _, len, Some(&mut read))
+        .map_err(describe_process_error)?;
+    buffer.truncate(read);
+    Ok(buffer)
+}
+
+#[cfg(windows)]
+unsafe fn read_remote_usize(handle: windows::Win32::Foundation::HANDLE, address: usize) -> Result<usize, String> {
+    const SIZE: usize = std::mem::size_of::<usize>();
+    let bytes = read_remote(handle, address, SIZE)?;
+    let bytes: [u8; SIZE] = bytes.try_into().map_err(|_| "Short read from process memory".to_string())?;
+    Ok(usize::from_ne_bytes(bytes))
+}
+
+/// Environment variables of another process (most targets need elevation)
+#[tauri::command]
+#[cfg(windows)]
+fn get_process_environment(pid: u32) -> Result<HashMap<String, String>, String> {
+    unsafe {
+        let handle = OpenProcess(PROCESS_QUERY_INFORMATION | PROCESS_VM_READ, false, pid).map_err(describe_process_error)?;
+
+        let result = (|| {
+            let mut info = ntdll::ProcessBasicInformation::default();
+            let status = ntdll::NtQueryInformationProcess(
+                handle,
+                ntdll::PROCESS_BASIC_INFORMATION,
+                &mut info as *mut _ as *mut _,
+                std::mem::size_of::<ntdll::ProcessBasicInformation>() as u32,
+                std::ptr::null_mut(),
+            );
+            if status < 0 || info.peb_base_address == 0 {
+                return Err(format!("Failed to query process information: NTSTATUS 0x{:08X}", status));
+            }
+
+            let params = read_remote_usize(handle, info.peb_base_address + PEB_PROCESS_PARAMETERS_OFFSET)?;
+            let environment = read_remote_usize(handle, params + PARAMS_ENVIRONMENT_OFFSET)?;
+            let size = read_remote_usize(handle, params + PARAMS_ENVIRONMENT_SIZE_OFFSET)?;
+            if environment == 0 || size == 0 || size > MAX_ENVIRONMENT_BYTES {
+                return Err("Process has no readable environment block".to_string());
+            }
+            read_remote(handle, environment, size)
+        })();
+
+        let _ = CloseHandle(handle);
+        let block = result?;
+
+        // UTF-16 "NAME=value\0" entries, ending with an empty entry
+        let wide: Vec<u16> = block.chunks_exact(2).map(|pair| u16::from_ne_bytes([pair[0], pair[1]])).collect();
+        let mut variables = HashMap::new();
+        for entry in wide.split(|&c| c == 0).take_while(|entry| !entry.is_empty()) {
+            let entry = String::from_utf16_lossy(entry);
+            // Skip the first character so hidden "=C:=C:\dir" entries keep their leading '='
+            if let Some(split) = entry.char_indices().skip(1).find(|&(_, c)| c == '=').map(|(i, _)| i) {
+                variables.insert(entry[..split].to_string(), entry[split + 1..].to_string());
+            }
+        }
+        Ok(variables)
+    }
+}
+
+#[tauri::command]
+#[cfg(not(windows))]
+fn get_process_environment(_pid: u32) -> Result<HashMap<String, String>, String> {
+    Err("Not supported on this platform".to_string())
+}
+
+// Profiling burst - short high-rate sampling of one PID, separate from the regular
+// poll (own sysinfo instance and NVML sample cursor, so it doesn't disturb the main
+// CPU deltas or take GPU samples from the main poll)
+const PROFILING_MIN_INTERVAL_MS: u64 = 50;
+const PROFILING_MAX_DURATION_SECS: u64 = 120;
+const PROFILING_MAX_SAMPLES: usize = 5000;
+
+#[derive(Serialize, Clone)]
+struct ProfilingSample {
+    // Milliseconds since the burst started
+    timestamp_ms: u64,
+    cpu_percent: f32,
+    memory_mb: f64,
+    gpu_percent: f32,
+    // Disk read + write since the previous sample, per second
+    disk_bytes_per_sec: f64,
+}
+
+#[derive(Serialize, Clone)]
+struct ProfilingResult {
+    pid: u32,
+    interval_ms: u64,
+    samples: Vec<ProfilingSample>,
+    // Stopped early because the process exited
+    process_exited: bool,
+}
+
+/// Clears AppState::profiling_active when the burst ends, even if it panics
+struct ProfilingGuard(tauri::AppHandle);
+
+impl Drop for ProfilingGuard {
+    fn drop(&mut self) {
+        self.0.state::<AppState>().profiling_active.store(false, Ordering::SeqCst);
+    }
+}
+
+/// Newest NVML utilization sample for one PID since `cursor`, busiest device wins.
+/// None when no new sample arrived (NVML samples slower than a burst can poll)
+#[cfg(windows)]
+fn read_pid_gpu_utilization(state: &AppState, pid: u32, cursor: &mut HashMap<String, u64>) -> Option<f32> {
+    let nvml = state.nvml.as_ref()?;
+    (0..nvml.device_count().unwrap_or(0))
+        .filter_map(|index| nvml.device_by_index(index).ok())
+        .filter_map(|device| {
+            let adapter_key = device.uuid().unwrap_or_else(|_| "0".to_string());
+            read_process_utilization(&device, &adapter_key, cursor)?.get(&pid).copied()
+        })
+        .reduce(f32::max)
+}
+
+#[cfg(not(windows))]
+fn read_pid_gpu_utilization(_state: &AppState, _pid: u32, _cursor: &mut HashMap<String, u64>) -> Option<f32> {
+    None
+}
+
+fn run_profiling_burst(app: tauri::AppHandle, pid: u32, interval_ms: u64, duration_secs: u64) {
+    use std::time::Duration;
+    use sysinfo::{ProcessRefreshKind, ProcessesToUpdate};
+
+    let active = ProfilingGuard(app.clone());
+    let pid_obj = Pid::from_u32(pid);
+    let refresh_kind = ProcessRefreshKind::new().with_cpu().with_memory().with_disk_usage();
+    let mut system = System::new();
+    system.refresh_cpu_all();
+    let cpu_divisor = system.cpus().len().max(1) as f32;
+    system.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid_obj]), true, refresh_kind);
+
+    let interval = Duration::from_millis(interval_ms);
+    let started = Instant::now();
+    let mut last_sample = started;
+    let mut samples = Vec::new();
+    let mut process_exited = false;
+    let mut gpu_cursor = HashMap::new();
+    let mut gpu_percent = 0.0;
+
+    while started.elapsed() < Duration::from_secs(duration_secs) && samples.len() < PROFILING_MAX_SAMPLES {
+        std::thread::sleep(interval);
+        system.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid_obj]), true, refresh_kind);
+        let Some(process) = system.process(pid_obj) else {
+            process_exited = true;
+            break;
+        };
+
+        let now = Instant::now();
+        let secs = (now - last_sample).as_secs_f64();
+        last_sample = now;
+
+        let disk = process.disk_usage();
+        let memory_bytes = get_process_memory(pid)
+            .map(|m| m.private_working_set)
+            .unwrap_or_else(|| process.memory());
+        // Hold the last reading until NVML has a newer sample for this PID
+        if let Some(util) = read_pid_gpu_utilization(&app.state::<AppState>(), pid, &mut gpu_cursor) {
+            gpu_percent = util;
+        }
+        samples.push(ProfilingSample {
+            timestamp_ms: (now - started).as_millis() as u64,
+            cpu_percent: process.cpu_usage() / cpu_divisor,
+            memory_mb: memory_bytes as f64 / (1024.0 * 1024.0),
+            gpu_percent,
+            disk_bytes_per_sec: if secs > 0.0 {
+                (disk.read_bytes + disk.written_bytes) as f64 / secs
+            } else {
+                0.0
+            },
+        });
+    }
+
+    // Cleared before the event, so a listener can start the next burst right away
+    drop(active);
+    let _ = app.emit("profiling-complete", ProfilingResult { pid, interval_ms, samples, process_exited });
+}
+
+/// Sample one process every `interval_ms` for `duration_secs`, then emit
+/// profiling-complete with the samples. Only one burst runs at a time
+#[tauri::command]
+fn start_profiling_burst(
+    app: tauri::AppHandle,
+    state: State<AppState>,
+    pid: u32,
+    interval_ms: u64,
+    duration_secs: u64,
+) -> Result<(), String> {
+    if interval_ms < PROFILING_MIN_INTERVAL_MS {
+        return Err(format!("interval_ms must be at least {}", PROFILING_MIN_INTERVAL_MS));
+    }
+    if duration_secs == 0 || duration_secs > PROFILING_MAX_DURATION_SECS {
+        return Err(format!("duration_secs must be between 1 and {}", PROFILING_MAX_DURATION_SECS));
+    }
+
+    {
+        let mut system = state.system.lock().unwrap();
+        let pid_obj = Pid::from_u32(pid);
+        system.refresh_processes(sysinfo::ProcessesToUpdate::Some(&[pid_obj]), true);
+        if system.process(pid_obj).is_none() {
+            return Err("Process not found".to_string());
+        }
+    }
+
+    if state.profiling_active.swap(true, Ordering::SeqCst) {
+        return Err("A profiling burst is already running".to_string());
+    }
+    std::thread::spawn(move || run_profiling_burst(app, pid, interval_ms, duration_secs));
+    Ok(())
+}
+
+// Elevation - killing system processes and reading protected tokens need admin rights
+#[tauri::command]
+#[cfg(windows)]
+fn is_app_elevated() -> bool {
+    use windows::Win32::Foundation::HANDLE;
+    use windows::Win32::Security::{GetTokenInformation, TokenElevation, TOKEN_ELEVATION, TOKEN_QUERY};
+    use windows::Win32::System::Threading::{GetCurrentProcess, OpenProcessToken};
+
+    unsafe {
+        let mut token = HANDLE::default();
+        if OpenProcessToken(GetCurrentProcess(), TOKEN_QUERY, &mut token).is_err() {
+            return false;
+        }
+
+        let mut elevation = TOKEN_ELEVATION::default();
+        let mut returned = 0u32;
+        let ok = GetTokenInformation(
+            token,
+            TokenElevation,
+            Some(&mut elevation as *mut _ as *mut _),
+            std::mem::size_of::<TOKEN_ELEVATION>() as u32,
+            &mut returned,
+        ).is_ok();
+        let _ = CloseHandle(token);
+
+        ok && elevation.TokenIsElevated != 0
+    }
+}
+
+#[tauri::command]
+#[cfg(not(windows))]
+fn is_app_elevated() -> bool {
+    false
+}
+
+/// Relaunch through the UAC prompt ("runas") and exit this instance
+/// If the user declines, the current instance keeps running and an error is returned
+#[tauri::command]
+#[cfg(windows)]
+fn request_elevation(app: tauri::AppHandle) -> Result<(), String> {
+    use windows::Win32::Foundation::{GetLastError, ERROR_CANCELLED, HWND};
+    use windows::Win32::UI::Shell::ShellExecuteW;
+    use windows::Win32::UI::WindowsAndMessaging::SW_SHOWNORMAL;
+
+    if is_app_elevated() {
+        return Ok(());
+    }
+
+    let exe = std::env::current_exe().map_err(|e| e.to_string())?;
+    let args = std::env::args()
+        .skip(1)
+        .map(|arg| format!("\"{}\"", arg))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let verb = to_wide("runas");
+    let file = to_wide(&exe.to_string_lossy());
+    let params = to_wide(&args);
+
+    let result = unsafe {
+        ShellExecuteW(
+            HWND::default(),
+            PCWSTR::from_raw(verb.as_ptr()),
+            PCWSTR::from_raw(file.as_ptr()),
+            PCWSTR::from_raw(params.as_ptr()),
+            PCWSTR::null(),
+            SW_SHOWNORMAL,
+        )
+    };
+
+    // Values <= 32 are errors
+    if result.0 as isize <= 32 {
+        if unsafe { GetLastError() } == ERROR_CANCELLED {
+            return Err("Elevation was cancelled".to_string());
+        }
+        return Err(format!("Failed to relaunch as administrator (code {})", result.0 as isize));
+    }
+
+    shutdown(&app);
+    Ok(())
+}
+
+#[tauri::command]
+#[cfg(not(windows))]
+fn request_elevation() -> Result<(), String> {
+    Err("Not supported on this platform".to_string())
+}
+
+// Services - Service Control Manager enumeration, used to label svchost rows
+#[derive(Serialize)]
+struct ServiceInfo {
+    name: String,
+    display_name: String,
+    // Hosting process, None unless the service is running
+    pid: Option<u32>,
+    status: String,
+}
+
+#[cfg(windows)]
+fn enumerate_services() -> Result<Vec<ServiceInfo>, String> {
+    use windows::Win32::Foundation::ERROR_MORE_DATA;
+    use windows::Win32::System::Services::{
+        CloseServiceHandle, EnumServicesStatusExW, OpenSCManagerW, ENUM_SERVICE_STATUS_PROCESSW,
+        SC_ENUM_PROCESS_INFO, SC_MANAGER_ENUMERATE_SERVICE, SERVICE_CONTINUE_PENDING, SERVICE_PAUSED,
+        SERVICE_PAUSE_PENDING, SERVICE_RUNNING, SERVICE_START_PENDING, SERVICE_STATE_ALL,
+        SERVICE_STOPPED, SERVICE_STOP_PENDING, SERVICE_WIN32,
+    };
+
+    unsafe {
+        let manager = OpenSCManagerW(PCWSTR::null(), PCWSTR::null(), SC_MANAGER_ENUMERATE_SERVICE)
+            .map_err(|e| e.message().to_string())?;
+
+        let mut services = Vec::new();
+        let mut resume_handle = 0u32;
+        // u64 storage keeps the returned structs aligned
+        let mut buffer: Vec<u64> = vec![0; 64 * 1024 / 8];
+
+        loop {
+            let mut bytes_needed = 0u32;
+            let mut returned = 0u32;
+            let bytes = std::slice::from_raw_parts_mut(buffer.as_mut_ptr() as *mut u8, buffer.len() * 8);
+            let result = EnumServicesStatusExW(
+                manager,
+                SC_ENUM_PROCESS_INFO,
+                SERVICE_WIN32,
+                SERVICE_STATE_ALL,
+                Some(bytes),
+                &mut bytes_needed,
+                &mut returned,
+                Some(&mut resume_handle as *mut u32),
+                PCWSTR::null(),
+            );
+            let more_data = match result {
+                Ok(()) => false,
+                Err(e) if e.code() == ERROR_MORE_DATA.to_hresult() => true,
+                Err(e) => {
+                    let _ = CloseServiceHandle(manager);
+                    return Err(e.message().to_string());
+                }
+            };
+
+            let entries = std::slice::from_raw_parts(
+                buffer.as_ptr() as *const ENUM_SERVICE_STATUS_PROCESSW,
+                returned as usize,
+            );
+            for entry in entries {
+                let status = &entry.ServiceStatusProcess;
+                let state = status.dwCurrentState;
+                services.push(ServiceInfo {
+                    name: entry.lpServiceName.to_string().unwrap_or_default(),
+                    display_name: entry.lpDisplayName.to_string().unwrap_or_default(),
+                    pid: (status.dwProcessId != 0).then_some(status.dwProcessId),
+                    status: match state {
+                        s if s == SERVICE_RUNNING => "running",
+                        s if s == SERVICE_STOPPED => "stopped",
+                        s if s == SERVICE_PAUSED => "paused",
+                        s if s == SERVICE_START_PENDING => "start_pending",
+                        s if s == SERVICE_STOP_PENDING => "stop_pending",
+                        s if s == SERVICE_PAUSE_PENDING => "pause_pending",
+                        s if s == SERVICE_CONTINUE_PENDING => "continue_pending",
+                        _ => "unknown",
+                    }
+                    .to_string(),
+                });
+            }
+
+            if !more_data {
+                break;
+            }
+            // Nothing fit - grow to what the SCM asked for
+            if returned == 0 {
+                buffer.resize((bytes_needed as usize).div_ceil(8), 0);
+            }
+        }
+
+        let _ = CloseServiceHandle(manager);
+        Ok(services)
+    }
+}
+
+#[cfg(not(windows))]
+fn enumerate_services() -> Result<Vec<ServiceInfo>, String> {
+    Err("Not supported on this platform".to_string())
+}
+
+/// Every Win32 service with its state and hosting PID
+#[tauri::command]
+fn get_services() -> Result<Vec<ServiceInfo>, String> {
+    enumerate_services()
+}
+
+// Enumerating services costs an SCM round trip per poll, services rarely move between PIDs
+const SERVICE_NAMES_TTL_SECS: u64 = 5;
+
+#[derive(Default)]
+struct ServiceNameCache {
+    by_pid: HashMap<u32, Vec<String>>,
+    refreshed_at: Option<Instant>,
+}
+
+/// Fill `service_names` for processes that host running services
+fn attach_service_names(state: &AppState, processes: &mut [ProcessInfo]) {
+    let mut cache = state.service_names.lock().unwrap();
+    let fresh = cache.refreshed_at.is_some_and(|at| at.elapsed().as_secs() < SERVICE_NAMES_TTL_SECS);
+    if !fresh {
+        let Ok(services) = enumerate_services() else { return };
+        cache.by_pid.clear();
+        for service in services {
+            if let Some(pid) = service.pid {
+                cache.by_pid.entry(pid).or_default().push(service.display_name);
+            }
+        }
+        cache.refreshed_at = Some(Instant::now());
+    }
+
+    for process in processes.iter_mut() {
+        if let Some(names) = cache.by_pid.get(&process.pid) {
+            process.service_names = names.clone();
+        }
+    }
+}
+
+// Priority classes by name (GetPriorityClass values)
+const PRIORITY_CLASSES: &[(&str, u32)] = &[
+    ("idle", 0x0000_0040),
+    ("below_normal", 0x0000_4000),
+    ("normal", 0x0000_0020),
+    ("above_normal", 0x0000_8000),
+    ("high", 0x0000_0080),
+    ("realtime", 0x0000_0100),
+];
+const NORMAL_PRIORITY_CLASS_VALUE: u32 = 0x0000_0020;
+
+#[derive(Serialize)]
+struct ProcessPriority {
+    priority_class: u32,
+    priority: String,
+    // Parent PID when the process shares its parent's non-normal class - idle and
+    // below normal are passed down by CreateProcess, others by launchers that copy their own
+    inherited_from: Option<u32>,
+}
+
+#[cfg(windows)]
+fn read_priority_class(pid: u32) -> Result<u32, String> {
+    use windows::Win32::System::Threading::{GetPriorityClass, PROCESS_QUERY_LIMITED_INFORMATION};
+
+    unsafe {
+        let handle = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid).map_err(describe_process_error)?;
+        let priority_class = GetPriorityClass(handle);
+        let _ = CloseHandle(handle);
+        if priority_class == 0 {
+            return Err("Failed to read priority class".to_string());
+        }
+        Ok(priority_class)
+    }
+}
+
+#[cfg(not(windows))]
+fn read_priority_class(_pid: u32) -> Result<u32, String> {
+    Err("Not supported on this platform".to_string())
+}
+
+/// The process that started `child`, None once it has exited
+fn live_parent<'a>(system: &'a System, child: &sysinfo::Process) -> Option<&'a sysinfo::Process> {
+    // A parent that started after the child is a reused PID, not the real parent
+    system
+        .process(child.parent()?)
+        .filter(|parent| parent.start_time() <= child.start_time() && parent.pid() != child.pid())
+}
+
+/// Priority class of a process, and the parent it was probably inherited from
+#[tauri::command]
+fn get_process_priority(state: State<AppState>, pid: u32) -> Result<ProcessPriority, String> {
+    let priority_class = read_priority_class(pid)?;
+
+    let parent = {
+        let mut system = state.system.lock().unwrap();
+        system.refresh_processes(sysinfo::ProcessesToUpdate::All, true);
+        system
+            .process(Pid::from_u32(pid))
+            .and_then(|child| live_parent(&system, child))
+            .map(|parent| parent.pid().as_u32())
+    };
+
+    let inherited_from = parent.filter(|&ppid| {
+        priority_class != NORMAL_PRIORITY_CLASS_VALUE && read_priority_class(ppid) == Ok(priority_class)
+    });
+
+    let priority = PRIORITY_CLASSES
+        .iter()
+        .find(|(_, value)| *value == priority_class)
+        .map(|(name, _)| name.to_string())
+        .unwrap_or_else(|| format!("0x{:X}", priority_class));
+
+    Ok(ProcessPriority { priority_class, priority, inherited_from })
+}
+
+#[cfg(windows)]
+fn write_priority_class(pid: u32, priority_class: u32) -> Result<(), String> {
+    use windows::Win32::System::Threading::{SetPriorityClass, PROCESS_CREATION_FLAGS, PROCESS_SET_INFORMATION};
+
+    unsafe {
+        let handle = OpenProcess(PROCESS_SET_INFORMATION, false, pid).map_err(describe_process_error)?;
+        let result = SetPriorityClass(handle, PROCESS_CREATION_FLAGS(priority_class)).map_err(describe_process_error);
+        let _ = CloseHandle(handle);
+        result
+    }
+}
+
+#[cfg(not(windows))]
+fn write_priority_class(_pid: u32, _priority_class: u32) -> Result<(), String> {
+    Err("Not supported on this platform".to_string())
+}
+
+/// Set a process's priority class: idle, below_normal, normal, above_normal or high
+#[tauri::command]
+fn set_process_priority(state: State<AppState>, pid: u32, priority: String) -> Result<(), String> {
+    // Realtime can starve input and the OS itself, so it is only ever reported, never set
+    let priority_class = PRIORITY_CLASSES
+        .iter()
+        .find(|(name, _)| *name == priority && *name != "realtime")
+        .map(|(_, value)| *value)
+        .ok_or_else(|| format!("Unknown priority: {}", priority))?;
+    ensure_not_protected(&state, pid)?;
+
+    write_priority_class(pid, priority_class)?;
+    log_info!("Set PID {} priority to {}", pid, priority);
+    Ok(())
+}
+
+// Process tuning snapshot - save priority/affinity/IO/memory/eco settings and put them back later
+#[derive(Serialize, Deserialize, Clone, Default)]
+struct TuningSnapshot {
+    priority_class: Option<u32>,
+    affinity_mask: Option<u64>,
+    io_priority: Option<u32>,
+    memory_priority: Option<u32>,
+    eco_mode: Option<bool>,
+}
+
+// Power throttling (eco mode) flags, see PROCESS_POWER_THROTTLING_STATE
+#[cfg(windows)]
+const POWER_THROTTLING_CURRENT_VERSION: u32 = 1;
+#[cfg(windows)]
+const POWER_THROTTLING_EXECUTION_SPEED: u32 = 1;
+
+/// Read every tuning knob of a process, fields that can't be read are None
+#[cfg(windows)]
+fn read_process_tuning(pid: u32) -> Result<TuningSnapshot, String> {
+    use windows::Win32::System::Threading::{
+        GetPriorityClass, GetProcessAffinityMask, GetProcessInformation, ProcessMemoryPriority,
+        ProcessPowerThrottling, MEMORY_PRIORITY_INFORMATION, PROCESS_POWER_THROTTLING_STATE,
+        PROCESS_QUERY_INFORMATION,
+    };
+
+    unsafe {
+        let handle = OpenProcess(PROCESS_QUERY_INFORMATION, false, pid).map_err(describe_process_error)?;
+        let mut snapshot = TuningSnapshot::default();
+
+        let priority_class = GetPriorityClass(handle);
+        if priority_class != 0 {
+            snapshot.priority_class = Some(priority_class);
+        }
+
+        let mut process_mask: usize = 0;
+        let mut system_mask: usize = 0;
+        if GetProcessAffinityMask(handle, &mut process_mask, &mut system_mask).is_ok() {
+            snapshot.affinity_mask = Some(process_mask as u64);
+        }
+
+        let mut io_priority: u32 = 0;
+        let status = ntdll::NtQueryInformationProcess(
+            handle,
+            ntdll::PROCESS_IO_PRIORITY,
+            &mut io_priority as *mut _ as *mut _,
+            std::mem::size_of::<u32>() as u32,
+            std::ptr::null_mut(),
+        );
+        if status >= 0 {
+            snapshot.io_priority = Some(io_priority);
+        }
+
+        let mut memory_priority = MEMORY_PRIORITY_INFORMATION::default();
+        if GetProcessInformation(
+            handle,
+            ProcessMemoryPriority,
+            &mut memory_priority as *mut _ as *mut _,
+            std::mem::size_of::<MEMORY_PRIORITY_INFORMATION>() as u32,
+        ).is_ok() {
+            snapshot.memory_priority = Some(memory_priority.MemoryPriority.0);
+        }
+
+        let mut throttling = PROCESS_POWER_THROTTLING_STATE {
+            Version: POWER_THROTTLING_CURRENT_VERSION,
+            ..Default::default()
+        };
+        if GetProcessInformation(
+            handle,
+            ProcessPowerThrottling,
+            &mut throttling as *mut _ as *mut _,
+            std::mem::size_of::<PROCESS_POWER_THROTTLING_STATE>() as u32,
+        ).is_ok() {
+            snapshot.eco_mode = Some(
+                throttling.ControlMask & POWER_THROTTLING_EXECUTION_SPEED != 0
+                    && throttling.StateMask & POWER_THROTTLING_EXECUTION_SPEED != 0,
+            );
+        }
+
+        let _ = CloseHandle(handle);
+        Ok(snapshot)
+    }
+}
+
+/// Apply every field that is Some, collecting the ones that failed
+#[cfg(windows)]
+fn apply_process_tuning(pid: u32, snapshot: &TuningSnapshot) -> Result<(), String> {
+    use windows::Win32::System::Threading::{
+        SetPriorityClass, SetProcessAffinityMask, SetProcessInformation, ProcessMemoryPriority,
+        ProcessPowerThrottling, MEMORY_PRIORITY, MEMORY_PRIORITY_INFORMATION, PROCESS_CREATION_FLAGS,
+        PROCESS_POWER_THROTTLING_STATE, PROCESS_SET_INFORMATION,
+    };
+
+    unsafe {
+        let handle = OpenProcess(PROCESS_SET_INFORMATION, false, pid).map_err(describe_process_error)?;
+        let mut failed: Vec<String> = Vec::new();
+
+        if let Some(priority_class) = snapshot.priority_class {
+            if let Err(e) = SetPriorityClass(handle, PROCESS_CREATION_FLAGS(priority_class)) {
+                failed.push(format!("priority: {}", describe_process_error(e)));
+            }
+        }
+
+        if let Some(mask) = snapshot.affinity_mask {
+            if let Err(e) = SetProcessAffinityMask(handle, mask as usize) {
+                failed.push(format!("affinity: {}", describe_process_error(e)));
+            }
+        }
+
+        if let Some(io_priority) = snapshot.io_priority {
+            let status = ntdll::NtSetInformationProcess(
+                handle,
+                ntdll::PROCESS_IO_PRIORITY,
+                &io_priority as *const _ as *const _,
+                std::mem::size_of::<u32>() as u32,
+            );
+            if status < 0 {
+                failed.push(format!("io priority: NTSTATUS 0x{:08X}", status));
+            }
+        }
+
+        if let Some(memory_priority) = snapshot.memory_priority {
+            let info = MEMORY_PRIORITY_INFORMATION {
+                MemoryPriority: MEMORY_PRIORITY(memory_priority),
+            };
+            if let Err(e) = SetProcessInformation(
+                handle,
+                ProcessMemoryPriority,
+                &info as *const _ as *const _,
+                std::mem::size_of::<MEMORY_PRIORITY_INFORMATION>() as u32,
+            ) {
+                failed.push(format!("memory priority: {}", describe_process_error(e)));
+            }
+        }
+
+        if let Some(eco_mode) = snapshot.eco_mode {
+            let throttling = PROCESS_POWER_THROTTLING_STATE {
+                Version: POWER_THROTTLING_CURRENT_VERSION,
+                ControlMask: POWER_THROTTLING_EXECUTION_SPEED,
+                StateMask: if eco_mode { POWER_THROTTLING_EXECUTION_SPEED } else { 0 },
+            };
+            if let Err(e) = SetProcessInformation(
+                handle,
+                ProcessPowerThrottling,
+                &throttling as *const _ as *const _,
+                std::mem::size_of::<PROCESS_POWER_THROTTLING_STATE>() as u32,
+            ) {
+                failed.push(format!("eco mode: {}", describe_process_error(e)));
+            }
+        }
+
+        let _ = CloseHandle(handle);
+
+        if failed.is_empty() {
+            Ok(())
+        } else {
+            Err(format!("Failed to restore {}", failed.join(", ")))
+        }
+    }
+}
+
+#[cfg(not(windows))]
+fn read_process_tuning(_pid: u32) -> Result<TuningSnapshot, String> {
+    Err("Not supported on this platform".to_string())
+}
+
+#[cfg(not(windows))]
+fn apply_process_tuning(_pid: u32, _snapshot: &TuningSnapshot) -> Result<(), String> {
+    Err("Not supported on this platform".to_string())
+}
+
+#[tauri::command]
+fn capture_process_tuning(pid: u32) -> Result<TuningSnapshot, String> {
+    read_process_tuning(pid)
+}
+
+/// Re-apply a snapshot from capture_process_tuning, None fields are skipped
+#[tauri::command]
+fn restore_process_tuning(state: State<AppState>, pid: u32, snapshot: TuningSnapshot) -> Result<(), String> {
+    ensure_not_protected(&state, pid)?;
+    apply_process_tuning(pid, &snapshot)
+}
+
+// Batch kill - terminate every instance of an app, reporting per-PID results
+#[derive(Serialize, Default)]
+struct KillResult {
+    killed: Vec<u32>,
+    failed: Vec<(u32, String)>,
+}
+
+#[cfg(windows)]
+fn terminate_process(pid: u32) -> Result<(), String> {
+    use windows::Win32::System::Threading::{TerminateProcess, PROCESS_TERMINATE};
+
+    unsafe {
+        let handle = OpenProcess(PROCESS_TERMINATE, false, pid).map_err(describe_process_error)?;
+        let result = TerminateProcess(handle, 1).map_err(describe_process_error);
+        let _ = CloseHandle(handle);
+        result
+    }
+}
+
+#[cfg(not(windows))]
+fn terminate_process(_pid: u32) -> Result<(), String> {
+    Err("Not supported on this platform".to_string())
+}
+
+/// Terminate one process. Errors are "Process not found", "protected process" or
+/// "Access denied (...)" so the frontend can tell them apart
+#[tauri::command]
+fn kill_process(state: State<AppState>, pid: u32) -> Result<(), String> {
+    let name = {
+        let mut system = state.system.lock().unwrap();
+        let pid_obj = Pid::from_u32(pid);
+        system.refresh_processes(sysinfo::ProcessesToUpdate::Some(&[pid_obj]), true);
+        system.process(pid_obj).map(canonical_name)
+    };
+    let Some(name) = name else { return Err("Process not found".to_string()) };
+
+    let user_protected = state.protected_processes.lock().unwrap().clone();
+    if is_protected_process(pid, &name, &user_protected) {
+        return Err("protected process".to_string());
+    }
+    // Can still fail with "Process not found" if it exits in between
+    terminate_process(pid)?;
+    log_info!("Killed {} (PID {})", name, pid);
+    Ok(())
+}
+
+/// Suspend a process until resume_process. Calling it again on an already suspended
+/// process is a no-op, so the OS suspend count never goes above one
+#[tauri::command]
+fn suspend_process(state: State<AppState>, pid: u32) -> Result<(), String> {
+    let create_time = {
+        let mut system = state.system.lock().unwrap();
+        let pid_obj = Pid::from_u32(pid);
+        system.refresh_processes(sysinfo::ProcessesToUpdate::Some(&[pid_obj]), true);
+        system.process(pid_obj).map(|p| p.start_time())
+    };
+    let Some(create_time) = create_time else { return Err("Process not found".to_string()) };
+    ensure_not_protected(&state, pid)?;
+
+    let mut suspended = state.manually_suspended.lock().unwrap();
+    // A recycled PID is a different process, drop the stale entry
+    if let Some(previous) = suspended.get(&pid).copied() {
+        if previous == create_time {
+            return Ok(());
+        }
+        suspended.remove(&pid);
+    }
+    // The throttle thread resumes capped processes every period and would undo the suspend
+    if state.cpu_caps.lock().unwrap().contains_key(&pid) {
+        return Err("Process has a CPU cap, remove it first".to_string());
+    }
+
+    set_threads_suspended(pid, true)?;
+    suspended.insert(pid, create_time);
+    log_info!("Suspended PID {}", pid);
+    Ok(())
+}
+
+/// Resume a process suspended with suspend_process. PIDs it never suspended
+/// (including ones held by game mode or rules) are left alone and return Ok
+#[tauri::command]
+fn resume_process(state: State<AppState>, pid: u32) -> Result<(), String> {
+    if state.manually_suspended.lock().unwrap().remove(&pid).is_none() {
+        return Ok(());
+    }
+    set_threads_suspended(pid, false)?;
+    log_info!("Resumed PID {}", pid);
+    Ok(())
+}
+
+/// Resume every process suspended with suspend_process
+fn release_manual_suspends(state: &AppState) {
+    let mut suspended = state.manually_suspended.lock().unwrap();
+    for pid in suspended.keys() {
+        let _ = set_threads_suspended(*pid, false);
+    }
+    suspended.clear();
+}
+
+/// Report processes we hold suspended (manually, by game mode or by a rule) as "Suspended"
+fn mark_suspended_processes(state: &AppState, processes: &mut [ProcessInfo]) {
+    let manual = state.manually_suspended.lock().unwrap();
+    let rules = state.rule_suspended.lock().unwrap();
+    let game_mode = state.game_mode_suspended.lock().unwrap();
+    for process in processes.iter_mut() {
+        let held = manual.get(&process.pid) == Some(&process.create_time)
+            || rules.get(&process.pid) == Some(&process.create_time)
+            || game_mode.as_ref().is_some_and(|pids| pids.contains(&process.pid));
+        if held {
+            process.status = "Suspended".to_string();
+        }
+    }
+}
+
+/// Terminate every process whose canonical name matches `name` (case and `.exe` ignored)
+#[tauri::command]
+fn kill_processes_by_name(state: State<AppState>, name: String) -> KillResult {
+    let matches: Vec<(u32, String)> = {
+        let mut system = state.system.lock().unwrap();
+        system.refresh_processes(sysinfo::ProcessesToUpdate::All, true);
+        system
+            .processes()
+            .iter()
+            .map(|(pid, process)| (pid.as_u32(), canonical_name(process)))
+            .filter(|(_, process_name)| same_process_name(process_name, &name))
+            .collect()
+    };
+
+    let user_protected = state.protected_processes.lock().unwrap().clone();
+    let mut result = KillResult::default();
+    for (pid, process_name) in matches {
+        if is_protected_process(pid, &process_name, &user_protected) {
+            result.failed.push((pid, "protected process".to_string()));
+            continue;
+        }
+        match terminate_process(pid) {
+            Ok(()) => result.killed.push(pid),
+            Err(e) => result.failed.push((pid, e)),
+        }
+    }
+    log_info!("Killed {} '{}' processes, {} failed", result.killed.len(), name, result.failed.len());
+    result
+}
+
+// Temporary boost - High priority on all cores for a while, then the previous
+// priority/affinity is restored by a watcher thread
+const HIGH_PRIORITY_CLASS_VALUE: u32 = 0x0000_0080;
+const BOOST_CHECK_MS: u64 = 500;
+const MAX_BOOST_SECS: u64 = 3600;
+
+struct ProcessBoost {
+    // Priority class and affinity captured before boosting
+    restore: TuningSnapshot,
+    until: Instant,
+}
+
+/// Watch one boosted process, restore its settings once the boost expires
+#[cfg(windows)]
+fn run_boost(app: tauri::AppHandle, pid: u32) {
+    use windows::Win32::System::Threading::PROCESS_QUERY_LIMITED_INFORMATION;
+
+    let state = app.state::<AppState>();
+    let handle = match unsafe { OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid) } {
+        Ok(handle) => handle,
+        Err(_) => {
+            state.boosts.lock().unwrap().remove(&pid);
+            return;
+        }
+    };
+
+    loop {
+        let alive = !process_exited(handle);
+
+        {
+            let mut boosts = state.boosts.lock().unwrap();
+            let Some(boost) = boosts.get(&pid) else { break };
+            if !alive {
+                boosts.remove(&pid);
+                break;
+            }
+            if Instant::now() >= boost.until {
+                if let Some(boost) = boosts.remove(&pid) {
+                    if let Err(e) = apply_process_tuning(pid, &boost.restore) {
+                        log_warn!("Boost restore for PID {} failed: {}", pid, e);
+                    }
+                }
+                break;
+            }
+        }
+        std::thread::sleep(std::time::Duration::from_millis(BOOST_CHECK_MS));
+    }
+
+    unsafe {
+        let _ = CloseHandle(handle);
+    }
+}
+
+/// Raise a process to High priority on all cores for `duration_secs`, then restore
+/// its previous priority/affinity. Boosting again extends the running boost
+#[tauri::command]
+#[cfg(windows)]
+fn boost_process(app: tauri::AppHandle, state: State<AppState>, pid: u32, duration_secs: u64) -> Result<(), String> {
+    use windows::Win32::System::Threading::{GetProcessAffinityMask, PROCESS_QUERY_LIMITED_INFORMATION};
+
+    if duration_secs == 0 || duration_secs > MAX_BOOST_SECS {
+        return Err(format!("duration_secs must be between 1 and {}", MAX_BOOST_SECS));
+    }
+    ensure_not_protected(&state, pid)?;
+    let until = Instant::now() + std::time::Duration::from_secs(duration_secs);
+
+    let mut boosts = state.boosts.lock().unwrap();
+    if let Some(boost) = boosts.get_mut(&pid) {
+        boost.until = until;
+        return Ok(());
+    }
+
+    let current = read_process_tuning(pid)?;
+    let restore = TuningSnapshot {
+        priority_class: current.priority_class,
+        affinity_mask: current.affinity_mask,
+        ..Default::default()
+    };
+
+    let mut system_mask: usize = 0;
+    unsafe {
+        let handle = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid).map_err(describe_process_error)?;
+        let mut process_mask: usize = 0;
+        let _ = GetProcessAffinityMask(handle, &mut process_mask, &mut system_mask);
+        let _ = CloseHandle(handle);
+    }
+    let boosted = TuningSnapshot {
+        priority_class: Some(HIGH_PRIORITY_CLASS_VALUE),
+        affinity_mask: (system_mask != 0).then_some(system_mask as u64),
+        ..Default::default()
+    };
+    if let Err(e) = apply_process_tuning(pid, &boosted) {
+        // Partially applied, put back what we can
+        let _ = apply_process_tuning(pid, &restore);
+        return Err(e.replace("Failed to restore", "Failed to boost"));
+    }
+
+    boosts.insert(pid, ProcessBoost { restore, until });
+    drop(boosts);
+
+    std::thread::spawn(move || run_boost(app, pid));
+    Ok(())
+}
+
+#[tauri::command]
+#[cfg(not(windows))]
+fn boost_process(_pid: u32, _duration_secs: u64) -> Result<(), String> {
+    Err("Not supported on this platform".to_string())
+}
+
+/// End every boost early and restore the original settings
+fn release_boosts(state: &AppState) {
+    let mut boosts = state.boosts.lock().unwrap();
+    for (pid, boost) in boosts.drain() {
+        let _ = apply_process_tuning(pid, &boost.restore);
+    }
+}
+
+// Support bundle - one zip with the data file, a system report and per-session CSVs
+const BUNDLE_DATA_ENTRY: &str = DATA_FILE_NAME;
+
+#[derive(Serialize)]
+struct SystemReport {
+    app_version: String,
+    os: Option<String>,
+    host_name: Option<String>,
+    generated_at: String,
+    stats: SystemStats,
+}
+
+fn session_history_csv(session: &SavedSession) -> String {
+    let mut csv = String::from("timestamp,cpu_percent,memory_mb,memory_percent,gpu_percent,user_activity_percent,is_foreground\n");
+    for snapshot in &session.performance_history {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{},{}\n",
+            snapshot.timestamp,
+            snapshot.cpu_percent,
+            snapshot.memory_mb,
+            snapshot.memory_percent,
+            snapshot.gpu_percent,
+            snapshot.user_activity_percent,
+            snapshot.is_foreground,
+        ));
+    }
+    csv
+}
+
+/// Write the app data, a system report and session CSVs into a zip at `dest_path`
+#[tauri::command]
+fn export_bundle(app: tauri::AppHandle, state: State<AppState>, dest_path: String) -> Result<(), String> {
+    use std::io::Write;
+    use zip::write::SimpleFileOptions;
+
+    let data = read_app_data(&get_data_file_path(&state))?;
+    let report = SystemReport {
+        app_version: app.package_info().version.to_string(),
+        os: System::long_os_version(),
+        host_name: System::host_name(),
+        generated_at: chrono::Local::now().to_rfc3339(),
+        stats: collect_system_stats(&state),
+    };
+
+    let file = fs::File::create(&dest_path).map_err(|e| e.to_string())?;
+    let mut zip = zip::ZipWriter::new(file);
+    let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+
+    let mut add_file = |name: &str, contents: &[u8]| -> Result<(), String> {
+        zip.start_file(name, options).map_err(|e| e.to_string())?;
+        zip.write_all(contents).map_err(|e| e.to_string())
+    };
+
+    let data_json = serde_json::to_string_pretty(&data).map_err(|e| e.to_string())?;
+    add_file(BUNDLE_DATA_ENTRY, data_json.as_bytes())?;
+
+    let report_json = serde_json::to_string_pretty(&report).map_err(|e| e.to_string())?;
+    add_file("system_report.json", report_json.as_bytes())?;
+
+    for session in &data.sessions {
+        let name = format!("sessions/session_{}.csv", session.id);
+        add_file(&name, session_history_csv(session).as_bytes())?;
+    }
+
+    zip.finish().map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Copy the data file to a timestamped .bak before it's replaced, None when there's no file yet.
+/// Callers hold AppState::data_file_lock
+fn back_up_data_file(data_file: &Path) -> Result<Option<PathBuf>, String> {
+    // No file yet means nothing to lose, anything else must be copied before it's replaced
+    if !data_file.exists() {
+        return Ok(None);
+    }
+    let backup = data_file.with_file_name(format!(
+        "{}.{}.bak",
+        DATA_FILE_NAME,
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    ));
+    fs::copy(data_file, &backup).map_err(|e| format!("Failed to back up {}: {}", data_file.display(), e))?;
+    Ok(Some(backup))
+}
+
+/// Load the backend-owned settings and in-memory state from `data` after the data file was
+/// replaced wholesale (import, reset), stopping or restarting the background workers to match
+fn apply_app_data(app: &tauri::AppHandle, state: &AppState, data: &AppData) {
+    *state.category_rules.lock().unwrap() = data.category_rules.clone();
+    *state.protected_processes.lock().unwrap() = data.protected_processes.clone();
+    *state.memory_metric.lock().unwrap() = data.memory_metric;
+    *state.memory_units.lock().unwrap() = data.memory_units;
+    *state.process_rules.lock().unwrap() = data.process_rules.clone();
+    *state.max_list_length.lock().unwrap() = data.max_list_length;
+    *state.self_cpu_budget.lock().unwrap() = SelfCpuBudget {
+        max_percent: data.self_cpu_budget.filter(|p| *p > 0.0 && *p <= 100.0),
+        interval_ms: DEFAULT_POLL_INTERVAL_MS,
+        last_sample: None,
+    };
+    {
+        let mut first_seen = state.first_seen.lock().unwrap();
+        first_seen.paths = data.first_seen.clone();
+        first_seen.baseline = data.first_seen_baseline.clone();
+        first_seen.dirty = false;
+    }
+    reload_daily_activity(state, &data.daily_activity);
+    *state.display_average.lock().unwrap() = DisplayAverage {
+        window: data.display_average_window.unwrap_or(1).clamp(1, MAX_DISPLAY_AVERAGE_WINDOW),
+        samples: HashMap::new(),
+    };
+    *state.cpu_anomaly.lock().unwrap() = CpuAnomalyDetector {
+        k: data.anomaly_sensitivity.unwrap_or(DEFAULT_ANOMALY_SENSITIVITY),
+        stats: HashMap::new(),
+    };
+
+    *ACTIVITY_APP_FILTER.lock().unwrap() = data.activity_app_filter.clone();
+    logger::set_level(data.log_level.as_deref().and_then(logger::Level::parse).unwrap_or(logger::Level::Info));
+    MOUSE_DPI_SCALE.store(data.screen_scale.unwrap_or_else(detect_screen_scale).to_bits(), Ordering::SeqCst);
+    METRIC_PRECISION.store(data.metric_precision.unwrap_or(1).min(MAX_METRIC_PRECISION), Ordering::SeqCst);
+    TRAY_LOAD_ICON.store(data.tray_load_icon, Ordering::SeqCst);
+    EXCLUDE_OWN_INPUT.store(data.exclude_own_input.unwrap_or(true), Ordering::SeqCst);
+    CRASH_REPORTS.store(data.crash_reports, Ordering::SeqCst);
+    RESUME_SESSIONS.store(data.resume_sessions, Ordering::SeqCst);
+    let alpha = data.gpu_smoothing_alpha.filter(|a| *a > 0.0 && *a <= 1.0).unwrap_or(DEFAULT_GPU_SMOOTHING_ALPHA);
+    GPU_SMOOTHING_ALPHA.store(alpha.to_bits(), Ordering::SeqCst);
+
+    apply_auto_track(
+        app,
+        state,
+        data.auto_track_foreground
+            .then(|| data.auto_track_grace_secs.unwrap_or(DEFAULT_AUTO_TRACK_GRACE_SECS)),
+    );
+    apply_auto_optimize(
+        app,
+        state,
+        data.auto_optimize_on_idle
+            .then(|| data.auto_optimize_idle_secs.unwrap_or(DEFAULT_AUTO_OPTIMIZE_IDLE_SECS)),
+    );
+
+    let mut watched = state.watched_directories.lock().unwrap();
+    // The watch thread is already running when something was watched, it picks up the new list
+    let start_watch = watched.is_empty() && !data.watched_directories.is_empty();
+    *watched = data.watched_directories.clone();
+    drop(watched);
+    if start_watch {
+        let app = app.clone();
+        std::thread::spawn(move || run_whitelist_watch(app));
+    }
+
+    stop_stats_server(state);
+    if let Some(port) = data.stats_server_port {
+        let config = StatsServerConfig {
+            port,
+            lan: data.stats_server_lan,
+            token: data.stats_server_token.clone(),
+        };
+        if let Err(e) = start_stats_server(app, state, config) {
+            log_error!("{}", e);
+        }
+    }
+}
+
+/// Restore the app data from a bundle written by export_bundle, the current data file is
+/// kept as a timestamped .bak. Returns the restored data so the frontend can reload its state
+#[tauri::command]
+fn import_bundle(app: tauri::AppHandle, state: State<AppState>, src_path: String) -> Result<AppData, String> {
+    use std::io::Read;
+
+    let file = fs::File::open(&src_path).map_err(|e| e.to_string())?;
+    let mut archive = zip::ZipArchive::new(file).map_err(|e| e.to_string())?;
+
+    let mut content = String::new();
+    archive
+        .by_name(BUNDLE_DATA_ENTRY)
+        .map_err(|_| "Bundle does not contain app data".to_string())?
+        .read_to_string(&mut content)
+        .map_err(|e| e.to_string())?;
+
+    // Parse before writing so a bad bundle never replaces good data
+    let mut data: AppData = serde_json::from_str(&content).map_err(|e| e.to_string())?;
+    let fixes = sanitize_app_data(&mut data);
+    if !fixes.is_empty() {
+        log_warn!("Repaired imported {}: {}", src_path, fixes.join(", "));
+    }
+    let data_file = get_data_file_path(&state);
+    let backup = {
+        let _lock = state.data_file_lock.lock().unwrap();
+        let backup = back_up_data_file(&data_file)?;
+        write_app_data(&data_file, &data)?;
+        backup
+    };
+
+    apply_app_data(&app, &state, &data);
+    match backup {
+        Some(backup) => log_info!("Imported {}, previous data saved to {}", src_path, backup.display()),
+        None => log_info!("Imported {}", src_path),
+    }
+    Ok(data)
+}
+
+/// Back up the data file to a timestamped .bak, then start over with defaults
+/// Emits app-data-reset so the frontend drops its whitelist/sessions instead of saving them back
+#[tauri::command]
+fn reset_all_data(app: tauri::AppHandle, state: State<AppState>) -> Result<(), String> {
+    let data_file = get_data_file_path(&state);
+    let backup = {
+        let _lock = state.data_file_lock.lock().unwrap();
+        let backup = back_up_data_file(&data_file)?;
+        write_app_data(&data_file, &AppData::default())?;
+        backup
+    };
+
+    // Put back anything held on other processes, then drop every setting and worker
+    release_process_holds(&state);
+    apply_app_data(&app, &state, &AppData::default());
+    state.named_snapshots.lock().unwrap().clear();
+    state.memory_history.lock().unwrap().clear();
+
+    match backup {
+        Some(backup) => log_info!("All data reset, previous data saved to {}", backup.display()),
+        None => log_info!("All data reset"),
+    }
+    let _ = app.emit("app-data-reset", ());
+    Ok(())
+}
+
+// OS performance settings - Hardware-accelerated GPU scheduling and Game Mode
+#[cfg(windows)]
+const GRAPHICS_DRIVERS_KEY: &str = "SYSTEM\\CurrentControlSet\\Control\\GraphicsDrivers";
+#[cfg(windows)]
+const GAME_BAR_KEY: &str = "Software\\Microsoft\\GameBar";
+
+#[derive(Serialize)]
+struct OsSettingChange {
+    // Windows only picks the new value up after a restart
+    reboot_required: bool,
+}
+
+/// HAGS state (HwSchMode 2 = on, 1 = off), None when the driver doesn't expose it
+#[tauri::command]
+#[cfg(windows)]
+fn get_hardware_gpu_scheduling() -> Option<bool> {
+    use windows::Win32::System::Registry::HKEY_LOCAL_MACHINE;
+
+    let mode = registry::value(HKEY_LOCAL_MACHINE, GRAPHICS_DRIVERS_KEY, "HwSchMode")
+        .as_deref()
+        .and_then(registry::dword)?;
+    Some(mode == 2)
+}
+
+/// Turn HAGS on/off (needs elevation, takes effect after a reboot)
+#[tauri::command]
+#[cfg(windows)]
+fn set_hardware_gpu_scheduling(enabled: bool) -> Result<OsSettingChange, String> {
+    use windows::Win32::System::Registry::{HKEY_LOCAL_MACHINE, REG_DWORD};
+
+    let mode: u32 = if enabled { 2 } else { 1 };
+    registry::set_value(HKEY_LOCAL_MACHINE, GRAPHICS_DRIVERS_KEY, "HwSchMode", REG_DWORD, &mode.to_le_bytes())?;
+    log_info!("Hardware GPU scheduling set to {}, reboot required", enabled);
+    Ok(OsSettingChange { reboot_required: true })
+}
+
+/// Windows Game Mode, on when AutoGameModeEnabled is missing (the Windows default)
+#[tauri::command]
+#[cfg(windows)]
+fn get_game_mode_enabled() -> Option<bool> {
+    use windows::Win32::System::Registry::HKEY_CURRENT_USER;
+
+    match registry::value(HKEY_CURRENT_USER, GAME_BAR_KEY, "AutoGameModeEnabled") {
+        Some(data) => registry::dword(&data).map(|value| value != 0),
+        None => Some(true),
+    }
+}
+
+#[tauri::command]
+#[cfg(windows)]
+fn set_game_mode_enabled(enabled: bool) -> Result<OsSettingChange, String> {
+    use windows::Win32::System::Registry::{HKEY_CURRENT_USER, REG_DWORD};
+
+    let value = enabled as u32;
+    registry::set_value(HKEY_CURRENT_USER, GAME_BAR_KEY, "AutoGameModeEnabled", REG_DWORD, &value.to_le_bytes())?;
+    Ok(OsSettingChange { reboot_required: false })
+}
+
+#[tauri::command]
+#[cfg(not(windows))]
+fn get_hardware_gpu_scheduling() -> Option<bool> {
+    None
+}
+
+#[tauri::command]
+#[cfg(not(windows))]
+fn set_hardware_gpu_scheduling(_enabled: bool) -> Result<OsSettingChange, String> {
+    Err("Not supported on this platform".to_string())
+}
+
+#[tauri::command]
+#[cfg(not(windows))]
+fn get_game_mode_enabled() -> Option<bool> {
+    None
+}
+
+#[tauri::command]
+#[cfg(not(windows))]
+fn set_game_mode_enabled(_enabled: bool) -> Result<OsSettingChange, String> {
+    Err("Not supported on this platform".to_string())
+}
+
+// Mic/camera usage - Windows records capability use per app in the consent store;
+// an entry with a start time and no stop time is using the device right now
+#[cfg(windows)]
+const CONSENT_STORE_KEY: &str = "Software\\Microsoft\\Windows\\CurrentVersion\\CapabilityAccessManager\\ConsentStore";
+#[cfg(windows)]
+const MEDIA_CAPABILITIES: [&str; 2] = ["microphone", "webcam"];
+
+#[derive(Serialize)]
+struct MediaUsage {
+    // "microphone" or "webcam"
+    device: String,
+    // Exe path for desktop apps, package family name for Store apps
+    app: String,
+    packaged: bool,
+    // Running processes belonging to the app
+    pids: Vec<u32>,
+    since: Option<String>,
+}
+
+/// Convert a FILETIME (100ns since 1601) to RFC 3339 local time
+#[cfg(windows)]
+fn filetime_to_rfc3339(filetime: u64) -> Option<String> {
+    const UNIX_EPOCH_OFFSET_SECS: i64 = 11_644_473_600;
+    let secs = (filetime / 10_000_000) as i64 - UNIX_EPOCH_OFFSET_SECS;
+    let nanos = (filetime % 10_000_000) as u32 * 100;
+    chrono::DateTime::from_timestamp(secs, nanos).map(|time| time.with_timezone(&chrono::Local).to_rfc3339())
+}
+
+/// Start time if the consent entry at `path` is in use right now
+#[cfg(windows)]
+fn media_in_use_since(path: &str) -> Option<Option<String>> {
+    use windows::Win32::System::Registry::HKEY_CURRENT_USER;
+
+    let read = |name| registry::value(HKEY_CURRENT_USER, path, name).as_deref().and_then(registry::qword);
+    let start = read("LastUsedTimeStart").filter(|&t| t != 0)?;
+    if read("LastUsedTimeStop").is_some_and(|t| t != 0) {
+        return None;
+    }
+    Some(filetime_to_rfc3339(start))
+}
+
+/// Apps currently using the microphone or camera, with their running PIDs
+#[tauri::command]
+#[cfg(windows)]
+fn get_media_device_usage(state: State<AppState>) -> Vec<MediaUsage> {
+    use windows::Win32::System::Registry::HKEY_CURRENT_USER;
+
+    let mut usage = Vec::new();
+    for device in MEDIA_CAPABILITIES {
+        let device_key = format!("{}\\{}", CONSENT_STORE_KEY, device);
+
+        // Store apps: subkeys named by package family name
+        for package in registry::subkeys(HKEY_CURRENT_USER, &device_key) {
+            if package.eq_ignore_ascii_case("NonPackaged") {
+                continue;
+            }
+            if let Some(since) = media_in_use_since(&format!("{}\\{}", device_key, package)) {
+                usage.push(MediaUsage { device: device.to_string(), app: package, packaged: true, pids: Vec::new(), since });
+            }
+        }
+
+        // Desktop apps: subkeys are exe paths with '#' for '\'
+        let non_packaged_key = format!("{}\\NonPackaged", device_key);
+        for entry in registry::subkeys(HKEY_CURRENT_USER, &non_packaged_key) {
+            if let Some(since) = media_in_use_since(&format!("{}\\{}", non_packaged_key, entry)) {
+                let app = entry.replace('#', "\\");
+                usage.push(MediaUsage { device: device.to_string(), app, packaged: false, pids: Vec::new(), since });
+            }
+        }
+    }
+    if usage.is_empty() {
+        return usage;
+    }
+
+    let mut system = state.system.lock().unwrap();
+    // Exe only - refreshing CPU here would skew the next poll's usage delta
+    system.refresh_processes_specifics(
+        sysinfo::ProcessesToUpdate::All,
+        true,
+        sysinfo::ProcessRefreshKind::new().with_exe(sysinfo::UpdateKind::OnlyIfNotSet),
+    );
+    for entry in usage.iter_mut() {
+        // "Name_PublisherId" -> installed under WindowsApps\Name_<version>_<arch>__PublisherId
+        let package = entry.app.to_lowercase();
+        let package_parts = package.rsplit_once('_');
+        for (pid, process) in system.processes() {
+            let Some(exe) = process.exe().map(|p| p.to_string_lossy().to_lowercase()) else { continue };
+            let matches = if entry.packaged {
+                package_parts.is_some_and(|(name, publisher)| {
+                    exe.contains(&format!("\\windowsapps\\{}_", name)) && exe.contains(&format!("__{}\\", publisher))
+                })
+            } else {
+                exe.eq_ignore_ascii_case(&entry.app)
+            };
+            if matches {
+                entry.pids.push(pid.as_u32());
+            }
+        }
+        entry.pids.sort_unstable();
+    }
+    usage
 }
 
-#[derive(Serialize, Deserialize, Clone)]
-struct SavedWhitelistEntry {
-    id: i64,
+#[tauri::command]
+#[cfg(not(windows))]
+fn get_media_device_usage() -> Vec<MediaUsage> {
+    Vec::new()
+}
+
+// Startup programs - Run keys and Startup folders, enabled state lives in
+// Explorer's StartupApproved keys (same mechanism Task Manager uses)
+#[derive(Serialize)]
+struct StartupEntry {
+    // Run value name or Startup folder file name, also the StartupApproved value name
     name: String,
-    exe_path: Option<String>,
-    added_date: String,
-    is_tracked: bool,
+    command: String,
+    location: String,
+    enabled: bool,
+}
+
+#[cfg(windows)]
+const STARTUP_APPROVED_KEY: &str = "Software\\Microsoft\\Windows\\CurrentVersion\\Explorer\\StartupApproved";
+
+/// An entry plus where its StartupApproved flag lives
+#[cfg(windows)]
+struct StartupSource {
+    entry: StartupEntry,
+    approved_root: windows::Win32::System::Registry::HKEY,
+    approved_key: String,
+}
+
+#[cfg(windows)]
+fn collect_startup_sources() -> Vec<StartupSource> {
+    use windows::Win32::System::Registry::{HKEY, HKEY_CURRENT_USER, HKEY_LOCAL_MACHINE};
+
+    const RUN_KEY: &str = "Software\\Microsoft\\Windows\\CurrentVersion\\Run";
+    // 32-bit programs register here on 64-bit Windows, approved flags go to Run32
+    const RUN32_KEY: &str = "Software\\WOW6432Node\\Microsoft\\Windows\\CurrentVersion\\Run";
+    const STARTUP_FOLDER: &str = "Microsoft\\Windows\\Start Menu\\Programs\\Startup";
+
+    // Approved flags: missing or even first byte = enabled, odd = disabled
+    let is_enabled = |root: HKEY, key: &str, name: &str| {
+        registry::value(root, key, name)
+            .and_then(|data| data.first().copied())
+            .map(|flag| flag % 2 == 0)
+            .unwrap_or(true)
+    };
+
+    let mut sources = Vec::new();
+
+    for (root, run_key, approved, location) in [
+        (HKEY_CURRENT_USER, RUN_KEY, "Run", "HKCU Run"),
+        (HKEY_LOCAL_MACHINE, RUN_KEY, "Run", "HKLM Run"),
+        (HKEY_LOCAL_MACHINE, RUN32_KEY, "Run32", "HKLM Run (32-bit)"),
+    ] {
+        let approved_key = format!("{}\\{}", STARTUP_APPROVED_KEY, approved);
+        for value in registry::values(root, run_key) {
+            sources.push(StartupSource {
+                entry: StartupEntry {
+                    enabled: is_enabled(root, &approved_key, &value.name),
+                    command: registry::string(&value.data),
+                    name: value.name,
+                    location: location.to_string(),
+                },
+                approved_root: root,
+                approved_key: approved_key.clone(),
+            });
+        }
+    }
+
+    for (root, env_var, location) in [
+        (HKEY_CURRENT_USER, "APPDATA", "Startup folder"),
+        (HKEY_LOCAL_MACHINE, "PROGRAMDATA", "Common startup folder"),
+    ] {
+        let Ok(base) = std::env::var(env_var) else { continue };
+        let Ok(entries) = fs::read_dir(PathBuf::from(base).join(STARTUP_FOLDER)) else { continue };
+        let approved_key = format!("{}\\StartupFolder", STARTUP_APPROVED_KEY);
+
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if name.eq_ignore_ascii_case("desktop.ini") {
+                continue;
+            }
+            sources.push(StartupSource {
+                entry: StartupEntry {
+                    enabled: is_enabled(root, &approved_key, &name),
+                    command: entry.path().to_string_lossy().to_string(),
+                    name,
+                    location: location.to_string(),
+                },
+                approved_root: root,
+                approved_key: approved_key.clone(),
+            });
+        }
+    }
+
+    sources
+}
+
+/// List programs launched at logon from the Run keys and Startup folders
+#[tauri::command]
+#[cfg(windows)]
+fn get_startup_programs() -> Vec<StartupEntry> {
+    collect_startup_sources().into_iter().map(|source| source.entry).collect()
+}
+
+#[tauri::command]
+#[cfg(not(windows))]
+fn get_startup_programs() -> Vec<StartupEntry> {
+    Vec::new()
+}
+
+/// Enable/disable a startup entry by writing its StartupApproved flag,
+/// the entry itself is left in place so it can be re-enabled. The same value name can
+/// be in several Run keys/folders, so the entry is picked by location and value name
+#[tauri::command]
+#[cfg(windows)]
+fn set_startup_program_enabled(location: String, name: String, enabled: bool) -> Result<(), String> {
+    use windows::Win32::System::Registry::REG_BINARY;
+
+    let source = collect_startup_sources()
+        .into_iter()
+        .find(|source| source.entry.location == location && source.entry.name.eq_ignore_ascii_case(&name))
+        .ok_or("Startup entry not found")?;
+
+    // 4-byte flag followed by a FILETIME of when it was disabled
+    let mut flag = [0u8; 12];
+    if enabled {
+        flag[0] = 0x02;
+    } else {
+        flag[0] = 0x03;
+        let unix_100ns = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0) / 100;
+        // FILETIME counts 100ns intervals since 1601-01-01
+        let filetime = (unix_100ns + 116_444_736_000_000_000) as u64;
+        flag[4..].copy_from_slice(&filetime.to_le_bytes());
+    }
+
+    registry::set_value(source.approved_root, &source.approved_key, &source.entry.name, REG_BINARY, &flag)
+}
+
+#[tauri::command]
+#[cfg(not(windows))]
+fn set_startup_program_enabled(_location: String, _name: String, _enabled: bool) -> Result<(), String> {
+    Err("Not supported on this platform".to_string())
+}
+
+// Scheduled tasks - walked through the Task Scheduler COM API, the other common
+// source of background launches next to the startup programs above
+#[derive(Serialize)]
+struct ScheduledTask {
+    name: String,
+    // Full task path, e.g. "\Microsoft\Windows\Defrag\ScheduledDefrag"
+    path: String,
+    // Local time, None when no run is scheduled
+    next_run: Option<String>,
+    enabled: bool,
+    // Programs the task starts ("path arguments"), "; "-separated
+    action: String,
+}
+
+/// Keeps COM initialized on this thread while alive
+#[cfg(windows)]
+struct ComGuard {
+    initialized: bool,
+}
+
+#[cfg(windows)]
+impl ComGuard {
+    fn new() -> ComGuard {
+        use windows::Win32::System::Com::{CoInitializeEx, COINIT_MULTITHREADED};
+        // Fails with RPC_E_CHANGED_MODE when the thread already chose an apartment, which still works
+        let initialized = unsafe { CoInitializeEx(None, COINIT_MULTITHREADED) }.is_ok();
+        ComGuard { initialized }
+    }
+}
+
+#[cfg(windows)]
+impl Drop for ComGuard {
+    fn drop(&mut self) {
+        if self.initialized {
+            unsafe { windows::Win32::System::Com::CoUninitialize() };
+        }
+    }
+}
+
+#[cfg(windows)]
+fn connect_task_service() -> Result<windows::Win32::System::TaskScheduler::ITaskService, String> {
+    use windows::core::VARIANT;
+    use windows::Win32::System::Com::{CoCreateInstance, CLSCTX_INPROC_SERVER};
+    use windows::Win32::System::TaskScheduler::{ITaskService, TaskScheduler};
+
+    unsafe {
+        let service: ITaskService = CoCreateInstance(&TaskScheduler, None, CLSCTX_INPROC_SERVER)
+            .map_err(|e| format!("Task Scheduler unavailable: {}", e))?;
+        let local = VARIANT::default();
+        service
+            .Connect(&local, &local, &local, &local)
+            .map_err(|e| format!("Failed to connect to Task Scheduler: {}", e))?;
+        Ok(service)
+    }
+}
+
+/// OLE automation date (days since 1899-12-30, local time) as RFC 3339
+#[cfg(windows)]
+fn ole_date_to_rfc3339(date: f64) -> Option<String> {
+    use chrono::TimeZone;
+
+    if date <= 0.0 {
+        return None;
+    }
+    let epoch = chrono::NaiveDate::from_ymd_opt(1899, 12, 30)?.and_hms_opt(0, 0, 0)?;
+    let local = epoch + chrono::Duration::milliseconds((date * 86_400_000.0).round() as i64);
+    chrono::Local.from_local_datetime(&local).earliest().map(|time| time.to_rfc3339())
+}
+
+/// The exec actions of a task as "path arguments", "; "-separated
+#[cfg(windows)]
+fn task_actions(task: &windows::Win32::System::TaskScheduler::IRegisteredTask) -> String {
+    use windows::core::Interface;
+    use windows::Win32::System::TaskScheduler::{IExecAction, TASK_ACTION_EXEC};
+
+    let mut commands = Vec::new();
+    unsafe {
+        let Ok(actions) = task.Definition().and_then(|definition| definition.Actions()) else { return String::new() };
+        let count = actions.Count().unwrap_or(0);
+        // Task Scheduler collections are 1-based
+        for index in 1..=count {
+            let Ok(action) = actions.get_Item(index) else { continue };
+            if action.Type().ok() != Some(TASK_ACTION_EXEC) {
+                continue;
+            }
+            let Ok(exec) = action.cast::<IExecAction>() else { continue };
+            let path = exec.Path().map(|p| p.to_string()).unwrap_or_default();
+            let arguments = exec.Arguments().map(|a| a.to_string()).unwrap_or_default();
+            commands.push(if arguments.is_empty() { path } else { format!("{} {}", path, arguments) });
+        }
+    }
+    commands.join("; ")
+}
+
+/// Append the tasks of `folder` and all of its subfolders
+#[cfg(windows)]
+fn collect_scheduled_tasks(folder: &windows::Win32::System::TaskScheduler::ITaskFolder, tasks: &mut Vec<ScheduledTask>) {
+    use windows::core::VARIANT;
+    use windows::Win32::System::TaskScheduler::TASK_ENUM_HIDDEN;
+
+    unsafe {
+        if let Ok(registered) = folder.GetTasks(TASK_ENUM_HIDDEN.0) {
+            for index in 1..=registered.Count().unwrap_or(0) {
+                let Ok(task) = registered.get_Item(&VARIANT::from(index)) else { continue };
+                tasks.push(ScheduledTask {
+                    name: task.Name().map(|n| n.to_string()).unwrap_or_default(),
+                    path: task.Path().map(|p| p.to_string()).unwrap_or_default(),
+                    next_run: task.NextRunTime().ok().and_then(ole_date_to_rfc3339),
+                    enabled: task.Enabled().map(|e| e.as_bool()).unwrap_or(false),
+                    action: task_actions(&task),
+                });
+            }
+        }
+        if let Ok(subfolders) = folder.GetFolders(0) {
+            for index in 1..=subfolders.Count().unwrap_or(0) {
+                if let Ok(subfolder) = subfolders.get_Item(&VARIANT::from(index)) {
+                    collect_scheduled_tasks(&subfolder, tasks);
+                }
+            }
+        }
+    }
+}
+
+/// Every scheduled task, hidden ones included, sorted by path
+#[tauri::command]
+#[cfg(windows)]
+fn get_scheduled_tasks() -> Result<Vec<ScheduledTask>, String> {
+    use windows::core::BSTR;
+
+    let _com = ComGuard::new();
+    let service = connect_task_service()?;
+    let root = unsafe { service.GetFolder(&BSTR::from("\\")) }.map_err(|e| e.to_string())?;
+
+    let mut tasks = Vec::new();
+    collect_scheduled_tasks(&root, &mut tasks);
+    tasks.sort_by(|a, b| a.path.to_lowercase().cmp(&b.path.to_lowercase()));
+    Ok(tasks)
+}
+
+#[tauri::command]
+#[cfg(not(windows))]
+fn get_scheduled_tasks() -> Result<Vec<ScheduledTask>, String> {
+    Err("Not supported on this platform".to_string())
+}
+
+/// Enable/disable the task at `path`; tasks outside the user's own usually need admin rights
+#[tauri::command]
+#[cfg(windows)]
+fn set_task_enabled(path: String, enabled: bool) -> Result<(), String> {
+    use windows::core::BSTR;
+
+    let _com = ComGuard::new();
+    let service = connect_task_service()?;
+    unsafe {
+        let root = service.GetFolder(&BSTR::from("\\")).map_err(|e| e.to_string())?;
+        let task = root
+            .GetTask(&BSTR::from(path.as_str()))
+            .map_err(|e| format!("Task {} not found: {}", path, e))?;
+        task.SetEnabled(enabled.into())
+            .map_err(|e| format!("Failed to change {}: {}", path, e))?;
+    }
+    log_info!("Scheduled task {} {}", path, if enabled { "enabled" } else { "disabled" });
+    Ok(())
+}
+
+#[tauri::command]
+#[cfg(not(windows))]
+fn set_task_enabled(_path: String, _enabled: bool) -> Result<(), String> {
+    Err("Not supported on this platform".to_string())
+}
+
+// Autostart commands
+#[tauri::command]
+fn get_autostart_enabled(app: tauri::AppHandle) -> bool {
+    use tauri_plugin_autostart::ManagerExt;
+    app.autolaunch().is_enabled().unwrap_or(false)
+}
+
+#[tauri::command]
+fn set_autostart_enabled(app: tauri::AppHandle, enabled: bool) -> Result<(), String> {
+    use tauri_plugin_autostart::ManagerExt;
+    let manager = app.autolaunch();
+    if enabled {
+        manager.enable().map_err(|e| e.to_string())
+    } else {
+        manager.disable().map_err(|e| e.to_string())
+    }
+}
+
+// Splash toggle - read from a small bootstrap file at the start of setup,
+// before the main data file and AppState exist
+const BOOTSTRAP_FILE_NAME: &str = "bootstrap.json";
+static SPLASH_ENABLED: AtomicBool = AtomicBool::new(true);
+
+#[derive(Serialize, Deserialize)]
+struct BootstrapConfig {
+    #[serde(default = "default_splash_enabled")]
+    splash_enabled: bool,
+}
+
+fn default_splash_enabled() -> bool {
+    true
+}
+
+fn read_bootstrap_config(data_path: &Path) -> BootstrapConfig {
+    fs::read_to_string(data_path.join(BOOTSTRAP_FILE_NAME))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or(BootstrapConfig { splash_enabled: true })
+}
+
+fn show_main_window(app: &tauri::AppHandle) -> Result<(), String> {
+    if let Some(main) = app.get_webview_window("main") {
+        main.show().map_err(|e| e.to_string())?;
+        main.set_focus().map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+#[tauri::command]
+fn get_splash_enabled() -> bool {
+    SPLASH_ENABLED.load(Ordering::SeqCst)
+}
+
+/// Turn the splash screen on/off - takes effect on next launch
+#[tauri::command]
+fn set_splash_enabled(state: State<AppState>, enabled: bool) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(&BootstrapConfig { splash_enabled: enabled })
+        .map_err(|e| e.to_string())?;
+    // Nothing may have been saved yet on a fresh install
+    fs::create_dir_all(&state.data_path).map_err(|e| e.to_string())?;
+    fs::write(state.data_path.join(BOOTSTRAP_FILE_NAME), json).map_err(|e| e.to_string())?;
+    SPLASH_ENABLED.store(enabled, Ordering::SeqCst);
+    Ok(())
+}
+
+// Emits event to splash window when main app is ready
+#[tauri::command]
+async fn signal_app_ready(app: tauri::AppHandle) -> Result<(), String> {
+    // Without a splash nobody calls close_splash_show_main, start the panels directly
+    if !SPLASH_ENABLED.load(Ordering::SeqCst) {
+        return close_splash_show_main(app).await;
+    }
+    // Emit global event that splash window can listen to
+    app.emit("app-ready", ()).map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+// Shows splash window when DOM is ready
+#[tauri::command]
+async fn show_splash_window(app: tauri::AppHandle) -> Result<(), String> {
+    if !SPLASH_ENABLED.load(Ordering::SeqCst) {
+        return show_main_window(&app);
+    }
+    if let Some(splash) = app.get_webview_window("splashscreen") {
+        splash.show().map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+// Closes splash and shows main window (called after animation completes)
+#[tauri::command]
+async fn close_splash_show_main(app: tauri::AppHandle) -> Result<(), String> {
+    // Show main window BEFORE closing splash
+    show_main_window(&app)?;
+    // Close splash
+    if let Some(splash) = app.get_webview_window("splashscreen") {
+        splash.close().map_err(|e| e.to_string())?;
+    }
+    // Trigger panel animation in main window after it's shown
+    app.emit("trigger-panel-animation", ()).map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+// LAN stats server and remote monitoring - a read-only HTTP endpoint serving the
+// latest /stats and /processes, and a client that polls other instances' endpoints.
+// Both sides only talk to loopback/private addresses. The server binds loopback unless
+// LAN access is turned on, which requires a bearer token from every non-loopback client
+const DEFAULT_STATS_SERVER_PORT: u16 = 47800;
+const STATS_SERVER_ACCEPT_POLL_MS: u64 = 200;
+const MIN_STATS_TOKEN_LEN: usize = 16;
+// Clients served at once, each on its own thread; more are turned away with 503
+const MAX_STATS_CLIENTS: u32 = 8;
+static STATS_CLIENTS: AtomicU32 = AtomicU32::new(0);
+const STATS_HTTP_TIMEOUT_MS: u64 = 3000;
+const MAX_STATS_HTTP_RESPONSE_BYTES: usize = 16 * 1024 * 1024;
+const REMOTE_POLL_MS: u64 = 2000;
+
+#[derive(Clone)]
+struct StatsServerConfig {
+    port: u16,
+    lan: bool,
+    token: Option<String>,
+}
+
+#[derive(Default)]
+struct StatsServer {
+    config: Option<StatsServerConfig>,
+    // Bumped on every start/stop, an accept loop from an older generation exits
+    generation: u64,
+    // Joined on stop, so the listener is closed before the port is bound again
+    thread: Option<std::thread::JoinHandle<()>>,
+}
+
+#[derive(Default)]
+struct StatsCache {
+    stats_json: Option<String>,
+    processes_json: Option<String>,
+}
+
+struct RemoteSource {
+    url: String,
+    // Sent as a bearer token, needed for servers with LAN access on
+    token: Option<String>,
+    // The poller of an older connect for the same source exits
+    generation: u64,
+    stats: Option<SystemStats>,
+    processes: Vec<ProcessInfo>,
+    last_update: Option<String>,
+    last_error: Option<String>,
+}
+
+#[derive(Serialize)]
+struct RemoteSourceInfo {
+    source_id: String,
+    url: String,
+    connected: bool,
+    last_update: Option<String>,
+    last_error: Option<String>,
+}
+
+fn is_lan_address(ip: std::net::IpAddr) -> bool {
+    match ip {
+        std::net::IpAddr::V4(v4) => v4.is_loopback() || v4.is_private() || v4.is_link_local(),
+        std::net::IpAddr::V6(v6) => {
+            let first = v6.segments()[0];
+            // Unique local fc00::/7 and link-local fe80::/10
+            v6.is_loopback() || (first & 0xfe00) == 0xfc00 || (first & 0xffc0) == 0xfe80
+        }
+    }
+}
+
+/// Update the served JSON, skipped entirely while the server is off
+fn cache_for_stats_server(state: &AppState, update: impl FnOnce(&mut StatsCache)) {
+    if state.stats_server.lock().unwrap().config.is_some() {
+        update(&mut state.stats_cache.lock().unwrap());
+    }
+}
+
+fn write_http_response(stream: &mut std::net::TcpStream, status: &str, content_type: &str, body: &str) {
+    use std::io::Write;
+
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        content_type,
+        body.len(),
+        body
+    );
+    let _ = stream.write_all(response.as_bytes());
+}
+
+// Prometheus exposition on /metrics for Grafana and friends
+const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+const METRICS_TOP_PROCESSES: usize = 10;
+
+fn escape_label(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+/// Render the cached stats and process list, None until something has been polled
+fn prometheus_metrics(cache: &StatsCache) -> Option<String> {
+    use std::fmt::Write;
+
+    let stats: Option<SystemStats> = cache.stats_json.as_deref().and_then(|json| serde_json::from_str(json).ok());
+    let mut processes: Vec<ProcessInfo> = cache
+        .processes_json
+        .as_deref()
+        .and_then(|json| serde_json::from_str(json).ok())
+        .unwrap_or_default();
+    if stats.is_none() && processes.is_empty() {
+        return None;
+    }
+
+    let mut out = String::new();
+    let gauge = |out: &mut String, name: &str, help: &str, value: f64| {
+        let _ = writeln!(out, "# HELP {} {}\n# TYPE {} gauge\n{} {}", name, help, name, name, value);
+    };
+    if let Some(stats) = &stats {
+        gauge(&mut out, "pg_cpu_percent", "Total CPU usage", stats.cpu_percent as f64);
+        gauge(&mut out, "pg_memory_percent", "Physical memory in use", stats.memory_percent as f64);
+    }
+    // No system-wide GPU counter, the per-process engine shares add up to it
+    let gpu_total: f32 = processes.iter().map(|p| p.gpu_percent).sum();
+    gauge(&mut out, "pg_gpu_percent", "Total GPU usage", gpu_total.min(100.0) as f64);
+
+    processes.sort_by(|a, b| b.cpu_percent.total_cmp(&a.cpu_percent).then(a.pid.cmp(&b.pid)));
+    processes.truncate(METRICS_TOP_PROCESSES);
+    let per_process: [(&str, &str, fn(&ProcessInfo) -> f64); 3] = [
+        ("pg_process_cpu_percent", "CPU usage of the top processes", |p| p.cpu_percent as f64),
+        ("pg_process_memory_mb", "Memory of the top processes", |p| p.memory_mb),
+        ("pg_process_gpu_percent", "GPU usage of the top processes", |p| p.gpu_percent as f64),
+    ];
+    for (metric, help, value) in per_process {
+        let _ = writeln!(out, "# HELP {} {}\n# TYPE {} gauge", metric, help, metric);
+        for process in &processes {
+            let _ = writeln!(
+                out,
+                "{}{{name=\"{}\",pid=\"{}\"}} {}",
+                metric,
+                escape_label(&process.name),
+                process.pid,
+                value(process)
+            );
+        }
+    }
+    Some(out)
+}
+
+/// Value of the Authorization: Bearer header, if any
+fn bearer_token(request: &str) -> Option<&str> {
+    request.lines().skip(1).find_map(|line| {
+        let (name, value) = line.split_once(':')?;
+        if !name.trim().eq_ignore_ascii_case("authorization") {
+            return None;
+        }
+        value.trim().strip_prefix("Bearer ").map(str::trim)
+    })
+}
+
+/// Constant-time for equal lengths, so the token can't be guessed byte by byte from timing.
+/// A length mismatch returns early, which only reveals the token's length
+fn tokens_match(expected: &str, given: &str) -> bool {
+    expected.len() == given.len()
+        && expected.bytes().zip(given.bytes()).fold(0u8, |diff, (a, b)| diff | (a ^ b)) == 0
 }
 
-#[derive(Serialize, Deserialize, Default)]
-struct AppData {
-    whitelist: Vec<SavedWhitelistEntry>,
-    sessions: Vec<SavedSession>,
-    next_session_id: i64,
+fn handle_stats_request(state: &AppState, mut stream: std::net::TcpStream, required_token: Option<&str>) {
+    use std::io::Read;
+
+    let timeout = Some(std::time::Duration::from_millis(STATS_HTTP_TIMEOUT_MS));
+    let _ = stream.set_read_timeout(timeout);
+    let _ = stream.set_write_timeout(timeout);
+
+    // Only the request line matters, GET requests have no body
+    let mut request = Vec::new();
+    let mut chunk = [0u8; 1024];
+    while !request.windows(4).any(|w| w == b"\r\n\r\n") && request.len() < 8192 {
+        match stream.read(&mut chunk) {
+            Ok(0) | Err(_) => break,
+            Ok(n) => request.extend_from_slice(&chunk[..n]),
+        }
+    }
+    let request = String::from_utf8_lossy(&request);
+    let mut parts = request.lines().next().unwrap_or("").split_whitespace();
+    let (method, path) = (parts.next().unwrap_or(""), parts.next().unwrap_or(""));
+
+    if let Some(expected) = required_token {
+        if !bearer_token(&request).is_some_and(|given| tokens_match(expected, given)) {
+            write_http_response(&mut stream, "401 Unauthorized", "text/plain", "token required");
+            return;
+        }
+    }
+    if method != "GET" {
+        write_http_response(&mut stream, "405 Method Not Allowed", "text/plain", "read-only");
+        return;
+    }
+    let body = {
+        let cache = state.stats_cache.lock().unwrap();
+        match path {
+            "/stats" => cache.stats_json.clone(),
+            "/processes" => cache.processes_json.clone(),
+            "/metrics" => {
+                let metrics = prometheus_metrics(&cache);
+                drop(cache);
+                match metrics {
+                    Some(body) => write_http_response(&mut stream, "200 OK", PROMETHEUS_CONTENT_TYPE, &body),
+                    None => write_http_response(&mut stream, "503 Service Unavailable", "text/plain", "no data yet"),
+                }
+                return;
+            }
+            _ => {
+                drop(cache);
+                write_http_response(&mut stream, "404 Not Found", "text/plain", "not found");
+                return;
+            }
+        }
+    };
+    match body {
+        Some(body) => write_http_response(&mut stream, "200 OK", "application/json", &body),
+        // Nothing polled yet (the app's own UI drives collection)
+        None => write_http_response(&mut stream, "503 Service Unavailable", "text/plain", "no data yet"),
+    }
 }
 
-fn get_data_file_path(state: &State<AppState>) -> PathBuf {
-    state.data_path.join("performance_guard_data.json")
+/// Accept loop, exits once the server is stopped or restarted (generation changes).
+/// Every client is handled on its own thread so a slow one can't block the rest
+fn run_stats_server(app: tauri::AppHandle, listener: std::net::TcpListener, generation: u64, config: StatsServerConfig) {
+    let state = app.state::<AppState>();
+    loop {
+        if state.stats_server.lock().unwrap().generation != generation {
+            break;
+        }
+        match listener.accept() {
+            Ok((mut stream, peer)) => {
+                if !is_lan_address(peer.ip()) {
+                    log_warn!("Stats server refused non-LAN client {}", peer);
+                    continue;
+                }
+                // Accepted sockets inherit non-blocking mode
+                let _ = stream.set_nonblocking(false);
+                if STATS_CLIENTS.fetch_add(1, Ordering::SeqCst) >= MAX_STATS_CLIENTS {
+                    STATS_CLIENTS.fetch_sub(1, Ordering::SeqCst);
+                    write_http_response(&mut stream, "503 Service Unavailable", "text/plain", "busy");
+                    continue;
+                }
+                // Loopback clients are this machine, everyone else needs the token
+                let required_token = if peer.ip().is_loopback() { None } else { config.token.clone() };
+                let app = app.clone();
+                std::thread::spawn(move || {
+                    handle_stats_request(&app.state::<AppState>(), stream, required_token.as_deref());
+                    STATS_CLIENTS.fetch_sub(1, Ordering::SeqCst);
+                });
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                std::thread::sleep(std::time::Duration::from_millis(STATS_SERVER_ACCEPT_POLL_MS));
+            }
+            Err(e) => {
+                log_error!("Stats server accept failed: {}", e);
+                std::thread::sleep(std::time::Duration::from_millis(STATS_SERVER_ACCEPT_POLL_MS));
+            }
+        }
+    }
+    log_info!("Stats server on port {} stopped", config.port);
 }
 
-#[tauri::command]
-fn save_app_data(state: State<AppState>, whitelist: Vec<SavedWhitelistEntry>, sessions: Vec<SavedSession>, next_session_id: i64) -> Result<(), String> {
-    let data = AppData {
-        whitelist,
-        sessions,
-        next_session_id,
+/// Stop the accept loop and wait for it, so its listener is closed when this returns
+fn stop_stats_server(state: &AppState) {
+    let thread = {
+        let mut server = state.stats_server.lock().unwrap();
+        server.config = None;
+        server.generation += 1;
+        server.thread.take()
     };
+    if let Some(thread) = thread {
+        let _ = thread.join();
+    }
+    *state.stats_cache.lock().unwrap() = StatsCache::default();
+}
 
-    let data_file = get_data_file_path(&state);
-
-    // Ensure directory exists
-    if let Some(parent) = data_file.parent() {
-        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+fn start_stats_server(app: &tauri::AppHandle, state: &AppState, config: StatsServerConfig) -> Result<(), String> {
+    if config.lan && config.token.as_deref().map_or(0, str::len) < MIN_STATS_TOKEN_LEN {
+        return Err(format!("LAN access needs a token of at least {} characters", MIN_STATS_TOKEN_LEN));
     }
+    stop_stats_server(state);
 
-    let json = serde_json::to_string_pretty(&data).map_err(|e| e.to_string())?;
-    fs::write(&data_file, json).map_err(|e| e.to_string())?;
+    let host = if config.lan { "0.0.0.0" } else { "127.0.0.1" };
+    let listener = std::net::TcpListener::bind((host, config.port))
+        .map_err(|e| format!("Failed to listen on port {}: {}", config.port, e))?;
+    listener.set_nonblocking(true).map_err(|e| e.to_string())?;
+    log_info!("Stats server listening on {}:{}", host, config.port);
 
+    let mut server = state.stats_server.lock().unwrap();
+    server.generation += 1;
+    server.config = Some(config.clone());
+    let generation = server.generation;
+    let app = app.clone();
+    server.thread = Some(std::thread::spawn(move || run_stats_server(app, listener, generation, config)));
     Ok(())
 }
 
+/// Serve /stats, /processes and /metrics on `port` (persisted). Loopback only unless
+/// `lan` is set, which requires `token` (sent by clients as "Authorization: Bearer <token>")
 #[tauri::command]
-fn load_app_data(state: State<AppState>) -> Result<AppData, String> {
-    let data_file = get_data_file_path(&state);
+fn set_stats_server(
+    app: tauri::AppHandle,
+    state: State<AppState>,
+    enabled: bool,
+    port: Option<u16>,
+    lan: Option<bool>,
+    token: Option<String>,
+) -> Result<(), String> {
+    let config = StatsServerConfig {
+        port: port.unwrap_or(DEFAULT_STATS_SERVER_PORT),
+        lan: lan.unwrap_or(false),
+        token: token.map(|t| t.trim().to_string()).filter(|t| !t.is_empty()),
+    };
+    if enabled {
+        start_stats_server(&app, &state, config.clone())?;
+    } else {
+        stop_stats_server(&state);
+    }
+    update_app_data(&state, |data| {
+        data.stats_server_port = enabled.then_some(config.port);
+        data.stats_server_lan = config.lan;
+        data.stats_server_token = config.token;
+    })
+}
 
-    if !data_file.exists() {
-        return Ok(AppData::default());
+/// GET `path` from an http://host:port base URL, the body on a 200 response
+fn http_get(addr: std::net::SocketAddr, host: &str, path: &str, token: Option<&str>) -> Result<String, String> {
+    use std::io::{Read, Write};
+
+    let timeout = std::time::Duration::from_millis(STATS_HTTP_TIMEOUT_MS);
+    let mut stream = std::net::TcpStream::connect_timeout(&addr, timeout).map_err(|e| e.to_string())?;
+    let _ = stream.set_read_timeout(Some(timeout));
+    let _ = stream.set_write_timeout(Some(timeout));
+    let authorization = token.map(|token| format!("Authorization: Bearer {}\r\n", token)).unwrap_or_default();
+    let request = format!(
+        "GET {} HTTP/1.1\r\nHost: {}\r\n{}Connection: close\r\n\r\n",
+        path, host, authorization
+    );
+    stream.write_all(request.as_bytes()).map_err(|e| e.to_string())?;
+
+    let mut response = Vec::new();
+    stream
+        .take(MAX_STATS_HTTP_RESPONSE_BYTES as u64)
+        .read_to_end(&mut response)
+        .map_err(|e| e.to_string())?;
+    let response = String::from_utf8_lossy(&response);
+    let (head, body) = response.split_once("\r\n\r\n").ok_or("Malformed HTTP response")?;
+    let status = head.lines().next().unwrap_or("");
+    if status.split_whitespace().nth(1) != Some("200") {
+        return Err(format!("{} returned {}", path, status));
     }
+    Ok(body.to_string())
+}
 
-    let content = fs::read_to_string(&data_file).map_err(|e| e.to_string())?;
-    let data: AppData = serde_json::from_str(&content).map_err(|e| e.to_string())?;
+/// Parse "http://host:port[/]" into a LAN socket address and the Host header value
+fn parse_remote_url(url: &str) -> Result<(std::net::SocketAddr, String), String> {
+    use std::net::ToSocketAddrs;
 
-    Ok(data)
+    let host = url
+        .trim()
+        .strip_prefix("http://")
+        .ok_or("Only http:// URLs are supported")?
+        .trim_end_matches('/');
+    if host.is_empty() || host.contains('/') {
+        return Err("URL must be http://host:port".to_string());
+    }
+    let with_port = if host.rsplit_once(':').is_some_and(|(_, port)| port.parse::<u16>().is_ok()) {
+        host.to_string()
+    } else {
+        format!("{}:{}", host, DEFAULT_STATS_SERVER_PORT)
+    };
+    let addr = with_port
+        .to_socket_addrs()
+        .map_err(|e| e.to_string())?
+        .next()
+        .ok_or("Host did not resolve")?;
+    if !is_lan_address(addr.ip()) {
+        return Err("Remote monitoring is limited to the local network".to_string());
+    }
+    Ok((addr, with_port))
 }
 
-// Autostart commands
-#[tauri::command]
-fn get_autostart_enabled(app: tauri::AppHandle) -> bool {
-    use tauri_plugin_autostart::ManagerExt;
-    app.autolaunch().is_enabled().unwrap_or(false)
+// Generation of the newest connect_remote, shared by all sources
+static REMOTE_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Poll one remote until it is disconnected or connected again (which starts a new poller)
+fn run_remote_poll(
+    app: tauri::AppHandle,
+    source_id: String,
+    addr: std::net::SocketAddr,
+    token: Option<String>,
+    generation: u64,
+) {
+    let state = app.state::<AppState>();
+    loop {
+        let stats = http_get(addr, &source_id, "/stats", token.as_deref())
+            .and_then(|body| serde_json::from_str::<SystemStats>(&body).map_err(|e| e.to_string()));
+        let processes = http_get(addr, &source_id, "/processes", token.as_deref())
+            .and_then(|body| serde_json::from_str::<Vec<ProcessInfo>>(&body).map_err(|e| e.to_string()));
+
+        {
+            let mut remotes = state.remotes.lock().unwrap();
+            let Some(remote) = remotes.get_mut(&source_id).filter(|r| r.generation == generation) else { break };
+            match (stats, processes) {
+                (Ok(stats), Ok(processes)) => {
+                    remote.stats = Some(stats);
+                    remote.processes = processes;
+                    remote.last_update = Some(chrono::Local::now().to_rfc3339());
+                    remote.last_error = None;
+                }
+                (Err(e), _) | (_, Err(e)) => remote.last_error = Some(e),
+            }
+        }
+        std::thread::sleep(std::time::Duration::from_millis(REMOTE_POLL_MS));
+    }
 }
 
+/// Start polling another instance's stats server, returns the source id to pass
+/// to get_system_stats / get_processes
 #[tauri::command]
-fn set_autostart_enabled(app: tauri::AppHandle, enabled: bool) -> Result<(), String> {
-    use tauri_plugin_autostart::ManagerExt;
-    let manager = app.autolaunch();
-    if enabled {
-        manager.enable().map_err(|e| e.to_string())
-    } else {
-        manager.disable().map_err(|e| e.to_string())
+fn connect_remote(
+    app: tauri::AppHandle,
+    state: State<AppState>,
+    url: String,
+    token: Option<String>,
+) -> Result<String, String> {
+    let (addr, source_id) = parse_remote_url(&url)?;
+    let url = format!("http://{}", source_id);
+    let token = token.map(|t| t.trim().to_string()).filter(|t| !t.is_empty());
+
+    let mut remotes = state.remotes.lock().unwrap();
+    if remotes.get(&source_id).is_some_and(|r| r.url == url && r.token == token) {
+        return Ok(source_id);
     }
+    // Replacing the entry retires any poller still running for this source
+    let generation = REMOTE_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    remotes.insert(source_id.clone(), RemoteSource {
+        url: url.clone(),
+        token: token.clone(),
+        generation,
+        stats: None,
+        processes: Vec::new(),
+        last_update: None,
+        last_error: None,
+    });
+    drop(remotes);
+
+    let id = source_id.clone();
+    std::thread::spawn(move || run_remote_poll(app, id, addr, token, generation));
+    Ok(source_id)
 }
 
-// Emits event to splash window when main app is ready
 #[tauri::command]
-async fn signal_app_ready(app: tauri::AppHandle) -> Result<(), String> {
-    // Emit global event that splash window can listen to
-    app.emit("app-ready", ()).map_err(|e| e.to_string())?;
-    Ok(())
+fn disconnect_remote(state: State<AppState>, source_id: String) -> bool {
+    state.remotes.lock().unwrap().remove(&source_id).is_some()
 }
 
-// Shows splash window when DOM is ready
 #[tauri::command]
-async fn show_splash_window(app: tauri::AppHandle) -> Result<(), String> {
-    if let Some(splash) = app.get_webview_window("splashscreen") {
-        splash.show().map_err(|e| e.to_string())?;
-    }
-    Ok(())
+fn get_remote_sources(state: State<AppState>) -> Vec<RemoteSourceInfo> {
+    let remotes = state.remotes.lock().unwrap();
+    let mut sources: Vec<RemoteSourceInfo> = remotes
+        .iter()
+        .map(|(id, remote)| RemoteSourceInfo {
+            source_id: id.clone(),
+            url: remote.url.clone(),
+            connected: remote.last_error.is_none() && remote.last_update.is_some(),
+            last_update: remote.last_update.clone(),
+            last_error: remote.last_error.clone(),
+        })
+        .collect();
+    sources.sort_by(|a, b| a.source_id.cmp(&b.source_id));
+    sources
 }
 
-// Closes splash and shows main window (called after animation completes)
-#[tauri::command]
-async fn close_splash_show_main(app: tauri::AppHandle) -> Result<(), String> {
-    // Show main window BEFORE closing splash
-    if let Some(main) = app.get_webview_window("main") {
-        main.show().map_err(|e| e.to_string())?;
-        main.set_focus().map_err(|e| e.to_string())?;
+fn remote_system_stats(state: &AppState, source_id: &str) -> Result<SystemStats, String> {
+    let remotes = state.remotes.lock().unwrap();
+    let remote = remotes.get(source_id).ok_or_else(|| format!("Unknown source: {}", source_id))?;
+    remote.stats.clone().ok_or_else(|| {
+        remote.last_error.clone().unwrap_or_else(|| "No data from remote yet".to_string())
+    })
+}
+
+fn remote_processes(state: &AppState, source_id: &str) -> Result<Vec<ProcessInfo>, String> {
+    let remotes = state.remotes.lock().unwrap();
+    let remote = remotes.get(source_id).ok_or_else(|| format!("Unknown source: {}", source_id))?;
+    Ok(remote.processes.clone())
+}
+
+// Shutdown - every quit path goes through shutdown(): let go of suspended/throttled
+// processes, give the frontend a moment to save running sessions, then exit
+const SHUTDOWN_FLUSH_TIMEOUT_MS: u64 = 2000;
+static SHUTTING_DOWN: AtomicBool = AtomicBool::new(false);
+static SHUTDOWN_FLUSHED: AtomicBool = AtomicBool::new(false);
+
+/// Resume everything we suspended or throttled and stop background trackers
+fn release_process_holds(state: &AppState) {
+    release_cpu_caps(state);
+    release_game_mode(state);
+    release_boosts(state);
+    release_rule_suspends(state);
+    release_manual_suspends(state);
+    state.auto_track.lock().unwrap().grace_seconds = None;
+    state.auto_optimize.lock().unwrap().idle_secs = None;
+    state.monitoring.lock().unwrap().interval_ms = None;
+}
+
+fn shutdown(app: &tauri::AppHandle) {
+    if SHUTTING_DOWN.swap(true, Ordering::SeqCst) {
+        return;
     }
-    // Close splash
-    if let Some(splash) = app.get_webview_window("splashscreen") {
-        splash.close().map_err(|e| e.to_string())?;
+    log_info!("Shutting down");
+    if let Some(state) = app.try_state::<AppState>() {
+        release_process_holds(&state);
+        flush_daily_activity(&state);
+        flush_first_seen(&state);
     }
-    // Trigger panel animation in main window after it's shown
-    app.emit("trigger-panel-animation", ()).map_err(|e| e.to_string())?;
-    Ok(())
+
+    let app = app.clone();
+    std::thread::spawn(move || {
+        // The frontend saves its sessions and answers with shutdown_flushed
+        let _ = app.emit("app-shutdown", ());
+        let deadline = Instant::now() + std::time::Duration::from_millis(SHUTDOWN_FLUSH_TIMEOUT_MS);
+        while !SHUTDOWN_FLUSHED.load(Ordering::SeqCst) && Instant::now() < deadline {
+            std::thread::sleep(std::time::Duration::from_millis(50));
+        }
+        app.exit(0);
+    });
+}
+
+/// Called by the frontend once its app-shutdown save has finished
+#[tauri::command]
+fn shutdown_flushed() {
+    SHUTDOWN_FLUSHED.store(true, Ordering::SeqCst);
 }
 
 /// Extract application icon from exe file and return as base64 PNG
@@ -807,7 +9206,219 @@ fn get_app_icon(_exe_path: String) -> Result<String, String> {
     Err("Not supported on this platform".to_string())
 }
 
+// Icons are a few KB of base64 each, past this many the oldest cached path is evicted
+const MAX_ICON_CACHE_ENTRIES: usize = 512;
+
+/// Exe path -> base64 PNG icon (None when extraction failed), bounded by MAX_ICON_CACHE_ENTRIES
+#[derive(Default)]
+struct IconCache {
+    icons: HashMap<String, Option<String>>,
+    // Cached paths, oldest first
+    order: std::collections::VecDeque<String>,
+}
+
+impl IconCache {
+    fn get(&self, exe_path: &str) -> Option<&Option<String>> {
+        self.icons.get(exe_path)
+    }
+
+    fn insert(&mut self, exe_path: String, icon: Option<String>) {
+        if self.icons.insert(exe_path.clone(), icon).is_none() {
+            self.order.push_back(exe_path);
+        }
+        while self.icons.len() > MAX_ICON_CACHE_ENTRIES {
+            let Some(oldest) = self.order.pop_front() else { break };
+            self.icons.remove(&oldest);
+        }
+    }
+}
+
+/// Icon for an exe path, extracted once and then served from the cache
+fn cached_app_icon(state: &AppState, exe_path: &str) -> Option<String> {
+    if let Some(icon) = state.icon_cache.lock().unwrap().get(exe_path) {
+        return icon.clone();
+    }
+    // Extract without holding the lock, icon extraction does file IO
+    let icon = get_app_icon(exe_path.to_string()).ok();
+    state.icon_cache.lock().unwrap().insert(exe_path.to_string(), icon.clone());
+    icon
+}
+
+// Icon prefetch - a few worker threads fill icon_cache and emit icon-ready per path,
+// instead of the UI firing get_app_icon for every row at once
+const ICON_PREFETCH_WORKERS: usize = 4;
+const MAX_ICON_PREFETCH_QUEUE: usize = 512;
+
+#[derive(Default)]
+struct IconPrefetchQueue {
+    pending: std::collections::VecDeque<String>,
+    // Worker threads currently draining `pending`
+    workers: usize,
+}
+
+#[derive(Serialize, Clone)]
+struct IconReady {
+    exe_path: String,
+    // Base64 PNG, None when extraction failed
+    icon: Option<String>,
+}
+
+/// Queue icon extraction for exe paths, each one answered with an icon-ready event
+/// Cached icons are answered straight away
+#[tauri::command]
+fn prefetch_icons(app: tauri::AppHandle, state: State<AppState>, exe_paths: Vec<String>) {
+    let mut ready = Vec::new();
+    {
+        let cache = state.icon_cache.lock().unwrap();
+        let mut queue = state.icon_prefetch.lock().unwrap();
+        for exe_path in exe_paths {
+            if let Some(icon) = cache.get(&exe_path) {
+                ready.push(IconReady { icon: icon.clone(), exe_path });
+            } else if !queue.pending.contains(&exe_path) {
+                queue.pending.push_back(exe_path);
+            }
+        }
+        // Over the bound the oldest requests go, the newest are the rows on screen now
+        while queue.pending.len() > MAX_ICON_PREFETCH_QUEUE {
+            queue.pending.pop_front();
+        }
+        while queue.workers < ICON_PREFETCH_WORKERS.min(queue.pending.len()) {
+            queue.workers += 1;
+            let app = app.clone();
+            std::thread::spawn(move || run_icon_prefetch_worker(app));
+        }
+    }
+
+    for icon in ready {
+        let _ = app.emit("icon-ready", icon);
+    }
+}
+
+fn run_icon_prefetch_worker(app: tauri::AppHandle) {
+    let state = app.state::<AppState>();
+    loop {
+        let exe_path = {
+            let mut queue = state.icon_prefetch.lock().unwrap();
+            match queue.pending.pop_front() {
+                Some(exe_path) => exe_path,
+                None => {
+                    queue.workers -= 1;
+                    return;
+                }
+            }
+        };
+        let icon = cached_app_icon(&state, &exe_path);
+        let _ = app.emit("icon-ready", IconReady { exe_path, icon });
+    }
+}
+
+// GPU processes - NVML and PDH PIDs joined with process details for a GPU panel
+#[derive(Serialize)]
+struct GpuProcessInfo {
+    pid: u32,
+    name: String,
+    exe_path: Option<String>,
+    // Base64 PNG
+    icon: Option<String>,
+    gpu_percent: f32,
+    gpu_adapter: Option<String>,
+    gpu_memory_mb: Option<f64>,
+}
+
+/// Every process on the GPU with name, exe path and icon, busiest first
+#[tauri::command]
+fn get_gpu_processes(state: State<AppState>) -> Vec<GpuProcessInfo> {
+    let gpu = cached_gpu_usage(&state);
+    let engines = read_gpu_engines(&state);
+    let mut pids: Vec<u32> = engines.keys().copied().collect();
+    pids.extend(gpu.utilization.keys().copied());
+    pids.sort_unstable();
+    pids.dedup();
+
+    let resolved: Vec<(u32, String, Option<String>)> = {
+        let mut system = state.system.lock().unwrap();
+        let sys_pids: Vec<Pid> = pids.iter().map(|&pid| Pid::from_u32(pid)).collect();
+        // Exe only - refreshing CPU here would skew the next poll's usage delta
+        system.refresh_processes_specifics(
+            sysinfo::ProcessesToUpdate::Some(&sys_pids),
+            true,
+            sysinfo::ProcessRefreshKind::new().with_exe(sysinfo::UpdateKind::OnlyIfNotSet),
+        );
+        // PIDs that exited since the GPU query are skipped
+        pids.iter()
+            .filter_map(|&pid| {
+                let process = system.process(Pid::from_u32(pid))?;
+                Some((pid, canonical_name(process), process.exe().map(|p| p.to_string_lossy().to_string())))
+            })
+            .collect()
+    };
+
+    let mut processes: Vec<GpuProcessInfo> = resolved
+        .into_iter()
+        .map(|(pid, name, exe_path)| {
+            let (gpu_percent, gpu_adapter) = merged_gpu_usage(pid, &gpu, &engines);
+            GpuProcessInfo {
+                pid,
+                icon: exe_path.as_deref().and_then(|path| cached_app_icon(&state, path)),
+                name,
+                exe_path,
+                gpu_percent: round_metric_f32(gpu_percent),
+                gpu_adapter,
+                gpu_memory_mb: gpu.memory_bytes.get(&pid).map(|&bytes| round_metric(bytes as f64 / (1024.0 * 1024.0))),
+            }
+        })
+        .collect();
+    processes.sort_by(|a, b| b.gpu_percent.total_cmp(&a.gpu_percent).then_with(|| a.pid.cmp(&b.pid)));
+    processes
+}
+
+// GPU devices - one entry per NVIDIA device for a per-GPU panel
+#[derive(Serialize)]
+struct GpuDevice {
+    index: u32,
+    name: String,
+    memory_total_mb: f64,
+    memory_used_mb: f64,
+    // Fields the driver can't report are None
+    utilization_percent: Option<f32>,
+    temperature_c: Option<u32>,
+}
+
+/// Every NVIDIA device NVML can see, empty without an NVIDIA driver
+#[cfg(windows)]
+fn read_gpu_devices(state: &AppState) -> Vec<GpuDevice> {
+    use nvml_wrapper::enum_wrappers::device::TemperatureSensor;
+
+    let Some(nvml) = state.nvml.as_ref() else { return Vec::new() };
+    (0..nvml.device_count().unwrap_or(0))
+        .filter_map(|index| {
+            let device = nvml.device_by_index(index).ok()?;
+            let memory = device.memory_info().ok();
+            Some(GpuDevice {
+                index,
+                name: device.name().unwrap_or_else(|_| format!("GPU {}", index)),
+                memory_total_mb: memory.as_ref().map_or(0.0, |m| round_metric(m.total as f64 / (1024.0 * 1024.0))),
+                memory_used_mb: memory.as_ref().map_or(0.0, |m| round_metric(m.used as f64 / (1024.0 * 1024.0))),
+                utilization_percent: device.utilization_rates().ok().map(|u| u.gpu as f32),
+                temperature_c: device.temperature(TemperatureSensor::Gpu).ok(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(not(windows))]
+fn read_gpu_devices(_state: &AppState) -> Vec<GpuDevice> {
+    Vec::new()
+}
+
+#[tauri::command]
+fn get_gpus(state: State<AppState>) -> Vec<GpuDevice> {
+    read_gpu_devices(&state)
+}
+
 fn main() {
+    install_crash_reporter();
+
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_google_auth::init())
@@ -815,19 +9426,168 @@ fn main() {
         .plugin(tauri_plugin_updater::Builder::new().build())
         .plugin(tauri_plugin_os::init())
         .setup(|app| {
+            // Get app data directory
+            let data_path = app.path().app_data_dir()
+                .unwrap_or_else(|_| PathBuf::from("."));
+
+            // Skip the splash entirely on fast-launch setups
+            if !read_bootstrap_config(&data_path).splash_enabled {
+                SPLASH_ENABLED.store(false, Ordering::SeqCst);
+                if let Some(splash) = app.get_webview_window("splashscreen") {
+                    let _ = splash.close();
+                }
+                let _ = show_main_window(app.handle());
+            }
+
             // Initialize system
             let mut system = System::new_all();
             system.refresh_all();
+            // Throwaway CPU sample so the first real poll has a baseline to diff against
+            system.refresh_cpu_all();
+            let cpu_primed_at = Instant::now();
 
-            // Get app data directory
-            let data_path = app.path().app_data_dir()
-                .unwrap_or_else(|_| PathBuf::from("."));
+            logger::init(&data_path.join("logs"));
+            log_info!("Performance Guard {} starting", app.package_info().version);
+
+            // Backend-owned settings persisted alongside the frontend data
+            let (saved, data_recovery) = recover_app_data(&data_path.join(DATA_FILE_NAME));
+            if let Some(level) = saved.log_level.as_deref().and_then(logger::Level::parse) {
+                logger::set_level(level);
+            }
 
             app.manage(AppState {
                 system: Mutex::new(system),
                 data_path,
+                category_rules: Mutex::new(saved.category_rules),
+                cpu_caps: Mutex::new(HashMap::new()),
+                last_cycle_ms: Mutex::new(None),
+                game_mode_suspended: Mutex::new(None),
+                version_cache: Mutex::new(HashMap::new()),
+                signature_cache: Mutex::new(HashMap::new()),
+                cpu_anomaly: Mutex::new(CpuAnomalyDetector {
+                    k: saved.anomaly_sensitivity.unwrap_or(DEFAULT_ANOMALY_SENSITIVITY),
+                    stats: HashMap::new(),
+                }),
+                gpu_memory_history: Mutex::new(HashMap::new()),
+                gui_leak_check: Mutex::new(GuiLeakCheck::default()),
+                first_seen: Mutex::new(FirstSeenTracker {
+                    paths: saved.first_seen,
+                    baseline: saved.first_seen_baseline,
+                    dirty: false,
+                    last_persist: Instant::now(),
+                }),
+                protected_processes: Mutex::new(saved.protected_processes),
+                cpu_primed_at,
+                cpu_polls: AtomicU32::new(0),
+                stats_polls: AtomicU32::new(0),
+                memory_metric: Mutex::new(saved.memory_metric),
+                memory_units: Mutex::new(saved.memory_units),
+                auto_track: Mutex::new(AutoTrackLoop {
+                    grace_seconds: saved.auto_track_foreground
+                        .then(|| saved.auto_track_grace_secs.unwrap_or(DEFAULT_AUTO_TRACK_GRACE_SECS)),
+                    generation: 0,
+                }),
+                profiling_active: AtomicBool::new(false),
+                #[cfg(windows)]
+                page_fault_counter: Mutex::new(None),
+                memory_history: Mutex::new(HashMap::new()),
+                named_snapshots: Mutex::new(Vec::new()),
+                #[cfg(windows)]
+                gpu_engine_counter: Mutex::new(None),
+                #[cfg(windows)]
+                disk_counters: Mutex::new(None),
+                display_average: Mutex::new(DisplayAverage {
+                    window: saved.display_average_window.unwrap_or(1).clamp(1, MAX_DISPLAY_AVERAGE_WINDOW),
+                    samples: HashMap::new(),
+                }),
+                boosts: Mutex::new(HashMap::new()),
+                #[cfg(windows)]
+                energy_meter_counter: Mutex::new(None),
+                auto_optimize: Mutex::new(AutoOptimizeLoop {
+                    idle_secs: saved.auto_optimize_on_idle
+                        .then(|| saved.auto_optimize_idle_secs.unwrap_or(DEFAULT_AUTO_OPTIMIZE_IDLE_SECS)),
+                    generation: 0,
+                }),
+                daily_activity: Mutex::new(DailyActivityTracker {
+                    days: saved.daily_activity.clone(),
+                    last_sample: None,
+                    last_persist: Instant::now(),
+                }),
+                process_rules: Mutex::new(saved.process_rules.clone()),
+                rule_suspended: Mutex::new(HashMap::new()),
+                icon_cache: Mutex::new(IconCache::default()),
+                max_list_length: Mutex::new(saved.max_list_length),
+                io_ops: Mutex::new(HashMap::new()),
+                stats_server: Mutex::new(StatsServer::default()),
+                stats_cache: Mutex::new(StatsCache::default()),
+                remotes: Mutex::new(HashMap::new()),
+                #[cfg(windows)]
+                gpu_adapter_names: Mutex::new(HashMap::new()),
+                context_switches: Mutex::new(HashMap::new()),
+                icon_prefetch: Mutex::new(IconPrefetchQueue::default()),
+                watched_directories: Mutex::new(saved.watched_directories.clone()),
+                network_sampler: Mutex::new(NetworkSampler {
+                    networks: sysinfo::Networks::new_with_refreshed_list(),
+                    last_refresh: Instant::now(),
+                }),
+                self_cpu_budget: Mutex::new(SelfCpuBudget {
+                    max_percent: saved.self_cpu_budget.filter(|p| *p > 0.0 && *p <= 100.0),
+                    interval_ms: DEFAULT_POLL_INTERVAL_MS,
+                    last_sample: None,
+                }),
+                manually_suspended: Mutex::new(HashMap::new()),
+                gpu_sample_timestamps: Mutex::new(HashMap::new()),
+                gpu_usage: Mutex::new(GpuProcessUsage::default()),
+                #[cfg(windows)]
+                nvml: init_nvml(),
+                components: Mutex::new(sysinfo::Components::new_with_refreshed_list()),
+                monitoring: Mutex::new(MonitoringLoop::default()),
+                data_recovery: Mutex::new(data_recovery),
+                data_file_lock: Mutex::new(()),
+                service_names: Mutex::new(ServiceNameCache::default()),
             });
 
+            if saved.auto_track_foreground {
+                let handle = app.handle().clone();
+                std::thread::spawn(move || run_auto_track(handle, 0));
+            }
+            if let Some(port) = saved.stats_server_port {
+                let state = app.state::<AppState>();
+                let config = StatsServerConfig {
+                    port,
+                    lan: saved.stats_server_lan,
+                    token: saved.stats_server_token.clone(),
+                };
+                if let Err(e) = start_stats_server(app.handle(), &state, config) {
+                    log_error!("{}", e);
+                }
+            }
+            if saved.auto_optimize_on_idle {
+                let handle = app.handle().clone();
+                std::thread::spawn(move || run_auto_optimize(handle, 0));
+            }
+            if !saved.watched_directories.is_empty() {
+                let handle = app.handle().clone();
+                std::thread::spawn(move || run_whitelist_watch(handle));
+            }
+
+            let screen_scale = saved.screen_scale.unwrap_or_else(detect_screen_scale);
+            MOUSE_DPI_SCALE.store(screen_scale.to_bits(), Ordering::SeqCst);
+
+            if let Some(decimals) = saved.metric_precision {
+                METRIC_PRECISION.store(decimals.min(MAX_METRIC_PRECISION), Ordering::SeqCst);
+            }
+            TRAY_LOAD_ICON.store(saved.tray_load_icon, Ordering::SeqCst);
+            OWN_PID.store(std::process::id(), Ordering::SeqCst);
+            EXCLUDE_OWN_INPUT.store(saved.exclude_own_input.unwrap_or(true), Ordering::SeqCst);
+            CRASH_REPORTS.store(saved.crash_reports, Ordering::SeqCst);
+            RESUME_SESSIONS.store(saved.resume_sessions, Ordering::SeqCst);
+            reconcile_open_sessions(&app.state::<AppState>());
+            if let Some(alpha) = saved.gpu_smoothing_alpha.filter(|a| *a > 0.0 && *a <= 1.0) {
+                GPU_SMOOTHING_ALPHA.store(alpha.to_bits(), Ordering::SeqCst);
+            }
+            *ACTIVITY_APP_FILTER.lock().unwrap() = saved.activity_app_filter.clone();
+
             // Setup input hooks for accurate activity detection (keyboard + mouse)
             #[cfg(windows)]
             input_hooks::setup();
@@ -847,14 +9607,14 @@ fn main() {
             let show = MenuItem::with_id(app, "show", "Show", true, None::<&str>)?;
             let menu = Menu::with_items(app, &[&show, &quit])?;
 
-            let _tray = TrayIconBuilder::new()
+            let _tray = TrayIconBuilder::with_id(TRAY_ID)
                 .icon(app.default_window_icon().unwrap().clone())
                 .menu(&menu)
                 .tooltip("Performance Guard")
                 .show_menu_on_left_click(false)
                 .on_menu_event(|app, event| {
                     match event.id.as_ref() {
-                        "quit" => app.exit(0),
+                        "quit" => shutdown(app),
                         "show" => {
                             if let Some(window) = app.get_webview_window("main") {
                                 let _ = window.show();
@@ -891,7 +9651,124 @@ fn main() {
             get_global_activity,
             check_foreground,
             get_autostart_enabled,
-            set_autostart_enabled
+            set_autostart_enabled,
+            get_category_rules,
+            set_category_rules,
+            set_process_cpu_cap,
+            clear_process_cpu_cap,
+            get_process_cpu_caps,
+            get_internal_stats,
+            format_bytes,
+            format_duration,
+            get_memory_units,
+            set_memory_units,
+            enter_game_mode,
+            exit_game_mode,
+            verify_process_signature,
+            get_processes_with_other,
+            get_numa_stats,
+            set_process_memory_priority,
+            set_anomaly_sensitivity,
+            export_bundle,
+            import_bundle,
+            get_startup_programs,
+            set_startup_program_enabled,
+            get_process_first_seen,
+            set_screen_scale,
+            get_protected_processes,
+            set_protected_processes,
+            capture_process_tuning,
+            restore_process_tuning,
+            get_memory_metric,
+            set_memory_metric,
+            get_auto_track_foreground,
+            set_auto_track_foreground,
+            set_log_level,
+            get_log_path,
+            get_splash_enabled,
+            set_splash_enabled,
+            get_process_priority,
+            start_profiling_burst,
+            is_app_elevated,
+            request_elevation,
+            get_services,
+            save_ui_preferences,
+            load_ui_preferences,
+            get_memory_growth_leaderboard,
+            get_exclude_own_input,
+            set_exclude_own_input,
+            capture_named_snapshot,
+            get_named_snapshots,
+            diff_named_snapshots,
+            set_metric_precision,
+            shutdown_flushed,
+            set_tray_load_icon,
+            get_disk_activity,
+            get_process_core_attribution,
+            set_display_average_window,
+            boost_process,
+            get_power_draw,
+            kill_processes_by_name,
+            set_auto_optimize_on_idle,
+            get_process_environment,
+            set_activity_app_filter,
+            get_activity_app_filter,
+            get_daily_activity_summary,
+            get_process_rules,
+            set_process_rules,
+            get_gpu_processes,
+            get_hardware_gpu_scheduling,
+            set_hardware_gpu_scheduling,
+            get_game_mode_enabled,
+            set_game_mode_enabled,
+            get_media_device_usage,
+            set_max_list_length,
+            set_crash_reporting,
+            get_crash_reporting,
+            get_recent_crashes,
+            set_stats_server,
+            connect_remote,
+            disconnect_remote,
+            get_remote_sources,
+            get_notification_state,
+            get_process_color,
+            get_frozen_processes,
+            reset_all_data,
+            set_session_tags,
+            set_session_notes,
+            find_sessions_by_tag,
+            get_user_apps,
+            set_gpu_smoothing_alpha,
+            get_gpu_smoothing_alpha,
+            get_power_plan,
+            list_power_plans,
+            set_power_plan,
+            prefetch_icons,
+            set_resume_sessions,
+            get_resume_sessions,
+            run_self_benchmark,
+            add_whitelist_from_directory,
+            watch_whitelist_directory,
+            unwatch_whitelist_directory,
+            get_watched_directories,
+            get_session_history_downsampled,
+            get_network_stats,
+            get_memory_compression,
+            set_self_cpu_budget,
+            get_self_cpu_budget,
+            get_effective_poll_interval,
+            get_scheduled_tasks,
+            set_task_enabled,
+            measure_effective_cpu,
+            kill_process,
+            set_process_priority,
+            suspend_process,
+            resume_process,
+            get_gpus,
+            start_monitoring,
+            stop_monitoring,
+            get_data_recovery,
+            get_display_processes
         ])
         .on_window_event(|window, event| {
             // Intercept close request on main window - hide to tray instead of closing
@@ -902,6 +9779,218 @@ fn main() {
                 }
             }
         })
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|app, event| {
+            if let tauri::RunEvent::Exit = event {
+                // Exits that bypassed shutdown() - never leave a throttled or
+                // game-mode process suspended behind us
+                if let Some(state) = app.try_state::<AppState>() {
+                    release_process_holds(&state);
+                }
+            }
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::OsStr;
+
+    #[test]
+    fn canonical_name_prefers_the_full_exe_file_name() {
+        // sysinfo's name() is cut to 15 characters on older Windows
+        let exe = Path::new("C:/Program Files/Vendor/VeryLongApplicationName.exe");
+        assert_eq!(name_from_exe(Some(exe), OsStr::new("VeryLongApplic")), "VeryLongApplicationName.exe");
+    }
+
+    #[test]
+    fn canonical_name_falls_back_without_an_exe_path() {
+        assert_eq!(name_from_exe(None, OsStr::new("System")), "System");
+        assert_eq!(name_from_exe(Some(Path::new("C:/Tools/..")), OsStr::new("tool.exe")), "tool.exe");
+    }
+
+    // The only test that changes METRIC_PRECISION, tests run in parallel
+    #[test]
+    fn round_metric_uses_the_configured_precision() {
+        METRIC_PRECISION.store(1, Ordering::SeqCst);
+        assert_eq!(round_metric(12.345), 12.3);
+        assert_eq!(round_metric(-0.06), -0.1);
+        assert_eq!(round_metric_f32(99.96), 100.0);
+
+        METRIC_PRECISION.store(0, Ordering::SeqCst);
+        assert_eq!(round_metric(2.5), 3.0);
+
+        METRIC_PRECISION.store(3, Ordering::SeqCst);
+        assert_eq!(round_metric(1.23456), 1.235);
+
+        for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            assert_eq!(round_metric(value), 0.0);
+        }
+        assert_eq!(round_metric_f32(f32::NAN), 0.0);
+        assert_eq!(round_metric_f32(f32::INFINITY), 0.0);
+
+        METRIC_PRECISION.store(1, Ordering::SeqCst);
+    }
+
+    fn whitelist_entry(id: i64, name: &str, exe_path: Option<&str>, is_tracked: bool) -> SavedWhitelistEntry {
+        SavedWhitelistEntry {
+            id,
+            name: name.to_string(),
+            exe_path: exe_path.map(str::to_string),
+            added_date: "2024-01-01T00:00:00+00:00".to_string(),
+            is_tracked,
+        }
+    }
+
+    #[test]
+    fn dedup_whitelist_merges_entries_for_the_same_exe() {
+        let path = "C:/Games/Foo/foo.exe";
+        let entries = vec![
+            whitelist_entry(1, "foo.exe", Some(path), false),
+            whitelist_entry(2, "bar.exe", Some("C:/Tools/bar.exe"), false),
+            whitelist_entry(3, "Foo Game", Some(path), true),
+            whitelist_entry(4, "notes.exe", None, false),
+            whitelist_entry(5, "foo.exe", Some(path), false),
+        ];
+
+        let (deduped, renamed) = dedup_whitelist(entries);
+        let ids: Vec<i64> = deduped.iter().map(|entry| entry.id).collect();
+        assert_eq!(ids, vec![1, 2, 4]);
+        // The first entry is kept and picks up tracking from a duplicate
+        assert!(deduped[0].is_tracked);
+        assert_eq!(deduped[0].name, "foo.exe");
+        assert_eq!(renamed.len(), 1);
+        assert_eq!(renamed.get("Foo Game").map(String::as_str), Some("foo.exe"));
+    }
+
+    #[test]
+    fn dedup_whitelist_keeps_distinct_entries() {
+        let entries = vec![
+            whitelist_entry(1, "a.exe", Some("C:/a.exe"), true),
+            whitelist_entry(2, "b.exe", Some("C:/b.exe"), false),
+        ];
+        let (deduped, renamed) = dedup_whitelist(entries);
+        assert_eq!(deduped.len(), 2);
+        assert!(renamed.is_empty());
+    }
+
+    #[test]
+    fn format_byte_count_uses_the_unit_base() {
+        assert_eq!(format_byte_count(0, MemoryUnits::Binary), "0 B");
+        assert_eq!(format_byte_count(1023, MemoryUnits::Binary), "1023 B");
+        assert_eq!(format_byte_count(1024, MemoryUnits::Binary), "1.0 KiB");
+        assert_eq!(format_byte_count(1_200_000_000, MemoryUnits::Decimal), "1.2 GB");
+        assert_eq!(format_byte_count(1_200_000_000, MemoryUnits::Binary), "1.1 GiB");
+        assert_eq!(format_byte_count(u64::MAX, MemoryUnits::Decimal), "18446744.1 TB");
+    }
+
+    #[test]
+    fn format_byte_count_rolls_over_when_rounding_reaches_the_base() {
+        assert_eq!(format_byte_count(999_949, MemoryUnits::Decimal), "999.9 KB");
+        assert_eq!(format_byte_count(999_950, MemoryUnits::Decimal), "1.0 MB");
+        assert_eq!(format_byte_count(1024 * 1024 - 1, MemoryUnits::Binary), "1.0 MiB");
+    }
+
+    #[test]
+    fn format_duration_matches_the_dashboard() {
+        assert_eq!(format_duration(0), "0s");
+        assert_eq!(format_duration(59), "59s");
+        assert_eq!(format_duration(60), "1m");
+        assert_eq!(format_duration(3599), "59m");
+        assert_eq!(format_duration(3600), "1h 0m");
+        assert_eq!(format_duration(11_520), "3h 12m");
+    }
+
+    #[test]
+    fn sort_by_cpu_breaks_ties_by_pid() {
+        let row = |pid, cpu_percent| ProcessInfo { pid, cpu_percent, ..Default::default() };
+        let mut processes = vec![row(30, 0.0), row(7, 5.0), row(12, 0.0), row(4, 0.0), row(9, 5.0)];
+
+        sort_by_cpu(&mut processes);
+        let order: Vec<u32> = processes.iter().map(|p| p.pid).collect();
+        assert_eq!(order, vec![7, 9, 4, 12, 30]);
+
+        // Idle rows keep their order however the next poll lists them
+        processes.reverse();
+        sort_by_cpu(&mut processes);
+        assert_eq!(processes.iter().map(|p| p.pid).collect::<Vec<_>>(), order);
+    }
+
+    #[test]
+    fn process_names_compare_by_stem() {
+        assert_eq!(process_name_stem("C:\\Games\\Foo.EXE"), "foo");
+        assert_eq!(process_name_stem("/usr/bin/foo"), "foo");
+        assert!(same_process_name("C:\\Games\\Foo.exe", "foo"));
+        assert!(same_process_name("FOO.exe", "foo.EXE"));
+        assert!(!same_process_name("foo.exe", "foobar.exe"));
+    }
+
+    fn session(id: i64) -> SavedSession {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "app_name": "game.exe",
+            "start_time": "2024-01-01T10:00:00+00:00",
+            "end_time": "2024-01-01T11:00:00+00:00",
+            "duration_seconds": 3600,
+            "avg_cpu_percent": 20.0,
+            "avg_memory_mb": 512.0,
+            "peak_cpu_percent": 60.0,
+            "peak_memory_mb": 1024.0,
+            "is_current": false,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn sanitize_leaves_valid_data_alone() {
+        let mut data = AppData { sessions: vec![session(0), session(1)], next_session_id: 2, ..Default::default() };
+        assert!(sanitize_app_data(&mut data).is_empty());
+        assert_eq!(data.next_session_id, 2);
+    }
+
+    #[test]
+    fn sanitize_clamps_out_of_range_metrics() {
+        let mut bad = session(0);
+        bad.avg_cpu_percent = 250.0;
+        bad.peak_gpu_percent = -5.0;
+        bad.avg_memory_mb = f64::NAN;
+        bad.cpu_seconds = f64::INFINITY;
+        let mut data = AppData { sessions: vec![bad], next_session_id: 1, ..Default::default() };
+
+        assert_eq!(sanitize_app_data(&mut data).len(), 1);
+        let fixed = &data.sessions[0];
+        assert_eq!(fixed.avg_cpu_percent, 100.0);
+        assert_eq!(fixed.peak_gpu_percent, 0.0);
+        assert_eq!(fixed.avg_memory_mb, 0.0);
+        assert_eq!(fixed.cpu_seconds, 0.0);
+    }
+
+    #[test]
+    fn sanitize_resets_negative_durations() {
+        let mut bad = session(0);
+        bad.duration_seconds = -30;
+        let mut data = AppData { sessions: vec![bad], next_session_id: 1, ..Default::default() };
+
+        assert_eq!(sanitize_app_data(&mut data).len(), 1);
+        assert_eq!(data.sessions[0].duration_seconds, 0);
+    }
+
+    #[test]
+    fn sanitize_gives_duplicate_ids_new_ids() {
+        let mut data = AppData { sessions: vec![session(3), session(3), session(5)], next_session_id: 6, ..Default::default() };
+
+        sanitize_app_data(&mut data);
+        let ids: Vec<i64> = data.sessions.iter().map(|s| s.id).collect();
+        assert_eq!(ids, vec![3, 6, 5]);
+        assert_eq!(data.next_session_id, 7);
+    }
+
+    #[test]
+    fn sanitize_moves_next_session_id_past_existing_ids() {
+        let mut data = AppData { sessions: vec![session(0), session(9)], next_session_id: 4, ..Default::default() };
+
+        assert_eq!(sanitize_app_data(&mut data).len(), 1);
+        assert_eq!(data.next_session_id, 10);
+    }
 }
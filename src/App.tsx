@@ -6,7 +6,7 @@ import { DashboardView, WhitelistView, DetailsView, SettingsView } from './views
 import { AuthProvider, useAuth } from './context/AuthContext';
 import { PlatformProvider } from './contexts/PlatformContext';
 import { useFirestoreSync } from './hooks/useFirestoreSync';
-import { getProcesses, getSystemStats, saveAppData, loadAppData, signalAppReady, getAppIcon, getGlobalActivity } from './api/tauri';
+import { getProcesses, getDisplayProcesses, getSystemStats, saveAppData, loadAppData, signalAppReady, getAppIcon, getGlobalActivity, shutdownFlushed, getEffectivePollInterval } from './api/tauri';
 import type { ViewType, WhitelistEntry, ProcessInfo, SystemStats, AppSummary, Session, PerformanceSnapshot } from './types';
 
 // Track session data for each app
@@ -19,10 +19,24 @@ interface SessionTracker {
     gpuSamples: number[];
     performanceHistory: PerformanceSnapshot[]; // Real-time performance data for charts
     notRunningCount: number; // Counter for grace period before ending session
+    lastSampleTime: number | null; // When the current session was last sampled (ms)
   };
 }
 
 const MAX_PERFORMANCE_POINTS = 1800; // Keep last 1800 data points (1 hour at 2s interval)
+const SESSION_CHECKPOINT_MS = 60000; // Save running sessions so a crash doesn't lose them
+
+// Every session to persist - running ones keep is_current so the backend can close/resume them
+const sessionsForSave = (tracker: SessionTracker): Session[] => {
+  const sessions: Session[] = [];
+  Object.values(tracker).forEach(t => {
+    if (t.currentSession) {
+      sessions.push(t.currentSession);
+    }
+    sessions.push(...t.sessions);
+  });
+  return sessions;
+};
 
 // Extract main process name from helper subprocess (e.g., "opera" from "opera_crashreporter")
 // Returns null if this is not a helper subprocess
@@ -66,8 +80,11 @@ const aggregateProcesses = (procs: ProcessInfo[]): Map<string, AggregatedProcess
           ...existing,
           cpu_percent: existing.cpu_percent + p.cpu_percent,
           memory_mb: existing.memory_mb + p.memory_mb,
+          peak_working_set_mb: existing.peak_working_set_mb + p.peak_working_set_mb,
+          peak_commit_mb: existing.peak_commit_mb + p.peak_commit_mb,
           memory_percent: existing.memory_percent + p.memory_percent,
           gpu_percent: (existing.gpu_percent || 0) + (p.gpu_percent || 0),
+          gpu_memory_mb: existing.gpu_memory_mb + p.gpu_memory_mb,
           allPids: [...existing.allPids, p.pid],
         });
       } else {
@@ -87,8 +104,11 @@ const aggregateProcesses = (procs: ProcessInfo[]): Map<string, AggregatedProcess
           ...existing,
           cpu_percent: existing.cpu_percent + p.cpu_percent,
           memory_mb: existing.memory_mb + p.memory_mb,
+          peak_working_set_mb: existing.peak_working_set_mb + p.peak_working_set_mb,
+          peak_commit_mb: existing.peak_commit_mb + p.peak_commit_mb,
           memory_percent: existing.memory_percent + p.memory_percent,
           gpu_percent: (existing.gpu_percent || 0) + (p.gpu_percent || 0),
+          gpu_memory_mb: existing.gpu_memory_mb + p.gpu_memory_mb,
           allPids: [...existing.allPids, p.pid],
         });
       }
@@ -112,6 +132,17 @@ function AppContent() {
     used_memory_gb: 0,
     available_memory_gb: 0,
     cpu_cores: 0,
+    is_warming_up: true,
+    page_faults_per_sec: null,
+    committed_percent: null,
+    gpu_watts: null,
+    cpu_watts: null,
+    network_received_bytes_per_sec: 0,
+    network_transmitted_bytes_per_sec: 0,
+    cpu_temp_c: null,
+    gpu_temp_c: null,
+    per_core_usage: [],
+    cpu_frequency_mhz: [],
   });
   const [appSummaries, setAppSummaries] = useState<AppSummary[]>([]);
   const [sessions, setSessions] = useState<Session[]>([]);
@@ -126,6 +157,7 @@ function AppContent() {
   const nextSessionIdRef = useRef(1);
   const isInitializedRef = useRef(false);
   const previousSessionsRef = useRef<Session[]>([]);
+  const lastCheckpointRef = useRef(Date.now());
   const appReadySignaledRef = useRef(false);
   const iconFetchedRef = useRef<Set<string>>(new Set());
   const firestoreSyncTimeoutRef = useRef<ReturnType<typeof setTimeout> | null>(null);
@@ -230,9 +262,21 @@ function AppContent() {
               gpuSamples: [],
               performanceHistory: [],
               notRunningCount: 0,
+              lastSampleTime: null,
             };
           }
-          sessionTrackerRef.current[appNameLower].sessions.push(session);
+          const appTracker = sessionTrackerRef.current[appNameLower];
+          if (session.is_current) {
+            // Resumed by the backend after a restart - keep sampling into it
+            const history = session.performance_history || [];
+            appTracker.currentSession = session;
+            appTracker.performanceHistory = [...history];
+            appTracker.cpuSamples = history.map(p => p.cpu_percent);
+            appTracker.memorySamples = history.map(p => p.memory_mb);
+            appTracker.gpuSamples = history.map(p => p.gpu_percent);
+          } else {
+            appTracker.sessions.push(session);
+          }
         });
 
         previousSessionsRef.current = finalSessions;
@@ -249,7 +293,7 @@ function AppContent() {
   // Fetch processes (only on demand for whitelist view)
   const fetchProcesses = useCallback(async () => {
     try {
-      const procs = await getProcesses();
+      const procs = await getDisplayProcesses();
       setProcesses(procs);
     } catch {
       // Ignore fetch errors
@@ -291,6 +335,7 @@ function AppContent() {
               gpuSamples: [],
               performanceHistory: [],
               notRunningCount: 0,
+              lastSampleTime: null,
             };
           }
         });
@@ -316,6 +361,7 @@ function AppContent() {
               gpuSamples: [],
               performanceHistory: [],
               notRunningCount: 0,
+              lastSampleTime: null,
             };
           }
 
@@ -346,6 +392,9 @@ function AppContent() {
             const totalMemory = runningProcs.reduce((sum, p) => sum + p.memory_mb, 0);
             const totalMemoryPercent = Math.min(100, runningProcs.reduce((sum, p) => sum + p.memory_percent, 0));
             const totalGpu = Math.min(100, runningProcs.reduce((sum, p) => sum + (p.gpu_percent || 0), 0));
+            // OS-kept peaks catch spikes between our samples
+            const peakWorkingSet = runningProcs.reduce((sum, p) => sum + p.peak_working_set_mb, 0);
+            const peakCommit = runningProcs.reduce((sum, p) => sum + p.peak_commit_mb, 0);
 
             // Check if this app is in foreground (compare foreground PID with all PIDs including helpers)
             const pids = aggregatedProc?.allPids || [];
@@ -363,6 +412,8 @@ function AppContent() {
               gpu_percent: totalGpu,
               user_activity_percent: activityPercent,
               is_foreground: isForeground,
+              // Left out rather than undefined, Firestore rejects undefined fields
+              ...(globalActivity.foreground_app ? { foreground_app: globalActivity.foreground_app } : {}),
               keyboard_clicks: isForeground ? globalActivity.keyboard_clicks : 0,
               mouse_pixels: isForeground ? globalActivity.mouse_pixels : 0,
             };
@@ -373,6 +424,13 @@ function AppContent() {
               appTracker.performanceHistory = appTracker.performanceHistory.slice(-MAX_PERFORMANCE_POINTS);
             }
 
+            // CPU time since the previous sample - uses the real gap so missed cycles still count
+            const elapsedSeconds = appTracker.lastSampleTime !== null
+              ? (now.getTime() - appTracker.lastSampleTime) / 1000
+              : 0;
+            const cpuSecondsDelta = totalCpu / 100 * stats.cpu_cores * elapsedSeconds;
+            appTracker.lastSampleTime = now.getTime();
+
             if (!appTracker.currentSession) {
               // Start new session
               const newSessionId = nextSessionIdRef.current++;
@@ -388,6 +446,9 @@ function AppContent() {
                 peak_cpu_percent: totalCpu,
                 peak_memory_mb: totalMemory,
                 peak_gpu_percent: totalGpu,
+                cpu_seconds: 0,
+                peak_working_set_mb: peakWorkingSet,
+                peak_commit_mb: peakCommit,
                 is_current: true,
                 performance_history: [...appTracker.performanceHistory],
               };
@@ -419,6 +480,9 @@ function AppContent() {
                 peak_cpu_percent: peakCpu,
                 peak_memory_mb: peakMemory,
                 peak_gpu_percent: peakGpu,
+                cpu_seconds: (appTracker.currentSession.cpu_seconds || 0) + cpuSecondsDelta,
+                peak_working_set_mb: Math.max(appTracker.currentSession.peak_working_set_mb || 0, peakWorkingSet),
+                peak_commit_mb: Math.max(appTracker.currentSession.peak_commit_mb || 0, peakCommit),
                 performance_history: [...appTracker.performanceHistory],
               };
             }
@@ -441,12 +505,10 @@ function AppContent() {
               appTracker.gpuSamples = [];
               appTracker.performanceHistory = [];
               appTracker.notRunningCount = 0;
+              appTracker.lastSampleTime = null;
 
               // Save data when session ends
-              const allSessionsToSave: Session[] = [];
-              Object.values(tracker).forEach(t => {
-                allSessionsToSave.push(...t.sessions);
-              });
+              const allSessionsToSave = sessionsForSave(tracker);
 
               // Save locally
               saveAppData(whitelist, allSessionsToSave, nextSessionIdRef.current)
@@ -462,15 +524,16 @@ function AppContent() {
         }
 
         // Collect all sessions
-        const allSessions: Session[] = [];
-        Object.values(tracker).forEach(t => {
-          if (t.currentSession) {
-            allSessions.push(t.currentSession);
-          }
-          allSessions.push(...t.sessions);
-        });
+        const allSessions = sessionsForSave(tracker);
         setSessions(allSessions);
 
+        // Checkpoint running sessions locally
+        if (now.getTime() - lastCheckpointRef.current >= SESSION_CHECKPOINT_MS && allSessions.some(s => s.is_current)) {
+          lastCheckpointRef.current = now.getTime();
+          saveAppData(whitelist, allSessions, nextSessionIdRef.current)
+            .catch(() => {});
+        }
+
         // Collect performance data for all apps - create new array copies to trigger re-render
         const allPerformanceData: { [appName: string]: PerformanceSnapshot[] } = {};
         Object.entries(tracker).forEach(([appNameLower, t]) => {
@@ -614,13 +677,27 @@ function AppContent() {
   // eslint-disable-next-line react-hooks/exhaustive-deps
   }, [whitelist, appIcons]);
 
-  // Initial fetch and periodic updates for system stats
+  // Initial fetch and periodic updates for system stats, the backend
+  // stretches the interval when the app exceeds its own CPU budget
   useEffect(() => {
-    fetchSystemData().catch(() => {}); // Ignore initial fetch errors
-    const interval = setInterval(() => {
-      fetchSystemData().catch(() => {}); // Ignore periodic fetch errors
-    }, 2000);
-    return () => clearInterval(interval);
+    let cancelled = false;
+    let timeout: ReturnType<typeof setTimeout> | null = null;
+
+    const poll = async () => {
+      await fetchSystemData().catch(() => {}); // Ignore fetch errors
+      const delay = await getEffectivePollInterval().catch(() => 2000);
+      if (!cancelled) {
+        timeout = setTimeout(poll, delay);
+      }
+    };
+    poll();
+
+    return () => {
+      cancelled = true;
+      if (timeout) {
+        clearTimeout(timeout);
+      }
+    };
   }, [fetchSystemData]);
 
   // Handle visibility change (e.g., after system wake from sleep)
@@ -678,13 +755,15 @@ function AppContent() {
     // Don't save during initial load or before data is loaded (e.g., after system wake)
     if (!isInitializedRef.current || !appDataLoaded) return;
 
-    const allSessionsToSave: Session[] = [];
-    Object.values(sessionTrackerRef.current).forEach(t => {
-      allSessionsToSave.push(...t.sessions);
-    });
+    const allSessionsToSave = sessionsForSave(sessionTrackerRef.current);
 
-    // Save locally immediately
+    // Save locally immediately, picking up duplicates the backend merged
     saveAppData(whitelist, allSessionsToSave, nextSessionIdRef.current)
+      .then(stored => {
+        if (stored.length !== whitelist.length) {
+          setWhitelist(stored);
+        }
+      })
       .catch(() => {});
 
     // Sync whitelist to cloud with debounce (30 seconds)
@@ -702,6 +781,59 @@ function AppContent() {
     }
   }, [whitelist, user, isOnline, firestoreSync, appDataLoaded]);
 
+  // Save running sessions as ended before the backend quits
+  useEffect(() => {
+    const unlistenPromise = listen('app-shutdown', async () => {
+      if (appDataLoaded) {
+        const now = new Date().toISOString();
+        const allSessionsToSave: Session[] = [];
+        Object.values(sessionTrackerRef.current).forEach(t => {
+          if (t.currentSession) {
+            allSessionsToSave.push({ ...t.currentSession, end_time: now, is_current: false });
+          }
+          allSessionsToSave.push(...t.sessions);
+        });
+        await saveAppData(whitelist, allSessionsToSave, nextSessionIdRef.current).catch(() => {});
+      }
+      shutdownFlushed().catch(() => {});
+    });
+
+    return () => {
+      unlistenPromise.then(unlisten => unlisten());
+    };
+  }, [whitelist, appDataLoaded]);
+
+  // Backend reset the data file, drop local state so it isn't saved back
+  useEffect(() => {
+    const unlistenPromise = listen('app-data-reset', () => {
+      sessionTrackerRef.current = {};
+      nextSessionIdRef.current = 1;
+      setSelectedAppName(null);
+      setSessions([]);
+      setWhitelist([]);
+    });
+
+    return () => {
+      unlistenPromise.then(unlisten => unlisten());
+    };
+  }, []);
+
+  // Backend whitelisted executables from a folder, merge them so the next save keeps them
+  useEffect(() => {
+    const unlistenPromise = listen<WhitelistEntry[]>('whitelist-updated', (event) => {
+      setWhitelist(prev => {
+        const added = event.payload
+          .map(entry => ({ ...entry, exe_path: entry.exe_path ?? undefined }))
+          .filter(entry => !prev.some(w => w.id === entry.id));
+        return added.length > 0 ? [...prev, ...added] : prev;
+      });
+    });
+
+    return () => {
+      unlistenPromise.then(unlisten => unlisten());
+    };
+  }, []);
+
   // Cleanup Firestore sync timer on unmount
   useEffect(() => {
     return () => {
@@ -1,5 +1,6 @@
 import { invoke } from '@tauri-apps/api/core';
-import type { ProcessInfo, SystemStats, WhitelistEntry, Session } from '../types';
+import { listen, type UnlistenFn } from '@tauri-apps/api/event';
+import type { ProcessInfo, SystemStats, WhitelistEntry, Session, PerformanceSnapshot } from '../types';
 
 // Backend returns slightly different types, map them to our frontend types
 interface BackendProcessInfo {
@@ -7,11 +8,38 @@ interface BackendProcessInfo {
   name: string;
   cpu_percent: number;
   memory_mb: number;
+  commit_mb: number;
+  peak_working_set_mb: number;
+  peak_commit_mb: number;
   memory_percent: number;
   gpu_percent: number;
+  gpu_memory_mb: number;
+  cpu_percent_avg: number;
+  gpu_percent_avg: number;
+  uses_gpu: boolean;
+  gpu_adapter: string | null;
   status: string;
   create_time: number;
   exe_path: string | null;
+  category: string;
+  description: string | null;
+  company: string | null;
+  is_new_binary: boolean;
+  is_warming_up: boolean;
+  service_names: string[];
+  has_visible_window: boolean;
+  io_wait_percent: number | null;
+  context_switches_per_sec: number | null;
+  ancestor_chain: string[];
+  tcp_connections: number;
+  udp_connections: number;
+  gdi_objects: number;
+  user_objects: number;
+  disk_read_ops_per_sec: number;
+  disk_write_ops_per_sec: number;
+  is_frozen: boolean;
+  color_hex: string;
+  icon?: string;
 }
 
 interface BackendSystemStats {
@@ -21,25 +49,79 @@ interface BackendSystemStats {
   used_memory_gb: number;
   available_memory_gb: number;
   cpu_cores: number;
+  is_warming_up: boolean;
+  page_faults_per_sec: number | null;
+  committed_percent: number | null;
+  gpu_watts: number | null;
+  cpu_watts: number | null;
+  network_received_bytes_per_sec: number;
+  network_transmitted_bytes_per_sec: number;
+  cpu_temp_c: number | null;
+  gpu_temp_c: number | null;
+  per_core_usage: number[];
+  cpu_frequency_mhz: number[];
 }
 
-export async function getProcesses(): Promise<ProcessInfo[]> {
-  const processes = await invoke<BackendProcessInfo[]>('get_processes');
-  return processes.map(p => ({
+function mapProcessInfo(p: BackendProcessInfo): ProcessInfo {
+  return {
     pid: p.pid,
     name: p.name,
     cpu_percent: p.cpu_percent,
     memory_mb: p.memory_mb,
+    commit_mb: p.commit_mb,
+    peak_working_set_mb: p.peak_working_set_mb,
+    peak_commit_mb: p.peak_commit_mb,
     memory_percent: p.memory_percent,
     gpu_percent: p.gpu_percent,
+    gpu_memory_mb: p.gpu_memory_mb,
+    cpu_percent_avg: p.cpu_percent_avg,
+    gpu_percent_avg: p.gpu_percent_avg,
+    uses_gpu: p.uses_gpu,
+    gpu_adapter: p.gpu_adapter ?? undefined,
     status: p.status,
     create_time: p.create_time,
     exe_path: p.exe_path ?? undefined,
-  }));
+    category: p.category,
+    description: p.description ?? undefined,
+    company: p.company ?? undefined,
+    is_new_binary: p.is_new_binary,
+    is_warming_up: p.is_warming_up,
+    service_names: p.service_names,
+    has_visible_window: p.has_visible_window,
+    io_wait_percent: p.io_wait_percent ?? undefined,
+    context_switches_per_sec: p.context_switches_per_sec ?? undefined,
+    ancestor_chain: p.ancestor_chain,
+    tcp_connections: p.tcp_connections,
+    udp_connections: p.udp_connections,
+    gdi_objects: p.gdi_objects,
+    user_objects: p.user_objects,
+    disk_read_ops_per_sec: p.disk_read_ops_per_sec,
+    disk_write_ops_per_sec: p.disk_write_ops_per_sec,
+    is_frozen: p.is_frozen,
+    color_hex: p.color_hex,
+    icon: p.icon,
+  };
 }
 
-export async function getSystemStats(): Promise<SystemStats> {
-  const stats = await invoke<BackendSystemStats>('get_system_stats');
+// source: a remote id from connectRemote, omitted for this machine
+// 'basic' leaves exePath, version fields and status empty for a cheaper poll
+export async function getProcesses(source?: string, detailLevel?: 'basic' | 'full'): Promise<ProcessInfo[]> {
+  const processes = await invoke<BackendProcessInfo[]>('get_processes', { source, detailLevel });
+  return processes.map(mapProcessInfo);
+}
+
+// Top N processes by CPU plus an aggregated "Other (K processes)" entry (pid OTHER_PROCESSES_PID)
+export const OTHER_PROCESSES_PID = 4294967295;
+
+export async function getProcessesWithOther(n: number): Promise<{ top: ProcessInfo[]; other: ProcessInfo }> {
+  const result = await invoke<{ top: BackendProcessInfo[]; other: BackendProcessInfo }>('get_processes_with_other', { n });
+  return {
+    top: result.top.map(mapProcessInfo),
+    other: mapProcessInfo(result.other),
+  };
+}
+
+function mapSystemStats(stats: BackendSystemStats): SystemStats {
   return {
     cpu_percent: stats.cpu_percent,
     memory_percent: stats.memory_percent,
@@ -47,23 +129,40 @@ export async function getSystemStats(): Promise<SystemStats> {
     used_memory_gb: stats.used_memory_gb,
     available_memory_gb: stats.available_memory_gb,
     cpu_cores: stats.cpu_cores,
+    is_warming_up: stats.is_warming_up,
+    page_faults_per_sec: stats.page_faults_per_sec,
+    committed_percent: stats.committed_percent,
+    gpu_watts: stats.gpu_watts,
+    cpu_watts: stats.cpu_watts,
+    network_received_bytes_per_sec: stats.network_received_bytes_per_sec,
+    network_transmitted_bytes_per_sec: stats.network_transmitted_bytes_per_sec,
+    cpu_temp_c: stats.cpu_temp_c,
+    gpu_temp_c: stats.gpu_temp_c,
+    per_core_usage: stats.per_core_usage,
+    cpu_frequency_mhz: stats.cpu_frequency_mhz,
   };
 }
 
+export async function getSystemStats(source?: string): Promise<SystemStats> {
+  const stats = await invoke<BackendSystemStats>('get_system_stats', { source });
+  return mapSystemStats(stats);
+}
+
+// Per-NUMA-node memory (single node on non-NUMA machines)
+export interface NumaNode {
+  node: number;
+  // null when the node's installed size is unknown
+  total_mb: number | null;
+  available_mb: number;
+}
+
+export async function getNumaStats(): Promise<NumaNode[]> {
+  return await invoke<NumaNode[]>('get_numa_stats');
+}
+
 export async function getProcessByPid(pid: number): Promise<ProcessInfo | null> {
   const process = await invoke<BackendProcessInfo | null>('get_process_by_pid', { pid });
-  if (!process) return null;
-  return {
-    pid: process.pid,
-    name: process.name,
-    cpu_percent: process.cpu_percent,
-    memory_mb: process.memory_mb,
-    memory_percent: process.memory_percent,
-    gpu_percent: process.gpu_percent,
-    status: process.status,
-    create_time: process.create_time,
-    exe_path: process.exe_path ?? undefined,
-  };
+  return process ? mapProcessInfo(process) : null;
 }
 
 // Data persistence types
@@ -83,6 +182,7 @@ interface SavedPerformanceSnapshot {
   gpu_percent: number;
   user_activity_percent: number;
   is_foreground: boolean;
+  foreground_app?: string | null;
   keyboard_clicks?: number;
   mouse_pixels?: number;
 }
@@ -99,8 +199,14 @@ interface SavedSession {
   peak_cpu_percent: number;
   peak_memory_mb: number;
   peak_gpu_percent: number;
+  cpu_seconds?: number;
+  peak_working_set_mb?: number;
+  peak_commit_mb?: number;
   is_current: boolean;
   performance_history: SavedPerformanceSnapshot[];
+  // Backend-owned, see setSessionTags / setSessionNotes
+  tags?: string[];
+  notes?: string;
 }
 
 interface AppData {
@@ -113,7 +219,7 @@ export async function saveAppData(
   whitelist: WhitelistEntry[],
   sessions: Session[],
   nextSessionId: number
-): Promise<void> {
+): Promise<WhitelistEntry[]> {
   const savedWhitelist: SavedWhitelistEntry[] = whitelist.map(w => ({
     id: w.id,
     name: w.name,
@@ -122,8 +228,8 @@ export async function saveAppData(
     is_tracked: w.is_tracked,
   }));
 
+  // Running sessions are saved too (is_current), the backend closes or resumes them after a crash
   const savedSessions: SavedSession[] = sessions
-    .filter(s => !s.is_current) // Only save completed sessions
     .map(s => ({
       id: s.id,
       app_name: s.app_name,
@@ -136,7 +242,10 @@ export async function saveAppData(
       peak_cpu_percent: s.peak_cpu_percent,
       peak_memory_mb: s.peak_memory_mb,
       peak_gpu_percent: s.peak_gpu_percent,
-      is_current: false,
+      cpu_seconds: s.cpu_seconds || 0,
+      peak_working_set_mb: s.peak_working_set_mb || 0,
+      peak_commit_mb: s.peak_commit_mb || 0,
+      is_current: s.is_current,
       performance_history: (s.performance_history || []).map(p => ({
         timestamp: p.timestamp,
         cpu_percent: p.cpu_percent,
@@ -145,32 +254,36 @@ export async function saveAppData(
         gpu_percent: p.gpu_percent,
         user_activity_percent: p.user_activity_percent || 0,
         is_foreground: p.is_foreground ?? true,
+        foreground_app: p.foreground_app ?? null,
       })),
     }));
 
-  await invoke('save_app_data', {
+  // The backend merges entries for the same executable and returns what it stored
+  const stored = await invoke<SavedWhitelistEntry[]>('save_app_data', {
     whitelist: savedWhitelist,
     sessions: savedSessions,
     nextSessionId,
   });
+  return stored.map(mapSavedWhitelistEntry);
 }
 
-export async function loadAppData(): Promise<{
-  whitelist: WhitelistEntry[];
-  sessions: Session[];
-  nextSessionId: number;
-}> {
-  const data = await invoke<AppData>('load_app_data');
-
-  const whitelist: WhitelistEntry[] = data.whitelist.map(w => ({
-    id: w.id,
-    name: w.name,
-    exe_path: w.exe_path ?? undefined,
-    added_date: w.added_date,
-    is_tracked: w.is_tracked,
-  }));
+function mapSavedSnapshot(p: SavedPerformanceSnapshot): PerformanceSnapshot {
+  return {
+    timestamp: p.timestamp,
+    cpu_percent: p.cpu_percent,
+    memory_mb: p.memory_mb,
+    memory_percent: p.memory_percent,
+    gpu_percent: p.gpu_percent || 0,
+    user_activity_percent: p.user_activity_percent || 0,
+    is_foreground: p.is_foreground ?? true,
+    ...(p.foreground_app ? { foreground_app: p.foreground_app } : {}),
+    keyboard_clicks: p.keyboard_clicks || 0,
+    mouse_pixels: p.mouse_pixels || 0,
+  };
+}
 
-  const sessions: Session[] = data.sessions.map(s => ({
+function mapSavedSession(s: SavedSession): Session {
+  return {
     id: s.id,
     app_name: s.app_name,
     start_time: s.start_time,
@@ -182,19 +295,37 @@ export async function loadAppData(): Promise<{
     peak_cpu_percent: s.peak_cpu_percent,
     peak_memory_mb: s.peak_memory_mb,
     peak_gpu_percent: s.peak_gpu_percent || 0,
-    is_current: false,
-    performance_history: (s.performance_history || []).map(p => ({
-      timestamp: p.timestamp,
-      cpu_percent: p.cpu_percent,
-      memory_mb: p.memory_mb,
-      memory_percent: p.memory_percent,
-      gpu_percent: p.gpu_percent || 0,
-      user_activity_percent: p.user_activity_percent || 0,
-      is_foreground: p.is_foreground ?? true,
-      keyboard_clicks: p.keyboard_clicks || 0,
-      mouse_pixels: p.mouse_pixels || 0,
-    })),
-  }));
+    cpu_seconds: s.cpu_seconds || 0,
+    peak_working_set_mb: s.peak_working_set_mb || 0,
+    peak_commit_mb: s.peak_commit_mb || 0,
+    // Still set only for a session the backend resumed at startup
+    is_current: s.is_current,
+    performance_history: (s.performance_history || []).map(mapSavedSnapshot),
+    tags: s.tags || [],
+    notes: s.notes || '',
+  };
+}
+
+function mapSavedWhitelistEntry(w: SavedWhitelistEntry): WhitelistEntry {
+  return {
+    id: w.id,
+    name: w.name,
+    exe_path: w.exe_path ?? undefined,
+    added_date: w.added_date,
+    is_tracked: w.is_tracked,
+  };
+}
+
+export async function loadAppData(): Promise<{
+  whitelist: WhitelistEntry[];
+  sessions: Session[];
+  nextSessionId: number;
+}> {
+  const data = await invoke<AppData>('load_app_data');
+
+  const whitelist: WhitelistEntry[] = data.whitelist.map(mapSavedWhitelistEntry);
+
+  const sessions: Session[] = data.sessions.map(mapSavedSession);
 
   return {
     whitelist,
@@ -203,11 +334,32 @@ export async function loadAppData(): Promise<{
   };
 }
 
+// Set when the data file was unreadable this launch and loadAppData got a backup or defaults
+export interface DataRecovery {
+  restored_from: 'backup' | 'defaults';
+  corrupt_file: string | null; // Where the unreadable file was moved
+  error: string;
+}
+
+export async function getDataRecovery(): Promise<DataRecovery | null> {
+  return await invoke<DataRecovery | null>('get_data_recovery');
+}
+
 // Signal that main app is ready (triggers splash exit animation)
 export async function signalAppReady(): Promise<void> {
   await invoke('signal_app_ready');
 }
 
+export interface IconReady {
+  exe_path: string;
+  icon: string | null;
+}
+
+// Queue icon extraction in the backend, results arrive as icon-ready events
+export async function prefetchIcons(exePaths: string[]): Promise<void> {
+  await invoke('prefetch_icons', { exePaths });
+}
+
 // Get application icon from exe file as base64 PNG
 export async function getAppIcon(exePath: string): Promise<string | null> {
   try {
@@ -227,6 +379,7 @@ interface UserActivityResult {
 interface GlobalActivityResult {
   activity_percent: number;
   foreground_pid: number | null;
+  foreground_app: string | null; // null when it's this app or unknown
   keyboard_clicks: number;
   mouse_pixels: number;
 }
@@ -254,3 +407,831 @@ export async function getAutostartEnabled(): Promise<boolean> {
 export async function setAutostartEnabled(enabled: boolean): Promise<void> {
   await invoke('set_autostart_enabled', { enabled });
 }
+
+// Process category rules: [pattern, category] pairs checked before the built-in ones
+export async function getCategoryRules(): Promise<[string, string][]> {
+  return await invoke<[string, string][]>('get_category_rules');
+}
+
+export async function setCategoryRules(rules: [string, string][]): Promise<void> {
+  await invoke('set_category_rules', { rules });
+}
+
+// Soft CPU cap (percent of the whole system) enforced by suspending the process on a duty cycle
+export async function setProcessCpuCap(pid: number, maxPercent: number): Promise<void> {
+  await invoke('set_process_cpu_cap', { pid, maxPercent });
+}
+
+export async function clearProcessCpuCap(pid: number): Promise<void> {
+  await invoke('clear_process_cpu_cap', { pid });
+}
+
+export async function getProcessCpuCaps(): Promise<Record<number, number>> {
+  return await invoke<Record<number, number>>('get_process_cpu_caps');
+}
+
+// Internal backend state sizes (debug / leak hunting)
+export interface InternalStats {
+  maps: { name: string; entries: number; approx_bytes: number }[];
+  last_cycle_ms: number | null;
+}
+
+export async function getInternalStats(): Promise<InternalStats> {
+  return await invoke<InternalStats>('get_internal_stats');
+}
+
+// Canonical byte/duration formatting shared by all views, bytes follow the memory-units setting
+export type MemoryUnits = 'binary' | 'decimal';
+
+export async function getMemoryUnits(): Promise<MemoryUnits> {
+  return await invoke<MemoryUnits>('get_memory_units');
+}
+
+export async function setMemoryUnits(units: MemoryUnits): Promise<void> {
+  await invoke('set_memory_units', { units });
+}
+
+export async function formatBytes(bytes: number): Promise<string> {
+  return await invoke<string>('format_bytes', { bytes });
+}
+
+// For the *_mb fields, which are in MiB
+export async function formatMegabytes(mb: number): Promise<string> {
+  return await formatBytes(Math.round(mb * 1024 * 1024));
+}
+
+export async function formatDuration(secs: number): Promise<string> {
+  return await invoke<string>('format_duration', { secs });
+}
+
+// Game mode: suspend whitelisted apps that aren't in the foreground
+export async function enterGameMode(keepPids: number[]): Promise<number[]> {
+  const result = await invoke<{ suspended: number[] }>('enter_game_mode', { keepPids });
+  return result.suspended;
+}
+
+export async function exitGameMode(): Promise<number[]> {
+  return await invoke<number[]>('exit_game_mode');
+}
+
+// Authenticode signature of a process's executable
+export interface SignatureInfo {
+  signed: boolean;
+  signer: string | null;
+  trusted: boolean;
+}
+
+export async function verifyProcessSignature(pid: number): Promise<SignatureInfo> {
+  return await invoke<SignatureInfo>('verify_process_signature', { pid });
+}
+
+// Memory priority 0-7 (lower = trimmed first, Windows default 5)
+export async function setProcessMemoryPriority(pid: number, priority: number): Promise<void> {
+  await invoke('set_process_memory_priority', { pid, priority });
+}
+
+// CPU spike detection: get_processes emits 'cpu-anomaly' when a process jumps
+// above mean + k * stddev of its own recent usage
+export interface CpuAnomaly {
+  pid: number;
+  name: string;
+  cpu_percent: number;
+  mean_percent: number;
+  stddev_percent: number;
+  silenced: boolean; // Focus Assist / presentation / fullscreen: record, don't notify
+}
+
+export async function setAnomalySensitivity(k: number): Promise<void> {
+  await invoke('set_anomaly_sensitivity', { k });
+}
+
+// Support bundle: zip with app data, system report and per-session CSVs
+export async function exportBundle(destPath: string): Promise<void> {
+  await invoke('export_bundle', { destPath });
+}
+
+// Restores the bundled data file; reload app state afterwards
+export async function importBundle(srcPath: string): Promise<void> {
+  await invoke('import_bundle', { srcPath });
+}
+
+// get_processes emits 'gpu-memory-leak-suspected' when a process's VRAM climbs steadily
+export interface GpuMemoryLeak {
+  pid: number;
+  name: string;
+  current_mb: number;
+  growth_mb_per_min: number;
+  silenced: boolean;
+}
+
+// get_processes emits 'gui-resource-leak-suspected' (checked every 30s) when a process
+// nears the per-process GDI or USER object limit
+export interface GuiResourceLeak {
+  pid: number;
+  name: string;
+  gdi_objects: number;
+  user_objects: number;
+  limit: number;
+  silenced: boolean;
+}
+
+// Programs launched at logon (Run keys + Startup folders)
+export interface StartupEntry {
+  // Registry value name (Run keys) or file name (Startup folders)
+  name: string;
+  command: string;
+  location: string;
+  enabled: boolean;
+}
+
+export async function getStartupPrograms(): Promise<StartupEntry[]> {
+  return await invoke<StartupEntry[]>('get_startup_programs');
+}
+
+export async function setStartupProgramEnabled(entry: StartupEntry, enabled: boolean): Promise<void> {
+  await invoke('set_startup_program_enabled', { location: entry.location, name: entry.name, enabled });
+}
+
+// When an executable was first observed running (RFC 3339), null if never
+export async function getProcessFirstSeen(exePath: string): Promise<string | null> {
+  return await invoke<string | null>('get_process_first_seen', { exePath });
+}
+
+// Display scale used to normalize mouse distance (1.0 = 96 DPI), null = auto-detect
+export async function setScreenScale(dpiScale: number | null): Promise<void> {
+  await invoke('set_screen_scale', { dpiScale });
+}
+
+// Protected processes: destructive commands fail with "protected process" for these
+// (built-in system list + user additions)
+export async function getProtectedProcesses(): Promise<string[]> {
+  return await invoke<string[]>('get_protected_processes');
+}
+
+export async function setProtectedProcesses(names: string[]): Promise<void> {
+  await invoke('set_protected_processes', { names });
+}
+
+// Process tuning snapshot (null = couldn't be read, skipped on restore)
+export interface TuningSnapshot {
+  priority_class: number | null;
+  affinity_mask: number | null;
+  io_priority: number | null;
+  memory_priority: number | null;
+  eco_mode: boolean | null;
+}
+
+export async function captureProcessTuning(pid: number): Promise<TuningSnapshot> {
+  return await invoke<TuningSnapshot>('capture_process_tuning', { pid });
+}
+
+export async function restoreProcessTuning(pid: number, snapshot: TuningSnapshot): Promise<void> {
+  await invoke('restore_process_tuning', { pid, snapshot });
+}
+
+// Memory metric: what memory_mb reports (commit_mb is always the commit charge)
+export type MemoryMetric = 'private_working_set' | 'commit';
+
+export async function getMemoryMetric(): Promise<MemoryMetric> {
+  return await invoke<MemoryMetric>('get_memory_metric');
+}
+
+export async function setMemoryMetric(metric: MemoryMetric): Promise<void> {
+  await invoke('set_memory_metric', { metric });
+}
+
+// Auto-track: backend follows the foreground app and emits
+// 'auto-track-session-started' / 'auto-track-session-ended' with an AutoTrackSession
+export interface AutoTrackSettings {
+  enabled: boolean;
+  grace_seconds: number;
+}
+
+export interface AutoTrackSession {
+  app_name: string;
+  exe_path: string | null;
+  start_time: string;
+  end_time: string | null;
+  duration_seconds: number;
+}
+
+export async function getAutoTrackForeground(): Promise<AutoTrackSettings> {
+  return await invoke<AutoTrackSettings>('get_auto_track_foreground');
+}
+
+export async function setAutoTrackForeground(enabled: boolean, graceSeconds?: number): Promise<void> {
+  await invoke('set_auto_track_foreground', { enabled, graceSeconds });
+}
+
+// Logging: the backend writes to a rotating file in the app data directory
+export type LogLevel = 'error' | 'warn' | 'info' | 'debug';
+
+export async function setLogLevel(level: LogLevel): Promise<void> {
+  await invoke('set_log_level', { level });
+}
+
+export async function getLogPath(): Promise<string> {
+  return await invoke<string>('get_log_path');
+}
+
+// Splash screen toggle (stored in a bootstrap file, applies on next launch)
+export async function getSplashEnabled(): Promise<boolean> {
+  return await invoke<boolean>('get_splash_enabled');
+}
+
+export async function setSplashEnabled(enabled: boolean): Promise<void> {
+  await invoke('set_splash_enabled', { enabled });
+}
+
+// Priority class, with the parent PID when the class was most likely inherited
+export interface ProcessPriority {
+  priority_class: number;
+  priority: string; // idle | below_normal | normal | above_normal | high | realtime
+  inherited_from: number | null;
+}
+
+export async function getProcessPriority(pid: number): Promise<ProcessPriority> {
+  return await invoke<ProcessPriority>('get_process_priority', { pid });
+}
+
+// Realtime is rejected, like any name outside the list
+export async function setProcessPriority(
+  pid: number,
+  priority: 'idle' | 'below_normal' | 'normal' | 'above_normal' | 'high',
+): Promise<void> {
+  await invoke('set_process_priority', { pid, priority });
+}
+
+// Profiling burst: high-rate sampling of one process, results arrive as a
+// 'profiling-complete' event carrying a ProfilingResult
+export interface ProfilingSample {
+  timestamp_ms: number;
+  cpu_percent: number;
+  memory_mb: number;
+  gpu_percent: number;
+  disk_bytes_per_sec: number;
+}
+
+export interface ProfilingResult {
+  pid: number;
+  interval_ms: number;
+  samples: ProfilingSample[];
+  process_exited: boolean;
+}
+
+export async function startProfilingBurst(pid: number, intervalMs: number, durationSecs: number): Promise<void> {
+  await invoke('start_profiling_burst', { pid, intervalMs, durationSecs });
+}
+
+// Elevation: some process commands need the app to run as administrator
+export async function isAppElevated(): Promise<boolean> {
+  return await invoke<boolean>('is_app_elevated');
+}
+
+export async function requestElevation(): Promise<void> {
+  await invoke('request_elevation');
+}
+
+// Services from the Service Control Manager (pid is null unless running)
+export interface ServiceInfo {
+  name: string;
+  display_name: string;
+  pid: number | null;
+  status: string; // running | stopped | paused | *_pending | unknown
+}
+
+export async function getServices(): Promise<ServiceInfo[]> {
+  return await invoke<ServiceInfo[]>('get_services');
+}
+
+// UI preferences: any JSON-serializable view state, stored as-is by the backend
+export async function saveUiPreferences<T>(preferences: T): Promise<void> {
+  await invoke('save_ui_preferences', { json: JSON.stringify(preferences) });
+}
+
+export async function loadUiPreferences<T>(): Promise<Partial<T>> {
+  const json = await invoke<string>('load_ui_preferences');
+  return JSON.parse(json) as Partial<T>;
+}
+
+// Memory growth leaderboard: system-wide leak hunting from the backend's per-PID history
+export interface MemoryGrowth {
+  pid: number;
+  name: string;
+  start_mb: number;
+  current_mb: number;
+  growth_mb_per_min: number;
+}
+
+export async function getMemoryGrowthLeaderboard(windowSecs: number, limit?: number): Promise<MemoryGrowth[]> {
+  return await invoke<MemoryGrowth[]>('get_memory_growth_leaderboard', { windowSecs, limit });
+}
+
+// Exclude input to Performance Guard's own window from activity tracking (default on)
+export async function getExcludeOwnInput(): Promise<boolean> {
+  return await invoke<boolean>('get_exclude_own_input');
+}
+
+export async function setExcludeOwnInput(enabled: boolean): Promise<void> {
+  await invoke('set_exclude_own_input', { enabled });
+}
+
+// Named snapshots: capture the process list now, diff against another capture later
+export interface SnapshotProcess {
+  pid: number;
+  name: string;
+  create_time: number;
+  cpu_percent: number;
+  memory_mb: number;
+}
+
+export interface SnapshotMover {
+  pid: number;
+  name: string;
+  before: number;
+  after: number;
+  delta: number;
+}
+
+export interface SnapshotDiff {
+  a_captured_at: string;
+  b_captured_at: string;
+  new_processes: SnapshotProcess[];
+  gone_processes: SnapshotProcess[];
+  cpu_movers: SnapshotMover[];
+  memory_movers: SnapshotMover[];
+}
+
+export async function captureNamedSnapshot(name: string): Promise<void> {
+  await invoke('capture_named_snapshot', { name });
+}
+
+export async function getNamedSnapshots(): Promise<string[]> {
+  return await invoke<string[]>('get_named_snapshots');
+}
+
+export async function diffNamedSnapshots(a: string, b: string): Promise<SnapshotDiff> {
+  return await invoke<SnapshotDiff>('diff_named_snapshots', { a, b });
+}
+
+// Decimal places the backend rounds metrics to (default 1)
+export async function setMetricPrecision(decimals: number): Promise<void> {
+  await invoke('set_metric_precision', { decimals });
+}
+
+// Shutdown: on 'app-shutdown' the frontend saves and then acknowledges with this
+export async function shutdownFlushed(): Promise<void> {
+  await invoke('shutdown_flushed');
+}
+
+// Tray icon shows live CPU load instead of the app icon
+export async function setTrayLoadIcon(enabled: boolean): Promise<void> {
+  await invoke('set_tray_load_icon', { enabled });
+}
+
+// Disk activity per physical disk (empty on the first call while counters prime)
+export interface DiskActivity {
+  drive: string;
+  busy_percent: number;
+  queue_length: number;
+  read_bytes_per_sec: number;
+  write_bytes_per_sec: number;
+}
+
+export async function getDiskActivity(): Promise<DiskActivity[]> {
+  return await invoke<DiskActivity[]>('get_disk_activity');
+}
+
+// Hybrid CPUs: how a process's CPU time over the next second splits between performance and
+// efficiency cores. Approximate - each thread is credited to its ideal core, not where it ran
+export interface CoreAttribution {
+  hybrid: boolean;
+  pcore_percent: number;
+  ecore_percent: number;
+}
+
+export async function getProcessCoreAttribution(pid: number): Promise<CoreAttribution> {
+  return await invoke<CoreAttribution>('get_process_core_attribution', { pid });
+}
+
+// Polls averaged into cpu_percent_avg / gpu_percent_avg (1 = raw, max 60)
+export async function setDisplayAverageWindow(samples: number): Promise<void> {
+  return await invoke('set_display_average_window', { samples });
+}
+
+// High priority on all cores for a while, previous priority/affinity restored afterwards
+export async function boostProcess(pid: number, durationSecs: number): Promise<void> {
+  return await invoke('boost_process', { pid, durationSecs });
+}
+
+// Power draw in watts, null where the hardware doesn't report it
+export interface PowerDraw {
+  gpu_watts: number | null;
+  cpu_watts: number | null;
+}
+
+export async function getPowerDraw(): Promise<PowerDraw> {
+  return await invoke<PowerDraw>('get_power_draw');
+}
+
+// failed holds [pid, reason] pairs
+export interface KillResult {
+  killed: number[];
+  failed: [number, string][];
+}
+
+export async function killProcessesByName(name: string): Promise<KillResult> {
+  return await invoke<KillResult>('kill_processes_by_name', { name });
+}
+
+// Rejects with "Process not found", "protected process" or "Access denied (...)"
+export async function killProcess(pid: number): Promise<void> {
+  await invoke('kill_process', { pid });
+}
+
+// Repeated suspends don't stack; the process's status reads "Suspended" until resumed
+export async function suspendProcess(pid: number): Promise<void> {
+  await invoke('suspend_process', { pid });
+}
+
+// Resolves without doing anything if the process was never suspended
+export async function resumeProcess(pid: number): Promise<void> {
+  await invoke('resume_process', { pid });
+}
+
+// Payload of the auto-optimize-ran event
+export interface TrimResult {
+  trimmed: number;
+  freed_mb: number;
+}
+
+// Trim background memory automatically once the system has been idle for a while
+export async function setAutoOptimizeOnIdle(enabled: boolean, idleThresholdSecs?: number): Promise<void> {
+  return await invoke('set_auto_optimize_on_idle', { enabled, idleThresholdSecs });
+}
+
+// Environment variables of a process (most targets need the app elevated)
+export async function getProcessEnvironment(pid: number): Promise<Record<string, string>> {
+  return await invoke<Record<string, string>>('get_process_environment', { pid });
+}
+
+// Only count keyboard/mouse activity while one of these exe names is foreground (empty = all)
+export async function setActivityAppFilter(exeNames: string[]): Promise<void> {
+  return await invoke('set_activity_app_filter', { exeNames });
+}
+
+export async function getActivityAppFilter(): Promise<string[]> {
+  return await invoke<string[]>('get_activity_app_filter');
+}
+
+export interface DailySummary {
+  date: string; // YYYY-MM-DD, local
+  active_seconds: number;
+  idle_seconds: number;
+  top_apps: [string, number][]; // [app name, active seconds], most used first
+}
+
+// Defaults to today
+export async function getDailyActivitySummary(date?: string): Promise<DailySummary> {
+  return await invoke<DailySummary>('get_daily_activity_summary', { date });
+}
+
+// Automation rules enforced on every process poll
+export type ProcessRule = {
+  type: 'SuspendWhileRunning';
+  target_name: string;
+  trigger_name: string;
+};
+
+export async function getProcessRules(): Promise<ProcessRule[]> {
+  return await invoke<ProcessRule[]>('get_process_rules');
+}
+
+export async function setProcessRules(rules: ProcessRule[]): Promise<void> {
+  return await invoke('set_process_rules', { rules });
+}
+
+// A process on the GPU, ready to render (icon is a base64 PNG)
+export interface GpuProcessInfo {
+  pid: number;
+  name: string;
+  exe_path: string | null;
+  icon: string | null;
+  gpu_percent: number;
+  gpu_adapter: string | null;
+  gpu_memory_mb: number | null;
+}
+
+export async function getGpuProcesses(): Promise<GpuProcessInfo[]> {
+  return await invoke<GpuProcessInfo[]>('get_gpu_processes');
+}
+
+export interface OsSettingChange {
+  reboot_required: boolean;
+}
+
+// Hardware-accelerated GPU scheduling, null when the driver doesn't support it
+export async function getHardwareGpuScheduling(): Promise<boolean | null> {
+  return await invoke<boolean | null>('get_hardware_gpu_scheduling');
+}
+
+// Needs elevation; Windows applies it after a reboot
+export async function setHardwareGpuScheduling(enabled: boolean): Promise<OsSettingChange> {
+  return await invoke<OsSettingChange>('set_hardware_gpu_scheduling', { enabled });
+}
+
+// Windows Game Mode (not the app's own game mode)
+export async function getGameModeEnabled(): Promise<boolean | null> {
+  return await invoke<boolean | null>('get_game_mode_enabled');
+}
+
+export async function setGameModeEnabled(enabled: boolean): Promise<OsSettingChange> {
+  return await invoke<OsSettingChange>('set_game_mode_enabled', { enabled });
+}
+
+// An app currently using the microphone or camera
+export interface MediaUsage {
+  device: 'microphone' | 'webcam';
+  app: string; // Exe path, or package family name for Store apps
+  packaged: boolean;
+  pids: number[];
+  since: string | null;
+}
+
+export async function getMediaDeviceUsage(): Promise<MediaUsage[]> {
+  return await invoke<MediaUsage[]>('get_media_device_usage');
+}
+
+// Cap how many processes getDisplayProcesses returns (highest CPU first), null = all
+export async function setMaxListLength(n: number | null): Promise<void> {
+  return await invoke('set_max_list_length', { n });
+}
+
+// Process list cut to the max list length - display only, never use it for session tracking
+export async function getDisplayProcesses(detailLevel?: 'basic' | 'full'): Promise<ProcessInfo[]> {
+  const processes = await invoke<BackendProcessInfo[]>('get_display_processes', { detailLevel });
+  return processes.map(mapProcessInfo);
+}
+
+// Opt-in: write logs/crash-<timestamp>.log when the backend panics
+export async function setCrashReporting(enabled: boolean): Promise<void> {
+  return await invoke('set_crash_reporting', { enabled });
+}
+
+export async function getCrashReporting(): Promise<boolean> {
+  return await invoke<boolean>('get_crash_reporting');
+}
+
+// Crash report texts, newest first
+export async function getRecentCrashes(): Promise<string[]> {
+  return await invoke<string[]>('get_recent_crashes');
+}
+
+// Serve /stats, /processes and Prometheus /metrics (read-only, LAN clients only)
+// Loopback only by default; lan needs a token of 16+ characters that clients send as a bearer token
+export async function setStatsServer(enabled: boolean, port?: number, lan?: boolean, token?: string): Promise<void> {
+  return await invoke('set_stats_server', { enabled, port, lan, token });
+}
+
+export interface RemoteSource {
+  source_id: string; // host:port
+  url: string;
+  connected: boolean;
+  last_update: string | null;
+  last_error: string | null;
+}
+
+// Poll another Performance Guard on the LAN, returns its source id
+export async function connectRemote(url: string, token?: string): Promise<string> {
+  return await invoke<string>('connect_remote', { url, token });
+}
+
+export async function disconnectRemote(sourceId: string): Promise<boolean> {
+  return await invoke<boolean>('disconnect_remote', { sourceId });
+}
+
+export async function getRemoteSources(): Promise<RemoteSource[]> {
+  return await invoke<RemoteSource[]>('get_remote_sources');
+}
+
+export type NotificationState =
+  | 'not_present'
+  | 'busy'
+  | 'fullscreen_d3d'
+  | 'presentation'
+  | 'accepts_notifications'
+  | 'quiet_time'
+  | 'app';
+
+// Windows notification state, null where unavailable
+export async function getNotificationState(): Promise<NotificationState | null> {
+  return await invoke<NotificationState | null>('get_notification_state');
+}
+
+// Stable chart color for a process name, matching ProcessInfo.color_hex
+export async function getProcessColor(name: string): Promise<string> {
+  return await invoke<string>('get_process_color', { name });
+}
+
+// PIDs of processes the OS has frozen (suspended UWP/Store apps)
+export async function getFrozenProcesses(): Promise<number[]> {
+  return await invoke<number[]>('get_frozen_processes');
+}
+
+// Back up the data file and reset everything to defaults
+// The backend emits app-data-reset afterwards
+export async function resetAllData(): Promise<void> {
+  await invoke('reset_all_data');
+}
+
+// Tags and notes live in the backend; saveAppData keeps whatever is stored
+export async function setSessionTags(id: number, tags: string[]): Promise<void> {
+  await invoke('set_session_tags', { id, tags });
+}
+
+export async function setSessionNotes(id: number, notes: string): Promise<void> {
+  await invoke('set_session_notes', { id, notes });
+}
+
+export async function findSessionsByTag(tag: string): Promise<Session[]> {
+  const sessions = await invoke<SavedSession[]>('find_sessions_by_tag', { tag });
+  return sessions.map(mapSavedSession);
+}
+
+// Apps with a visible window, deduplicated by exe and sorted by name, icons attached
+export async function getUserApps(): Promise<ProcessInfo[]> {
+  const processes = await invoke<BackendProcessInfo[]>('get_user_apps');
+  return processes.map(mapProcessInfo);
+}
+
+// EMA weight of the newest GPU utilization reading (0-1], 1 disables smoothing
+export async function setGpuSmoothingAlpha(alpha: number): Promise<void> {
+  await invoke('set_gpu_smoothing_alpha', { alpha });
+}
+
+export async function getGpuSmoothingAlpha(): Promise<number> {
+  return await invoke<number>('get_gpu_smoothing_alpha');
+}
+
+export interface PowerPlan {
+  guid: string;
+  name: string;
+}
+
+// Active power plan, null where unavailable
+export async function getPowerPlan(): Promise<PowerPlan | null> {
+  return await invoke<PowerPlan | null>('get_power_plan');
+}
+
+export async function listPowerPlans(): Promise<PowerPlan[]> {
+  return await invoke<PowerPlan[]>('list_power_plans');
+}
+
+export async function setPowerPlan(guid: string): Promise<void> {
+  await invoke('set_power_plan', { guid });
+}
+
+// Continue sessions cut off by a crash/update when their app is still running (persisted)
+export async function setResumeSessions(enabled: boolean): Promise<void> {
+  await invoke('set_resume_sessions', { enabled });
+}
+
+export async function getResumeSessions(): Promise<boolean> {
+  return await invoke<boolean>('get_resume_sessions');
+}
+
+export interface SelfBenchmark {
+  iterations: number;
+  avg_refresh_ms: number;
+  avg_collect_ms: number;
+  avg_gpu_query_ms: number;
+  peak_memory_mb: number | null;
+}
+
+// Time the monitoring hot path (a few back-to-back polls)
+export async function runSelfBenchmark(): Promise<SelfBenchmark> {
+  return await invoke<SelfBenchmark>('run_self_benchmark');
+}
+
+// Bulk-whitelist every .exe in a folder, already listed paths are skipped.
+// New entries are also emitted as 'whitelist-updated' (WhitelistEntry-shaped, exe_path may be null)
+export async function addWhitelistFromDirectory(dir: string, recursive: boolean): Promise<WhitelistEntry[]> {
+  const entries = await invoke<SavedWhitelistEntry[]>('add_whitelist_from_directory', { dir, recursive });
+  return entries.map(mapSavedWhitelistEntry);
+}
+
+// Watched folders keep whitelisting executables dropped into them (persisted)
+export interface WatchedDirectory {
+  path: string;
+  recursive: boolean;
+}
+
+export async function watchWhitelistDirectory(dir: string, recursive: boolean): Promise<WhitelistEntry[]> {
+  const entries = await invoke<SavedWhitelistEntry[]>('watch_whitelist_directory', { dir, recursive });
+  return entries.map(mapSavedWhitelistEntry);
+}
+
+export async function unwatchWhitelistDirectory(dir: string): Promise<void> {
+  await invoke('unwatch_whitelist_directory', { dir });
+}
+
+export async function getWatchedDirectories(): Promise<WatchedDirectory[]> {
+  return await invoke<WatchedDirectory[]>('get_watched_directories');
+}
+
+// A saved session's history capped at maxPoints (LTTB keeps the peaks), for drawing long sessions
+export async function getSessionHistoryDownsampled(sessionId: number, maxPoints: number): Promise<PerformanceSnapshot[]> {
+  const history = await invoke<SavedPerformanceSnapshot[]>('get_session_history_downsampled', { sessionId, maxPoints });
+  return history.map(mapSavedSnapshot);
+}
+
+// Per-interface throughput (loopback and never-used adapters left out)
+export interface NetworkInterface {
+  name: string;
+  received_bytes_per_sec: number;
+  transmitted_bytes_per_sec: number;
+  total_received: number;
+  total_transmitted: number;
+}
+
+export async function getNetworkStats(): Promise<NetworkInterface[]> {
+  return await invoke<NetworkInterface[]>('get_network_stats');
+}
+
+// Windows memory compression store, null when compression is disabled or unsupported
+export interface MemoryCompression {
+  compressed_mb: number;
+  store_size_mb: number;
+}
+
+export async function getMemoryCompression(): Promise<MemoryCompression | null> {
+  return await invoke<MemoryCompression | null>('get_memory_compression');
+}
+
+// Cap the monitor's own CPU use (percent of the machine), null = no budget
+export async function setSelfCpuBudget(maxPercent: number | null): Promise<void> {
+  await invoke('set_self_cpu_budget', { maxPercent });
+}
+
+export async function getSelfCpuBudget(): Promise<number | null> {
+  return await invoke<number | null>('get_self_cpu_budget');
+}
+
+// How long to wait before the next stats poll, stretched while over the CPU budget
+export async function getEffectivePollInterval(): Promise<number> {
+  return await invoke<number>('get_effective_poll_interval');
+}
+
+// Task Scheduler tasks, another source of background launches besides startup programs
+export interface ScheduledTask {
+  name: string;
+  path: string;
+  next_run: string | null;
+  enabled: boolean;
+  action: string; // Programs started, "path arguments" joined with "; "
+}
+
+export async function getScheduledTasks(): Promise<ScheduledTask[]> {
+  return await invoke<ScheduledTask[]>('get_scheduled_tasks');
+}
+
+export async function setTaskEnabled(path: string, enabled: boolean): Promise<void> {
+  await invoke('set_task_enabled', { path, enabled });
+}
+
+// Measured average CPU of a process (0-100% of the system) over durationSecs (1-60),
+// e.g. "capped at 20%, measured 21.3%"
+export async function measureEffectiveCpu(pid: number, durationSecs: number): Promise<number> {
+  return await invoke<number>('measure_effective_cpu', { pid, durationSecs });
+}
+
+export interface GpuDevice {
+  index: number;
+  name: string;
+  memory_total_mb: number;
+  memory_used_mb: number;
+  utilization_percent: number | null;
+  temperature_c: number | null;
+}
+
+// Empty on systems without an NVIDIA driver
+export async function getGpus(): Promise<GpuDevice[]> {
+  return await invoke<GpuDevice[]>('get_gpus');
+}
+
+// Backend polling - the backend refreshes on its own thread and pushes the results
+export async function startMonitoring(intervalMs: number): Promise<void> {
+  await invoke('start_monitoring', { intervalMs });
+}
+
+export async function stopMonitoring(): Promise<void> {
+  await invoke('stop_monitoring');
+}
+
+export function onProcessesUpdated(callback: (processes: ProcessInfo[]) => void): Promise<UnlistenFn> {
+  return listen<BackendProcessInfo[]>('processes-updated', event => callback(event.payload.map(mapProcessInfo)));
+}
+
+export function onStatsUpdated(callback: (stats: SystemStats) => void): Promise<UnlistenFn> {
+  return listen<BackendSystemStats>('stats-updated', event => callback(mapSystemStats(event.payload)));
+}
@@ -56,6 +56,10 @@ export const AppList = memo(function AppList({ apps, onSelectApp }: AppListProps
           comparison = a.session_count - b.session_count;
           break;
       }
+      // Equal values fall back to name so rows don't reshuffle between renders
+      if (comparison === 0 && sortField !== 'name') {
+        return a.name.localeCompare(b.name);
+      }
       return sortDirection === 'asc' ? comparison : -comparison;
     });
 
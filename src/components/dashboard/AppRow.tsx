@@ -1,7 +1,9 @@
 import { memo, useCallback } from 'react';
 import { Play, MoreVertical, AppWindow } from 'lucide-react';
 import { ProgressBar } from '../common';
-import { formatDurationCompact, formatEfficiencyBadge } from '../../utils/formatters';
+import { formatEfficiencyBadge } from '../../utils/formatters';
+import { formatDuration } from '../../api/tauri';
+import { useFormatted } from '../../hooks/useFormatted';
 import type { AppSummary } from '../../types';
 
 interface AppRowProps {
@@ -24,10 +26,10 @@ export const AppRow = memo(function AppRow({ app, onSelect }: AppRowProps) {
     ? (app.idle_time_seconds / app.total_time_seconds) * 100
     : 0;
 
-  const totalTimeDisplay = app.total_time_seconds > 0 ? formatDurationCompact(app.total_time_seconds) : '-';
-  const activeTimeDisplay = formatDurationCompact(app.active_time_seconds);
-  const idleTimeDisplay = formatDurationCompact(app.idle_time_seconds);
-  const backgroundTimeDisplay = formatDurationCompact(app.background_time_seconds);
+  const totalTimeDisplay = useFormatted(formatDuration, app.total_time_seconds > 0 ? app.total_time_seconds : null, '-');
+  const activeTimeDisplay = useFormatted(formatDuration, app.active_time_seconds);
+  const idleTimeDisplay = useFormatted(formatDuration, app.idle_time_seconds);
+  const backgroundTimeDisplay = useFormatted(formatDuration, app.background_time_seconds);
   const usageDisplay = `${app.avg_usage_percent.toFixed(0)}%`;
   const efficiencyDisplay = `${app.efficiency_percent.toFixed(0)}%`;
 
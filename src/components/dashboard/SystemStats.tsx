@@ -1,14 +1,29 @@
-import { memo } from 'react';
+import { memo, useEffect, useState } from 'react';
 import { Cpu, MemoryStick, HardDrive, Activity } from 'lucide-react';
 import { StatCard } from '../common';
 import { formatCpuPercent } from '../../utils/formatters';
+import { getMemoryCompression, type MemoryCompression } from '../../api/tauri';
 import type { SystemStats as SystemStatsType } from '../../types';
 
 interface SystemStatsProps {
   stats: SystemStatsType;
 }
 
+// The compressed store changes slowly, no need to follow the stats poll
+const COMPRESSION_POLL_MS = 5000;
+
 export const SystemStats = memo(function SystemStats({ stats }: SystemStatsProps) {
+  const watts = (stats.cpu_watts ?? 0) + (stats.gpu_watts ?? 0);
+  const hasPower = stats.cpu_watts !== null || stats.gpu_watts !== null;
+  const [compression, setCompression] = useState<MemoryCompression | null>(null);
+
+  useEffect(() => {
+    const refresh = () => getMemoryCompression().then(setCompression).catch(() => {});
+    refresh();
+    const interval = setInterval(refresh, COMPRESSION_POLL_MS);
+    return () => clearInterval(interval);
+  }, []);
+
   return (
     <div className="grid grid-cols-4 gap-4">
       <StatCard
@@ -22,7 +37,9 @@ export const SystemStats = memo(function SystemStats({ stats }: SystemStatsProps
         icon={MemoryStick}
         label="Memory Usage"
         value={formatCpuPercent(stats.memory_percent)}
-        subValue={`${stats.available_memory_gb.toFixed(1)} GB available`}
+        subValue={compression
+          ? `${stats.available_memory_gb.toFixed(1)} GB available · ${compression.compressed_mb.toFixed(0)} MB compressed`
+          : `${stats.available_memory_gb.toFixed(1)} GB available`}
         iconColor="text-accent-green"
       />
       <StatCard
@@ -36,7 +53,9 @@ export const SystemStats = memo(function SystemStats({ stats }: SystemStatsProps
         icon={Activity}
         label="System Load"
         value={stats.cpu_percent > 70 ? 'High' : stats.cpu_percent > 40 ? 'Medium' : 'Low'}
-        subValue={`${stats.cpu_percent.toFixed(0)}% utilization`}
+        subValue={hasPower
+          ? `${stats.cpu_percent.toFixed(0)}% utilization · ${watts.toFixed(0)} W`
+          : `${stats.cpu_percent.toFixed(0)}% utilization`}
         iconColor={stats.cpu_percent > 70 ? 'text-accent-red' : stats.cpu_percent > 40 ? 'text-accent-yellow' : 'text-accent-green'}
       />
     </div>
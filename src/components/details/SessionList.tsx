@@ -1,7 +1,8 @@
 import { memo, useMemo, useState, useCallback, useEffect, useRef } from 'react';
 import { Clock, Play, Square, History } from 'lucide-react';
 import { Pagination, Select } from '../common';
-import { formatDuration } from '../../utils/formatters';
+import { formatDuration } from '../../api/tauri';
+import { useFormatted } from '../../hooks/useFormatted';
 import { exportData } from '../../utils/export';
 import type { Session, ExportFormat } from '../../types';
 
@@ -109,6 +110,8 @@ const SessionCard = memo(function SessionCard({
     };
   }, [isAnimating, animationDelay]);
 
+  const durationDisplay = useFormatted(formatDuration, session.duration_seconds);
+
   return (
     <div
       onClick={onClick}
@@ -143,7 +146,7 @@ const SessionCard = memo(function SessionCard({
       {/* Duration */}
       <span className="text-xs text-text-muted flex items-center gap-1 flex-shrink-0">
         <Clock size={11} />
-        {formatDuration(session.duration_seconds)}
+        {durationDisplay}
       </span>
     </div>
   );
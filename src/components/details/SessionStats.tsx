@@ -1,7 +1,9 @@
 import { memo, useState, useRef, useEffect } from 'react';
 import { Cpu, MemoryStick, Clock, Monitor, ChevronDown, AppWindow } from 'lucide-react';
 import { StatCard } from '../common';
-import { formatCpuPercent, formatMemoryMB, formatDuration } from '../../utils/formatters';
+import { formatCpuPercent } from '../../utils/formatters';
+import { formatDuration, formatMegabytes } from '../../api/tauri';
+import { useFormatted } from '../../hooks/useFormatted';
 import { usePlatform } from '../../contexts/PlatformContext';
 import type { Session, AppSummary } from '../../types';
 
@@ -24,6 +26,9 @@ export const SessionStats = memo(function SessionStats({
   const dropdownRef = useRef<HTMLDivElement>(null);
   const platform = usePlatform();
   const showGpu = platform === 'windows' || platform === 'unknown';
+  const durationDisplay = useFormatted(formatDuration, session?.duration_seconds, '--:--');
+  const avgMemoryDisplay = useFormatted(formatMegabytes, session?.avg_memory_mb, '--MB');
+  const peakMemoryDisplay = useFormatted(formatMegabytes, session?.peak_memory_mb);
 
   // Close dropdown when clicking outside
   useEffect(() => {
@@ -121,7 +126,7 @@ export const SessionStats = memo(function SessionStats({
       <StatCard
         icon={Clock}
         label="Duration"
-        value={session ? formatDuration(session.duration_seconds) : '--:--'}
+        value={durationDisplay}
         subValue={session?.is_current ? 'Active' : session ? 'Ended' : 'No session'}
         iconColor="text-accent-blue"
         compact
@@ -133,7 +138,7 @@ export const SessionStats = memo(function SessionStats({
         icon={Cpu}
         label="Avg CPU"
         value={session ? formatCpuPercent(session.avg_cpu_percent) : '--%'}
-        subValue={session ? `Peak: ${formatCpuPercent(session.peak_cpu_percent)}` : ''}
+        subValue={session ? `Peak: ${formatCpuPercent(session.peak_cpu_percent)} · ${Math.round(session.cpu_seconds || 0)} CPU-s` : ''}
         iconColor="text-accent-blue"
         compact
         animationKey={animationKey}
@@ -143,8 +148,8 @@ export const SessionStats = memo(function SessionStats({
       <StatCard
         icon={MemoryStick}
         label="Avg Memory"
-        value={session ? formatMemoryMB(session.avg_memory_mb) : '--MB'}
-        subValue={session ? `Peak: ${formatMemoryMB(session.peak_memory_mb)}` : ''}
+        value={avgMemoryDisplay}
+        subValue={session ? `Peak: ${peakMemoryDisplay}` : ''}
         iconColor="text-accent-yellow"
         compact
         animationKey={animationKey}
@@ -2,7 +2,8 @@ import { memo, useMemo, useState, useCallback, useEffect, useRef } from 'react';
 import { RefreshCw } from 'lucide-react';
 import { ProcessRow } from './ProcessRow';
 import { SearchInput, Button } from '../common';
-import { getAppIcon } from '../../api/tauri';
+import { listen } from '@tauri-apps/api/event';
+import { prefetchIcons, type IconReady } from '../../api/tauri';
 import type { ProcessInfo } from '../../types';
 
 interface ProcessListProps {
@@ -56,6 +57,8 @@ export const ProcessList = memo(function ProcessList({
   const processKeyRef = useRef(0);
   const scrollContainerRef = useRef<HTMLDivElement>(null);
   const iconFetchedRef = useRef<Set<string>>(new Set());
+  // exe path -> process name, to route icon-ready events
+  const iconNamesRef = useRef<Map<string, string>>(new Map());
 
   // Deduplicate and aggregate processes by name, merging helper subprocesses into main process
   const uniqueProcesses = useMemo(() => {
@@ -111,20 +114,35 @@ export const ProcessList = memo(function ProcessList({
     return uniqueProcesses.filter(p => p.name.toLowerCase().includes(query));
   }, [uniqueProcesses, searchQuery]);
 
-  // Fetch icons for processes with exe_path
+  // Queue icons for processes with exe_path; rows keep their placeholder until icon-ready arrives
   useEffect(() => {
+    const exePaths: string[] = [];
     uniqueProcesses.forEach(process => {
       if (process.exe_path && !iconFetchedRef.current.has(process.name)) {
         iconFetchedRef.current.add(process.name);
-        getAppIcon(process.exe_path).then(icon => {
-          if (icon) {
-            setProcessIcons(prev => ({ ...prev, [process.name]: icon }));
-          }
-        });
+        iconNamesRef.current.set(process.exe_path, process.name);
+        exePaths.push(process.exe_path);
       }
     });
+    if (exePaths.length > 0) {
+      prefetchIcons(exePaths).catch(() => {});
+    }
   }, [uniqueProcesses]);
 
+  useEffect(() => {
+    const unlistenPromise = listen<IconReady>('icon-ready', event => {
+      const { exe_path, icon } = event.payload;
+      const name = iconNamesRef.current.get(exe_path);
+      if (name && icon) {
+        setProcessIcons(prev => ({ ...prev, [name]: icon }));
+      }
+    });
+
+    return () => {
+      unlistenPromise.then(unlisten => unlisten());
+    };
+  }, []);
+
   // Start animation when view becomes active (every tab switch)
   useEffect(() => {
     if (isActive && !prevActiveRef.current) {
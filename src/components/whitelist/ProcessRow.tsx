@@ -1,6 +1,8 @@
 import { memo, useCallback, useState, useEffect, useRef } from 'react';
 import { Plus, Check, Cpu, MemoryStick, AppWindow } from 'lucide-react';
-import { formatCpuPercent, formatMemoryMB } from '../../utils/formatters';
+import { formatCpuPercent } from '../../utils/formatters';
+import { formatMegabytes } from '../../api/tauri';
+import { useFormatted } from '../../hooks/useFormatted';
 import type { ProcessInfo } from '../../types';
 
 // Get app icon color based on process name (consistent color per app)
@@ -49,6 +51,7 @@ export const ProcessRow = memo(function ProcessRow({
   const animationRef = useRef<number | null>(null);
   const hasCalledCompleteRef = useRef(false);
   const onAnimationCompleteRef = useRef(onAnimationComplete);
+  const memoryDisplay = useFormatted(formatMegabytes, process.memory_mb);
 
   // Keep callback ref updated
   onAnimationCompleteRef.current = onAnimationComplete;
@@ -183,7 +186,10 @@ export const ProcessRow = memo(function ProcessRow({
       {/* Process info */}
       <div className="flex-1 min-w-0">
         <p className="text-sm font-medium text-text-primary truncate">{process.name.replace(/\.exe$/i, '')}</p>
-        <p className="text-xs text-text-muted truncate">PID: {process.pid}</p>
+        <p className="text-xs text-text-muted truncate">
+          PID: {process.pid}
+          {process.status === 'Suspended' && <span className="text-accent-yellow"> · Suspended</span>}
+        </p>
       </div>
 
       {/* CPU */}
@@ -195,7 +201,7 @@ export const ProcessRow = memo(function ProcessRow({
       {/* Memory */}
       <div className="flex items-center gap-1.5 flex-shrink-0 w-[80px]">
         <MemoryStick size={14} className="text-accent-green flex-shrink-0" />
-        <span className="text-sm text-text-secondary">{formatMemoryMB(process.memory_mb)}</span>
+        <span className="text-sm text-text-secondary">{memoryDisplay}</span>
       </div>
 
       {/* Toggle button */}
@@ -33,6 +33,9 @@ interface FirestoreSession {
   peak_cpu_percent: number;
   peak_memory_mb: number;
   peak_gpu_percent: number;
+  cpu_seconds?: number;
+  peak_working_set_mb?: number;
+  peak_commit_mb?: number;
   performance_history: PerformanceSnapshot[];
   updated_at: Timestamp;
 }
@@ -131,6 +134,9 @@ export function useFirestoreSync(uid: string | null) {
           peak_cpu_percent: session.peak_cpu_percent,
           peak_memory_mb: session.peak_memory_mb,
           peak_gpu_percent: session.peak_gpu_percent,
+          cpu_seconds: session.cpu_seconds || 0,
+          peak_working_set_mb: session.peak_working_set_mb || 0,
+          peak_commit_mb: session.peak_commit_mb || 0,
           performance_history: session.performance_history || [],
           updated_at: Timestamp.now(),
         };
@@ -196,6 +202,9 @@ export function useFirestoreSync(uid: string | null) {
           peak_cpu_percent: data.peak_cpu_percent,
           peak_memory_mb: data.peak_memory_mb,
           peak_gpu_percent: data.peak_gpu_percent,
+          cpu_seconds: data.cpu_seconds || 0,
+          peak_working_set_mb: data.peak_working_set_mb || 0,
+          peak_commit_mb: data.peak_commit_mb || 0,
           is_current: false,
           performance_history: data.performance_history || [],
         });
@@ -0,0 +1,31 @@
+import { useState, useEffect } from 'react';
+
+// Formats a value with one of the backend's canonical formatters (formatBytes, formatDuration...).
+// Returns `fallback` while the value is null/undefined or the call is pending
+export function useFormatted(
+  format: (value: number) => Promise<string>,
+  value: number | null | undefined,
+  fallback = ''
+): string {
+  const [text, setText] = useState(fallback);
+
+  useEffect(() => {
+    if (value === null || value === undefined) {
+      setText(fallback);
+      return;
+    }
+    let cancelled = false;
+    format(value)
+      .then((formatted) => {
+        if (!cancelled) setText(formatted);
+      })
+      .catch(() => {
+        if (!cancelled) setText(fallback);
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [format, value, fallback]);
+
+  return text;
+}
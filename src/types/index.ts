@@ -5,11 +5,38 @@ export interface ProcessInfo {
   name: string;
   cpu_percent: number;
   memory_mb: number;
+  commit_mb: number; // Private commit charge
+  peak_working_set_mb: number; // Highest working set since the process started (kept by the OS)
+  peak_commit_mb: number; // Highest commit charge since the process started
   memory_percent: number;
   gpu_percent: number;
+  gpu_memory_mb: number; // Dedicated VRAM (NVML), 0 when not on an NVIDIA GPU
+  cpu_percent_avg: number; // Trailing average over the display window
+  gpu_percent_avg: number;
+  uses_gpu: boolean; // On the GPU at all (NVML process list or PDH engine time)
+  gpu_adapter?: string; // Adapter the GPU figure comes from (iGPU vs dGPU on hybrid laptops)
   status: string;
   create_time: number;
   exe_path?: string;
+  category: string; // game | browser | dev | background | other
+  description?: string; // FileDescription from the exe's version info
+  company?: string;     // CompanyName from the exe's version info
+  is_new_binary: boolean; // Exe first seen running within the last 7 days
+  is_warming_up: boolean; // CPU still measuring (first polls after launch)
+  service_names: string[]; // Services hosted by this process (svchost etc.)
+  has_visible_window: boolean; // Owns a visible top-level window
+  io_wait_percent?: number; // Threads blocked on I/O or paging (detail lookups only)
+  context_switches_per_sec?: number; // Thread context switches per second (detail lookups only)
+  ancestor_chain: string[]; // Processes that started this one, root first (detail lookups only)
+  tcp_connections: number; // Open TCP connections (detail lookups only, 0 in lists)
+  udp_connections: number; // UDP endpoints (detail lookups only, 0 in lists)
+  gdi_objects: number; // GDI handles in use, limit 10,000 (detail lookups only, 0 in lists)
+  user_objects: number; // USER handles in use, limit 10,000 (detail lookups only, 0 in lists)
+  disk_read_ops_per_sec: number; // IOPS since the previous poll
+  disk_write_ops_per_sec: number;
+  is_frozen: boolean; // Suspended by the OS (background UWP/Store app)
+  color_hex: string; // stable chart color derived from the name
+  icon?: string; // Base64 PNG, only set by getUserApps
 }
 
 export interface AppSummary {
@@ -44,8 +71,13 @@ export interface Session {
   peak_cpu_percent: number;
   peak_memory_mb: number;
   peak_gpu_percent: number;
+  cpu_seconds?: number; // CPU time consumed (seconds on one core), missing on old sessions
+  peak_working_set_mb?: number; // OS-reported peaks of the app's processes, missing on old sessions
+  peak_commit_mb?: number;
   is_current: boolean;
   performance_history?: PerformanceSnapshot[]; // Performance data for this session
+  tags?: string[]; // Set through setSessionTags, stored by the backend
+  notes?: string;
 }
 
 export interface PerformanceSnapshot {
@@ -56,6 +88,7 @@ export interface PerformanceSnapshot {
   gpu_percent: number;
   user_activity_percent: number;
   is_foreground: boolean;
+  foreground_app?: string; // App in the foreground at the time, missing on old snapshots
   keyboard_clicks: number;
   mouse_pixels: number;
 }
@@ -75,6 +108,17 @@ export interface SystemStats {
   cpu_cores: number;
   cpu_percent: number;
   memory_percent: number;
+  is_warming_up: boolean; // CPU still measuring (first polls after launch)
+  page_faults_per_sec: number | null; // System-wide, Windows only
+  committed_percent: number | null;   // Commit charge / commit limit
+  gpu_watts: number | null; // Power draw, null where unsupported
+  cpu_watts: number | null;
+  network_received_bytes_per_sec: number; // All active non-loopback interfaces
+  network_transmitted_bytes_per_sec: number;
+  cpu_temp_c: number | null; // Celsius, null where no sensor is exposed
+  gpu_temp_c: number | null;
+  per_core_usage: number[]; // One entry per logical core
+  cpu_frequency_mhz: number[];
 }
 
 export interface ChartDataPoint {
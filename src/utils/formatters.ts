@@ -4,45 +4,10 @@ export const formatCpuPercent = (value: number): string => {
   return `${value.toFixed(1)}%`;
 };
 
-export const formatMemoryMB = (value: number): string => {
-  if (value >= 1024) {
-    return `${(value / 1024).toFixed(2)} GB`;
-  }
-  return `${value.toFixed(1)} MB`;
-};
-
 export const formatMemoryPercent = (value: number): string => {
   return `${value.toFixed(1)}%`;
 };
 
-export const formatDuration = (seconds: number): string => {
-  if (seconds < 60) {
-    return `${Math.floor(seconds)}s`;
-  }
-
-  const hours = Math.floor(seconds / 3600);
-  const minutes = Math.floor((seconds % 3600) / 60);
-
-  if (hours > 0) {
-    return `${hours}h ${minutes}m`;
-  }
-  return `${minutes}m`;
-};
-
-export const formatDurationCompact = (seconds: number): string => {
-  if (seconds < 60) {
-    return '<1m';
-  }
-
-  const hours = Math.floor(seconds / 3600);
-  const minutes = Math.floor((seconds % 3600) / 60);
-
-  if (hours > 0) {
-    return minutes > 0 ? `${hours}h ${minutes}m` : `${hours}h`;
-  }
-  return `${minutes}m`;
-};
-
 export const formatEfficiencyBadge = (percent: number): { color: string; bg: string } => {
   if (percent >= 70) {
     return { color: 'text-accent-green', bg: 'bg-accent-green/20' };